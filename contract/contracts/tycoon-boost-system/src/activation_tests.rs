@@ -0,0 +1,384 @@
+/// # Player Activation Tests
+///
+/// Covers `activate_boost` and the template / payment admin entrypoints.
+/// Collectible burns go to a mock of tycoon-collectibles that exposes the two
+/// entrypoints the boost system calls.
+///
+/// | Rule  | Behaviour |
+/// |-------|-----------|
+/// | ACT-1 | Paying TYC activates the template's boost with a contract-assigned id and computed expiry |
+/// | ACT-2 | Burning a collectible with the template's perk activates the boost |
+/// | ACT-3 | A collectible with another perk panics with `"PerkMismatch"`; nothing is burned |
+/// | ACT-4 | A payment the template does not accept panics with `"PaymentNotAccepted"` |
+/// | ACT-5 | An unknown template panics with `"TemplateNotFound"` |
+/// | ACT-6 | The per-player cap and the template's `max_grants` apply; the player is not charged |
+/// | ACT-7 | The player must authorize the activation |
+/// | ACT-8 | Templates are admin-only and validated; collected TYC can be withdrawn |
+/// | ACT-9 | `PlayerBoostActivatedEvent` records the template and payment |
+#[cfg(test)]
+extern crate std;
+
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Events},
+    token::StellarAssetClient,
+    Env,
+};
+
+// ── mock collectibles ─────────────────────────────────────────────────────────
+
+#[contracttype]
+enum MockKey {
+    Perk(u128),
+    Balance(Address, u128),
+}
+
+/// Stand-in for tycoon-collectibles: per-token perks and balances.
+#[contract]
+pub struct MockCollectibles;
+
+#[contractimpl]
+impl MockCollectibles {
+    pub fn mint(env: Env, to: Address, token_id: u128, perk: Perk) {
+        env.storage()
+            .persistent()
+            .set(&MockKey::Perk(token_id), &perk);
+        let key = MockKey::Balance(to, token_id);
+        let balance: u64 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(balance + 1));
+    }
+
+    pub fn balance_of(env: Env, owner: Address, token_id: u128) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&MockKey::Balance(owner, token_id))
+            .unwrap_or(0)
+    }
+
+    pub fn get_token_perk(env: Env, token_id: u128) -> Perk {
+        env.storage()
+            .persistent()
+            .get(&MockKey::Perk(token_id))
+            .unwrap_or(Perk::None)
+    }
+
+    pub fn burn_collectible_for_perk(env: Env, caller: Address, token_id: u128) {
+        caller.require_auth();
+        let key = MockKey::Balance(caller, token_id);
+        let balance: u64 = env.storage().persistent().get(&key).unwrap_or(0);
+        if balance == 0 {
+            panic!("InsufficientBalance");
+        }
+        env.storage().persistent().set(&key, &(balance - 1));
+    }
+}
+
+// ── helpers ───────────────────────────────────────────────────────────────────
+
+const RENT_TEMPLATE: u32 = 1;
+const ROLL_TEMPLATE: u32 = 2;
+const PRICE: i128 = 500;
+
+struct H<'a> {
+    env: Env,
+    client: TycoonBoostSystemClient<'a>,
+    collectibles: MockCollectiblesClient<'a>,
+    tyc: token::Client<'a>,
+    player: Address,
+}
+
+impl H<'_> {
+    /// Contract with payments configured, a player holding 10 000 TYC and two
+    /// templates: Rent (TYC or a `RentBoost` collectible, 100 ledgers) and
+    /// Roll (collectible only, 3 600 seconds).
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        let client = TycoonBoostSystemClient::new(&env, &env.register(TycoonBoostSystem, ()));
+        client.initialize(&Address::generate(&env));
+
+        let tyc_id = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let collectibles_id = env.register(MockCollectibles, ());
+        client.admin_set_payment_contracts(&tyc_id, &collectibles_id);
+
+        let player = Address::generate(&env);
+        StellarAssetClient::new(&env, &tyc_id).mint(&player, &10_000);
+
+        client.admin_set_template(
+            &RENT_TEMPLATE,
+            &template(BoostCategory::Rent, 100, 0, PRICE, Perk::RentBoost),
+        );
+        client.admin_set_template(
+            &ROLL_TEMPLATE,
+            &template(BoostCategory::Roll, 0, 3_600, 0, Perk::RollBoost),
+        );
+
+        H {
+            collectibles: MockCollectiblesClient::new(&env, &collectibles_id),
+            tyc: token::Client::new(&env, &tyc_id),
+            env,
+            client,
+            player,
+        }
+    }
+}
+
+fn template(
+    category: BoostCategory,
+    duration_ledgers: u32,
+    duration_seconds: u64,
+    tyc_price: i128,
+    perk: Perk,
+) -> BoostTemplate {
+    BoostTemplate {
+        boost_type: BoostType::Additive,
+        category,
+        value: 1000,
+        priority: 0,
+        duration_ledgers,
+        duration_seconds,
+        charges: None,
+        max_grants: None,
+        tyc_price,
+        perk,
+    }
+}
+
+// ── ACT-1 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_activate_with_tyc() {
+    let h = H::new();
+    let seq = h.env.ledger().sequence();
+
+    let id = h
+        .client
+        .activate_boost(&h.player, &RENT_TEMPLATE, &BoostPayment::Tyc);
+
+    assert_eq!(id, ACTIVATED_BOOST_ID_BASE);
+    assert_eq!(h.tyc.balance(&h.player), 10_000 - PRICE);
+    assert_eq!(h.tyc.balance(&h.client.address), PRICE);
+
+    let boost = h.client.get_active_boosts(&h.player).get(0).unwrap();
+    assert_eq!(boost.id, id);
+    assert_eq!(boost.category, BoostCategory::Rent);
+    assert_eq!(boost.expires_at_ledger, seq + 100);
+    assert_eq!(boost.expires_at_timestamp, 0);
+    assert_eq!(
+        h.client
+            .calculate_total_boost_for(&h.player, &BoostCategory::Rent),
+        11000
+    );
+
+    // A second activation gets the next id.
+    let next = h
+        .client
+        .activate_boost(&h.player, &RENT_TEMPLATE, &BoostPayment::Tyc);
+    assert_eq!(next, id + 1);
+}
+
+// ── ACT-2 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_activate_by_burning_collectible() {
+    let h = H::new();
+    h.collectibles.mint(&h.player, &7, &Perk::RollBoost);
+    let now = h.env.ledger().timestamp();
+
+    h.client
+        .activate_boost(&h.player, &ROLL_TEMPLATE, &BoostPayment::Collectible(7));
+
+    assert_eq!(h.collectibles.balance_of(&h.player, &7), 0);
+    assert_eq!(h.tyc.balance(&h.player), 10_000);
+    let boost = h.client.get_active_boosts(&h.player).get(0).unwrap();
+    assert_eq!(boost.category, BoostCategory::Roll);
+    assert_eq!(boost.expires_at_ledger, 0);
+    assert_eq!(boost.expires_at_timestamp, now + 3_600);
+}
+
+// ── ACT-3 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_perk_mismatch_rejected() {
+    let h = H::new();
+    h.collectibles.mint(&h.player, &7, &Perk::DoubleRent);
+
+    let result =
+        h.client
+            .try_activate_boost(&h.player, &RENT_TEMPLATE, &BoostPayment::Collectible(7));
+
+    assert!(result.is_err());
+    assert_eq!(h.collectibles.balance_of(&h.player, &7), 1);
+    assert!(h.client.get_active_boosts(&h.player).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "PerkMismatch")]
+fn test_perk_mismatch_panics() {
+    let h = H::new();
+    h.collectibles.mint(&h.player, &7, &Perk::Shield);
+    h.client
+        .activate_boost(&h.player, &ROLL_TEMPLATE, &BoostPayment::Collectible(7));
+}
+
+// ── ACT-4 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic(expected = "PaymentNotAccepted")]
+fn test_tyc_not_accepted_without_price() {
+    let h = H::new();
+    h.client
+        .activate_boost(&h.player, &ROLL_TEMPLATE, &BoostPayment::Tyc);
+}
+
+#[test]
+#[should_panic(expected = "PaymentNotAccepted")]
+fn test_collectible_not_accepted_without_perk() {
+    let h = H::new();
+    h.client
+        .admin_set_template(&3, &template(BoostCategory::Cash, 0, 0, PRICE, Perk::None));
+    h.collectibles.mint(&h.player, &7, &Perk::None);
+    h.client
+        .activate_boost(&h.player, &3, &BoostPayment::Collectible(7));
+}
+
+// ── ACT-5 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic(expected = "TemplateNotFound")]
+fn test_unknown_template_rejected() {
+    let h = H::new();
+    h.client.activate_boost(&h.player, &99, &BoostPayment::Tyc);
+}
+
+#[test]
+#[should_panic(expected = "TemplateNotFound")]
+fn test_removed_template_rejected() {
+    let h = H::new();
+    h.client.admin_remove_template(&RENT_TEMPLATE);
+    assert_eq!(h.client.get_template(&RENT_TEMPLATE), None);
+    h.client
+        .activate_boost(&h.player, &RENT_TEMPLATE, &BoostPayment::Tyc);
+}
+
+// ── ACT-6 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_cap_applies_and_player_not_charged() {
+    let h = H::new();
+    for _ in 0..MAX_BOOSTS_PER_PLAYER {
+        h.client
+            .activate_boost(&h.player, &RENT_TEMPLATE, &BoostPayment::Tyc);
+    }
+    let balance = h.tyc.balance(&h.player);
+
+    let result = h
+        .client
+        .try_activate_boost(&h.player, &RENT_TEMPLATE, &BoostPayment::Tyc);
+
+    assert!(result.is_err());
+    assert_eq!(h.tyc.balance(&h.player), balance);
+    assert_eq!(
+        h.client.get_active_boosts(&h.player).len(),
+        MAX_BOOSTS_PER_PLAYER
+    );
+}
+
+#[test]
+fn test_max_grants_applies_and_player_not_charged() {
+    let h = H::new();
+    let mut limited = template(BoostCategory::Rent, 100, 0, PRICE, Perk::None);
+    limited.max_grants = Some(1);
+    h.client.admin_set_template(&RENT_TEMPLATE, &limited);
+    h.client
+        .activate_boost(&h.player, &RENT_TEMPLATE, &BoostPayment::Tyc);
+
+    let result = h
+        .client
+        .try_activate_boost(&h.player, &RENT_TEMPLATE, &BoostPayment::Tyc);
+
+    assert!(result.is_err());
+    assert_eq!(h.tyc.balance(&h.player), 10_000 - PRICE);
+    assert_eq!(h.client.template_grants(&RENT_TEMPLATE), 1);
+}
+
+// ── ACT-7 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic]
+fn test_activation_requires_player_auth() {
+    let h = H::new();
+    h.env.mock_auths(&[]);
+    h.client
+        .activate_boost(&h.player, &RENT_TEMPLATE, &BoostPayment::Tyc);
+}
+
+// ── ACT-8 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic]
+fn test_set_template_requires_admin() {
+    let h = H::new();
+    h.env.mock_auths(&[]);
+    h.client
+        .admin_set_template(&3, &template(BoostCategory::Cash, 0, 0, PRICE, Perk::None));
+}
+
+#[test]
+fn test_template_validated() {
+    let h = H::new();
+    let mut zero_value = template(BoostCategory::Cash, 0, 0, PRICE, Perk::None);
+    zero_value.value = 0;
+    assert!(h.client.try_admin_set_template(&3, &zero_value).is_err());
+    assert!(h
+        .client
+        .try_admin_set_template(&3, &template(BoostCategory::Cash, 0, 0, -1, Perk::None))
+        .is_err());
+    assert_eq!(h.client.get_template(&3), None);
+}
+
+#[test]
+fn test_admin_withdraws_collected_tyc() {
+    let h = H::new();
+    h.client
+        .activate_boost(&h.player, &RENT_TEMPLATE, &BoostPayment::Tyc);
+    let treasury = Address::generate(&h.env);
+
+    h.client.admin_withdraw_tyc(&treasury, &PRICE);
+
+    assert_eq!(h.tyc.balance(&treasury), PRICE);
+    assert_eq!(h.tyc.balance(&h.client.address), 0);
+}
+
+// ── ACT-9 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_activation_event() {
+    let h = H::new();
+    h.collectibles.mint(&h.player, &7, &Perk::RentBoost);
+
+    let id = h
+        .client
+        .activate_boost(&h.player, &RENT_TEMPLATE, &BoostPayment::Collectible(7));
+
+    let (_, topics, data) = h.env.events().all().last().unwrap();
+    let name: Symbol = topics.get(0).unwrap().into_val(&h.env);
+    let boost_id: u128 = topics.get(2).unwrap().into_val(&h.env);
+    let data: Map<Symbol, soroban_sdk::Val> = data.into_val(&h.env);
+    let template_id: u32 = data
+        .get(Symbol::new(&h.env, "template_id"))
+        .unwrap()
+        .into_val(&h.env);
+    let payment: BoostPayment = data
+        .get(Symbol::new(&h.env, "payment"))
+        .unwrap()
+        .into_val(&h.env);
+
+    assert_eq!(name, Symbol::new(&h.env, "player_boost_activated_event"));
+    assert_eq!(boost_id, id);
+    assert_eq!(template_id, RENT_TEMPLATE);
+    assert_eq!(payment, BoostPayment::Collectible(7));
+}
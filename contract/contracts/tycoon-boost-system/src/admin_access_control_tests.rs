@@ -48,9 +48,12 @@ fn nb(id: u128, boost_type: BoostType, value: u32) -> Boost {
     Boost {
         id,
         boost_type,
+        category: BoostCategory::General,
         value,
         priority: 0,
         expires_at_ledger: 0,
+        expires_at_timestamp: 0,
+        charges: None,
     }
 }
 
@@ -58,9 +61,12 @@ fn eb(id: u128, boost_type: BoostType, value: u32, expires_at_ledger: u32) -> Bo
     Boost {
         id,
         boost_type,
+        category: BoostCategory::General,
         value,
         priority: 0,
         expires_at_ledger,
+        expires_at_timestamp: 0,
+        charges: None,
     }
 }
 
@@ -8,7 +8,10 @@
 
 extern crate std;
 
-use crate::{Boost, BoostType, TycoonBoostSystem, TycoonBoostSystemClient, MAX_BOOSTS_PER_PLAYER};
+use crate::{
+    Boost, BoostCategory, BoostType, TycoonBoostSystem, TycoonBoostSystemClient,
+    ACTIVATED_BOOST_ID_BASE, MAX_BOOSTS_PER_PLAYER,
+};
 use soroban_sdk::{
     testutils::{Address as _, Events, Ledger, LedgerInfo},
     Address, Env,
@@ -48,9 +51,12 @@ fn boost(id: u128, boost_type: BoostType, value: u32, priority: u32, expires: u3
     Boost {
         id,
         boost_type,
+        category: BoostCategory::General,
         value,
         priority,
         expires_at_ledger: expires,
+        expires_at_timestamp: 0,
+        charges: None,
     }
 }
 
@@ -104,18 +110,22 @@ fn test_maximum_priority_override() {
     assert_eq!(client.calculate_total_boost(&player), 20000);
 }
 
-/// Test boost with ID at maximum u128 value
+/// Test boost with the largest admin-assignable ID; higher ids are reserved
+/// for template boosts.
 #[test]
 fn test_maximum_boost_id() {
     let env = make_env();
     let (client, player) = setup(&env);
 
-    let max_id = u128::MAX;
+    let max_id = ACTIVATED_BOOST_ID_BASE - 1;
     client.add_boost(&player, &boost(max_id, BoostType::Additive, 1000, 0, 0));
 
     let boosts = client.get_boosts(&player);
     assert_eq!(boosts.len(), 1);
     assert_eq!(boosts.get(0).unwrap().id, max_id);
+    assert!(client
+        .try_add_boost(&player, &boost(u128::MAX, BoostType::Additive, 1000, 0, 0))
+        .is_err());
 }
 
 // ── Stress Tests ──────────────────────────────────────────────────────────────
@@ -71,9 +71,12 @@ fn nb(id: u128, boost_type: BoostType, value: u32, priority: u32) -> Boost {
     Boost {
         id,
         boost_type,
+        category: BoostCategory::General,
         value,
         priority,
         expires_at_ledger: 0,
+        expires_at_timestamp: 0,
+        charges: None,
     }
 }
 
@@ -82,9 +85,12 @@ fn eb(id: u128, boost_type: BoostType, value: u32, priority: u32, expires: u32)
     Boost {
         id,
         boost_type,
+        category: BoostCategory::General,
         value,
         priority,
         expires_at_ledger: expires,
+        expires_at_timestamp: 0,
+        charges: None,
     }
 }
 
@@ -0,0 +1,228 @@
+/// # Boost Category Tests
+///
+/// Boosts only stack with boosts of the same `BoostCategory`.
+///
+/// | Rule  | Behaviour |
+/// |-------|-----------|
+/// | CAT-1 | `calculate_total_boost_for` only stacks boosts of the requested category |
+/// | CAT-2 | An Override in one category does not affect another |
+/// | CAT-3 | `calculate_total_boost` reads the `General` category |
+/// | CAT-4 | `calculate_all_boosts` maps each category with active boosts to its total |
+/// | CAT-5 | Expired boosts are left out of per-category totals |
+/// | CAT-6 | `BoostActivatedEvent` carries the boost's category |
+#[cfg(test)]
+extern crate std;
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger, LedgerInfo},
+    Env, IntoVal, Map, Symbol, Val,
+};
+
+// ── helpers ───────────────────────────────────────────────────────────────────
+
+fn setup(env: &Env) -> (TycoonBoostSystemClient, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(TycoonBoostSystem, ());
+    let client = TycoonBoostSystemClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env));
+    (client, Address::generate(env))
+}
+
+fn boost(
+    id: u128,
+    category: BoostCategory,
+    boost_type: BoostType,
+    value: u32,
+    expires_at_ledger: u32,
+) -> Boost {
+    Boost {
+        id,
+        boost_type,
+        category,
+        value,
+        priority: 0,
+        expires_at_ledger,
+        expires_at_timestamp: 0,
+        charges: None,
+    }
+}
+
+fn set_ledger(env: &Env, seq: u32) {
+    env.ledger().set(LedgerInfo {
+        sequence_number: seq,
+        timestamp: seq as u64 * 5,
+        protocol_version: 23,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 100_000,
+    });
+}
+
+// ── CAT-1 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_category_totals_are_independent() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+
+    client.add_boost(
+        &player,
+        &boost(1, BoostCategory::Rent, BoostType::Additive, 1000, 0),
+    );
+    client.add_boost(
+        &player,
+        &boost(2, BoostCategory::Rent, BoostType::Additive, 500, 0),
+    );
+    client.add_boost(
+        &player,
+        &boost(3, BoostCategory::Roll, BoostType::Multiplicative, 20000, 0),
+    );
+
+    assert_eq!(
+        client.calculate_total_boost_for(&player, &BoostCategory::Rent),
+        11500
+    );
+    assert_eq!(
+        client.calculate_total_boost_for(&player, &BoostCategory::Roll),
+        20000
+    );
+    assert_eq!(
+        client.calculate_total_boost_for(&player, &BoostCategory::Cash),
+        10000
+    );
+}
+
+// ── CAT-2 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_override_scoped_to_its_category() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+
+    client.add_boost(
+        &player,
+        &boost(1, BoostCategory::Cash, BoostType::Override, 50000, 0),
+    );
+    client.add_boost(
+        &player,
+        &boost(2, BoostCategory::Xp, BoostType::Additive, 2000, 0),
+    );
+
+    assert_eq!(
+        client.calculate_total_boost_for(&player, &BoostCategory::Cash),
+        50000
+    );
+    assert_eq!(
+        client.calculate_total_boost_for(&player, &BoostCategory::Xp),
+        12000
+    );
+}
+
+// ── CAT-3 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_legacy_total_reads_general_category() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+
+    client.add_boost(
+        &player,
+        &boost(1, BoostCategory::General, BoostType::Additive, 1000, 0),
+    );
+    client.add_boost(
+        &player,
+        &boost(
+            2,
+            BoostCategory::PropertyDiscount,
+            BoostType::Additive,
+            3000,
+            0,
+        ),
+    );
+
+    assert_eq!(client.calculate_total_boost(&player), 11000);
+    assert_eq!(
+        client.calculate_total_boost(&player),
+        client.calculate_total_boost_for(&player, &BoostCategory::General)
+    );
+}
+
+// ── CAT-4 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_all_boosts_maps_each_category() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+
+    assert!(client.calculate_all_boosts(&player).is_empty());
+
+    client.add_boost(
+        &player,
+        &boost(1, BoostCategory::Rent, BoostType::Additive, 1000, 0),
+    );
+    client.add_boost(
+        &player,
+        &boost(2, BoostCategory::Roll, BoostType::Multiplicative, 15000, 0),
+    );
+    client.add_boost(
+        &player,
+        &boost(3, BoostCategory::Rent, BoostType::Additive, 1000, 0),
+    );
+
+    let all = client.calculate_all_boosts(&player);
+    assert_eq!(all.len(), 2);
+    assert_eq!(all.get(BoostCategory::Rent), Some(12000));
+    assert_eq!(all.get(BoostCategory::Roll), Some(15000));
+    assert_eq!(all.get(BoostCategory::General), None);
+}
+
+// ── CAT-5 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_expired_boosts_left_out_of_categories() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+    set_ledger(&env, 100);
+
+    client.add_boost(
+        &player,
+        &boost(1, BoostCategory::Rent, BoostType::Additive, 1000, 150),
+    );
+    client.add_boost(
+        &player,
+        &boost(2, BoostCategory::Roll, BoostType::Additive, 1000, 0),
+    );
+
+    set_ledger(&env, 150);
+    assert_eq!(
+        client.calculate_total_boost_for(&player, &BoostCategory::Rent),
+        10000
+    );
+    let all = client.calculate_all_boosts(&player);
+    assert_eq!(all.len(), 1);
+    assert_eq!(all.get(BoostCategory::Roll), Some(11000));
+}
+
+// ── CAT-6 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_activation_event_carries_category() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+
+    client.add_boost(
+        &player,
+        &boost(7, BoostCategory::Xp, BoostType::Additive, 500, 0),
+    );
+
+    let (_, _, data) = env.events().all().last().unwrap();
+    let data: Map<Symbol, Val> = data.into_val(&env);
+    let category: BoostCategory = data
+        .get(Symbol::new(&env, "category"))
+        .unwrap()
+        .into_val(&env);
+    assert_eq!(category, BoostCategory::Xp);
+}
@@ -0,0 +1,208 @@
+/// # Charge-Based Boost Tests
+///
+/// Covers `Boost.charges`, `admin_set_game_controller` and `consume_boost`.
+///
+/// | Rule  | Behaviour |
+/// |-------|-----------|
+/// | CHG-1 | `consume_boost` spends one charge, returns the rest and emits `BoostConsumedEvent` |
+/// | CHG-2 | Spending the last charge removes the boost |
+/// | CHG-3 | Only the registered game controller may consume, with its authorization |
+/// | CHG-4 | Unknown, expired and non-charge boosts are rejected |
+/// | CHG-5 | Zero-charge boosts are rejected on insert and never count as active |
+#[cfg(test)]
+extern crate std;
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    Env,
+};
+
+// ── helpers ───────────────────────────────────────────────────────────────────
+
+struct H<'a> {
+    env: Env,
+    client: TycoonBoostSystemClient<'a>,
+    controller: Address,
+    player: Address,
+}
+
+impl H<'_> {
+    /// Contract with a registered game controller.
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        let client = TycoonBoostSystemClient::new(&env, &env.register(TycoonBoostSystem, ()));
+        client.initialize(&Address::generate(&env));
+        let controller = Address::generate(&env);
+        client.admin_set_game_controller(&controller);
+        H {
+            player: Address::generate(&env),
+            env,
+            client,
+            controller,
+        }
+    }
+
+    fn consume(&self, boost_id: u128) -> u32 {
+        self.client
+            .consume_boost(&self.controller, &self.player, &boost_id)
+    }
+}
+
+fn charged(id: u128, charges: Option<u32>) -> Boost {
+    Boost {
+        id,
+        boost_type: BoostType::Additive,
+        category: BoostCategory::General,
+        value: 1000,
+        priority: 0,
+        expires_at_ledger: 0,
+        expires_at_timestamp: 0,
+        charges,
+    }
+}
+
+// ── CHG-1 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_consume_spends_one_charge() {
+    let h = H::new();
+    h.client.add_boost(&h.player, &charged(1, Some(3)));
+
+    assert_eq!(h.consume(1), 2);
+
+    let (_, topics, data) = h.env.events().all().last().unwrap();
+    let name: Symbol = topics.get(0).unwrap().into_val(&h.env);
+    let data: Map<Symbol, soroban_sdk::Val> = data.into_val(&h.env);
+    let remaining: u32 = data
+        .get(Symbol::new(&h.env, "remaining"))
+        .unwrap()
+        .into_val(&h.env);
+    assert_eq!(name, Symbol::new(&h.env, "boost_consumed_event"));
+    assert_eq!(remaining, 2);
+
+    let boost = h.client.get_active_boosts(&h.player).get(0).unwrap();
+    assert_eq!(boost.charges, Some(2));
+    assert_eq!(h.client.calculate_total_boost(&h.player), 11000);
+}
+
+// ── CHG-2 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_last_charge_removes_boost() {
+    let h = H::new();
+    h.client.add_boost(&h.player, &charged(1, Some(2)));
+    h.client.add_boost(&h.player, &charged(2, None));
+
+    h.consume(1);
+    assert_eq!(h.consume(1), 0);
+
+    let active = h.client.get_active_boosts(&h.player);
+    assert_eq!(active.len(), 1);
+    assert_eq!(active.get(0).unwrap().id, 2);
+    assert_eq!(h.client.calculate_total_boost(&h.player), 11000);
+    assert!(h
+        .client
+        .try_consume_boost(&h.controller, &h.player, &1)
+        .is_err());
+}
+
+// ── CHG-3 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_unregistered_caller_rejected() {
+    let h = H::new();
+    h.client.add_boost(&h.player, &charged(1, Some(1)));
+    h.client
+        .consume_boost(&Address::generate(&h.env), &h.player, &1);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_no_controller_registered() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = TycoonBoostSystemClient::new(&env, &env.register(TycoonBoostSystem, ()));
+    client.initialize(&Address::generate(&env));
+    let player = Address::generate(&env);
+    client.add_boost(&player, &charged(1, Some(1)));
+    client.consume_boost(&Address::generate(&env), &player, &1);
+}
+
+#[test]
+#[should_panic]
+fn test_consume_requires_controller_auth() {
+    let h = H::new();
+    h.client.add_boost(&h.player, &charged(1, Some(1)));
+    h.env.mock_auths(&[]);
+    h.consume(1);
+}
+
+#[test]
+fn test_controller_is_replaceable() {
+    let h = H::new();
+    assert_eq!(h.client.game_controller(), Some(h.controller.clone()));
+    let next = Address::generate(&h.env);
+    h.client.admin_set_game_controller(&next);
+    h.client.add_boost(&h.player, &charged(1, Some(2)));
+
+    assert!(h
+        .client
+        .try_consume_boost(&h.controller, &h.player, &1)
+        .is_err());
+    assert_eq!(h.client.consume_boost(&next, &h.player, &1), 1);
+}
+
+// ── CHG-4 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic(expected = "BoostNotFound")]
+fn test_unknown_boost_rejected() {
+    let h = H::new();
+    h.consume(1);
+}
+
+#[test]
+#[should_panic(expected = "BoostNotFound")]
+fn test_expired_boost_rejected() {
+    let h = H::new();
+    let mut boost = charged(1, Some(5));
+    boost.expires_at_ledger = h.env.ledger().sequence() + 10;
+    h.client.add_boost(&h.player, &boost);
+    h.env.ledger().with_mut(|li| li.sequence_number += 10);
+    h.consume(1);
+}
+
+#[test]
+#[should_panic(expected = "NotChargeBased")]
+fn test_non_charge_boost_rejected() {
+    let h = H::new();
+    h.client.add_boost(&h.player, &charged(1, None));
+    h.consume(1);
+}
+
+// ── CHG-5 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic(expected = "InvalidValue")]
+fn test_zero_charges_rejected_on_insert() {
+    let h = H::new();
+    h.client.admin_grant_boost(&h.player, &charged(1, Some(0)));
+}
+
+#[test]
+fn test_zero_charge_boost_never_active() {
+    let h = H::new();
+    h.env.as_contract(&h.client.address, || {
+        h.env.storage().persistent().set(
+            &DataKey::PlayerBoostsV2(h.player.clone()),
+            &soroban_sdk::vec![&h.env, charged(1, Some(0))],
+        );
+    });
+
+    assert!(h.client.get_active_boosts(&h.player).is_empty());
+    assert_eq!(h.client.calculate_total_boost(&h.player), 10000);
+    assert!(h.client.calculate_all_boosts(&h.player).is_empty());
+}
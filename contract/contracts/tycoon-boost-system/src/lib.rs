@@ -1,27 +1,54 @@
 #![no_std]
-use soroban_sdk::{contract, contractevent, contractimpl, contracttype, Address, Env, Vec};
+use soroban_sdk::{
+    contract, contractevent, contractimpl, contracttype, token, vec, Address, Env, IntoVal, Map,
+    Symbol, Vec,
+};
 
 // ── Constants ─────────────────────────────────────────────────────────────────
 
-/// Maximum number of boosts a single player may hold simultaneously.
-/// Adding a boost when the player already holds this many panics with
-/// `BoostError::CapExceeded`.
+/// Default number of boosts a single player may hold simultaneously, until the
+/// admin sets another cap with `admin_set_stacking_config`. Adding a boost when
+/// the player already holds the cap panics with `BoostError::CapExceeded`.
 pub const MAX_BOOSTS_PER_PLAYER: u32 = 10;
 
+/// Default ceiling on any computed multiplier, in basis points. Until the
+/// admin sets a lower one, totals only saturate at the `u32` range.
+pub const DEFAULT_MAX_MULTIPLIER_BPS: u32 = u32::MAX;
+
+/// Base multiplier (1×) in basis points.
+const BASE_BPS: u32 = 10000;
+
+/// First id handed to boosts created from a template by `activate_boost` or
+/// `grant_from_template`. Ids passed to `add_boost` and `admin_grant_boost`
+/// must stay below this.
+pub const ACTIVATED_BOOST_ID_BASE: u128 = 1 << 64;
+
+/// Largest `page_size` accepted by `get_templates`.
+pub const MAX_TEMPLATE_PAGE: u32 = 50;
+
 // ── Error codes ───────────────────────────────────────────────────────────────
 
 /// Canonical error codes returned (via panic message) for invalid boost operations.
 ///
 /// | Code | Meaning |
 /// |------|---------|
-/// | `CapExceeded`      | Player already holds `MAX_BOOSTS_PER_PLAYER` active boosts |
+/// | `CapExceeded`      | Player already holds the configured cap of active boosts |
 /// | `DuplicateId`      | A boost with the same `id` is already active for this player |
 /// | `InvalidValue`     | `value` is 0, which would have no effect |
-/// | `InvalidExpiry`    | `expires_at_ledger` is in the past (≤ current ledger) |
+/// | `InvalidId`        | An admin-assigned `id` is at or above `ACTIVATED_BOOST_ID_BASE` |
+/// | `InvalidExpiry`    | `expires_at_ledger` or `expires_at_timestamp` is already reached |
 /// | `NotInitialized`   | Contract has not been initialized yet |
 /// | `AlreadyInitialized` | Contract has already been initialized |
-/// | `Unauthorized`     | Caller is not the admin |
+/// | `Unauthorized`     | Caller is not the admin or the game controller |
 /// | `AlreadyInitialized` | `initialize` called more than once |
+/// | `TemplateNotFound` | `activate_boost` / `grant_from_template` names a template that does not exist |
+/// | `MaxGrantsReached` | The template has already been granted `max_grants` times |
+/// | `InvalidPageSize`  | `get_templates` page size is 0 or above `MAX_TEMPLATE_PAGE` |
+/// | `PaymentNotAccepted` | The template cannot be bought with the chosen payment |
+/// | `PerkMismatch`     | The burned collectible's perk differs from the template's |
+/// | `PaymentsNotConfigured` | The TYC token and collectibles contract are not set |
+/// | `BoostNotFound`    | `consume_boost` names a boost the player does not hold |
+/// | `NotChargeBased`   | `consume_boost` names a boost without `charges` |
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum BoostError {
@@ -32,6 +59,15 @@ pub enum BoostError {
     NotInitialized,
     AlreadyInitialized,
     Unauthorized,
+    TemplateNotFound,
+    PaymentNotAccepted,
+    PerkMismatch,
+    PaymentsNotConfigured,
+    BoostNotFound,
+    NotChargeBased,
+    MaxGrantsReached,
+    InvalidPageSize,
+    InvalidId,
 }
 
 // ── Data types ────────────────────────────────────────────────────────────────
@@ -44,27 +80,165 @@ pub enum BoostType {
     Override,       // Only highest-priority value applies
 }
 
+/// The game effect a boost applies to. Boosts only stack with boosts of the
+/// same category.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum BoostCategory {
+    /// Default for boosts with no specific effect; the category legacy
+    /// `calculate_total_boost` callers read.
+    General,
+    Rent,
+    Roll,
+    Cash,
+    PropertyDiscount,
+    Xp,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Boost {
     pub id: u128,
     pub boost_type: BoostType,
+    pub category: BoostCategory,
     /// Boost magnitude in basis points (10 000 = 100 %).
     pub value: u32,
     /// Higher priority wins when two Override boosts compete.
     pub priority: u32,
     /// Ledger sequence number at which this boost expires.
-    /// `0` means the boost never expires.
+    /// `0` means no ledger bound.
+    pub expires_at_ledger: u32,
+    /// Ledger close time (Unix seconds) at which this boost expires.
+    /// `0` means no timestamp bound. When both bounds are set the boost
+    /// expires at whichever is reached first.
+    pub expires_at_timestamp: u64,
+    /// Uses left, for boosts spent by the game controller through
+    /// `consume_boost`. `None` means the boost is not use-limited.
+    pub charges: Option<u32>,
+}
+
+/// `Boost` as stored by 0.2.0 and earlier, before categories, timestamp
+/// expiry and charges. Lists in this layout are still read from
+/// `DataKey::PlayerBoosts` and converted with `Boost::from`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyBoost {
+    pub id: u128,
+    pub boost_type: BoostType,
+    pub value: u32,
+    pub priority: u32,
     pub expires_at_ledger: u32,
 }
 
+impl From<LegacyBoost> for Boost {
+    fn from(b: LegacyBoost) -> Self {
+        Boost {
+            id: b.id,
+            boost_type: b.boost_type,
+            category: BoostCategory::General,
+            value: b.value,
+            priority: b.priority,
+            expires_at_ledger: b.expires_at_ledger,
+            expires_at_timestamp: 0,
+            charges: None,
+        }
+    }
+}
+
+/// Per-player cap and multiplier ceiling applied by every boost path.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StackingConfig {
+    /// Active boosts a player may hold at once.
+    pub max_boosts_per_player: u32,
+    /// Largest multiplier any `calculate_*` view returns, in basis points.
+    pub max_multiplier_bps: u32,
+}
+
+/// Perk of a tycoon-collectibles token. Mirrors `tycoon_collectibles::Perk`;
+/// the discriminants must stay in sync with that contract.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Perk {
+    None = 0,
+    CashTiered = 1,
+    TaxRefund = 2,
+    RentBoost = 3,
+    PropertyDiscount = 4,
+    ExtraTurn = 5,
+    JailFree = 6,
+    DoubleRent = 7,
+    RollBoost = 8,
+    Teleport = 9,
+    Shield = 10,
+    RollExact = 11,
+}
+
+/// Catalog entry describing a boost. The admin grants it with
+/// `grant_from_template`; players activate it, and pay for it, with
+/// `activate_boost`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BoostTemplate {
+    pub boost_type: BoostType,
+    pub category: BoostCategory,
+    /// Boost magnitude in basis points (10 000 = 100 %).
+    pub value: u32,
+    pub priority: u32,
+    /// Lifetime in ledgers from activation. `0` means no ledger bound.
+    pub duration_ledgers: u32,
+    /// Lifetime in seconds from activation. `0` means no timestamp bound.
+    pub duration_seconds: u64,
+    /// Uses the activated boost starts with. `None` means not use-limited.
+    pub charges: Option<u32>,
+    /// Total boosts that may ever be created from this template, across
+    /// `activate_boost` and `grant_from_template`. `None` means unlimited.
+    pub max_grants: Option<u32>,
+    /// TYC charged by `activate_boost`. `0` means the template cannot be
+    /// bought with TYC.
+    pub tyc_price: i128,
+    /// Collectible perk accepted instead of TYC. `Perk::None` means the
+    /// template cannot be activated by burning a collectible.
+    pub perk: Perk,
+}
+
+/// How a player pays for `activate_boost`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BoostPayment {
+    /// Pay the template's `tyc_price` into this contract.
+    Tyc,
+    /// Burn one unit of the given tycoon-collectibles token.
+    Collectible(u128),
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     /// Admin address — set once during `initialize`, never changed.
     Admin,
-    /// Per-player boost list.
+    /// Per-player boost list in the 0.2.0 `LegacyBoost` layout. Read only;
+    /// replaced by `PlayerBoostsV2` the next time the player's list is written.
     PlayerBoosts(Address),
+    /// Per-player boost list.
+    PlayerBoostsV2(Address),
+    /// TYC token that `activate_boost` is paid in.
+    TycToken,
+    /// tycoon-collectibles contract whose tokens can be burned for boosts.
+    Collectibles,
+    /// Boost template by id.
+    Template(u32),
+    /// Ids of every stored template, in creation order.
+    TemplateIds,
+    /// Number of boosts ever created from a template id; survives removal.
+    TemplateGrants(u32),
+    /// Next id for a boost created from a template.
+    NextBoostId,
+    /// Game contract allowed to spend boost charges via `consume_boost`.
+    GameController,
+    /// `StackingConfig`; defaults apply while unset.
+    StackingConfig,
 }
 
 /// Emitted when a boost is successfully added to a player.
@@ -75,8 +249,11 @@ pub struct BoostActivatedEvent {
     #[topic]
     pub boost_id: u128,
     pub boost_type: BoostType,
+    pub category: BoostCategory,
     pub value: u32,
     pub expires_at_ledger: u32,
+    pub expires_at_timestamp: u64,
+    pub charges: Option<u32>,
 }
 
 /// Emitted when one or more expired boosts are pruned from a player's list.
@@ -84,7 +261,7 @@ pub struct BoostActivatedEvent {
 pub struct BoostExpiredEvent {
     #[topic]
     pub player: Address,
-    pub boost_id: u32,
+    pub boost_id: u128,
 }
 
 /// Emitted when all boosts are cleared for a player.
@@ -103,8 +280,11 @@ pub struct AdminBoostGrantedEvent {
     #[topic]
     pub boost_id: u128,
     pub boost_type: BoostType,
+    pub category: BoostCategory,
     pub value: u32,
     pub expires_at_ledger: u32,
+    pub expires_at_timestamp: u64,
+    pub charges: Option<u32>,
 }
 
 /// Emitted when the admin revokes a specific boost from a player.
@@ -116,6 +296,62 @@ pub struct AdminBoostRevokedEvent {
     pub boost_id: u128,
 }
 
+/// Emitted when the admin changes the per-player cap or multiplier ceiling.
+#[contractevent]
+pub struct StackingConfigUpdatedEvent {
+    pub max_boosts_per_player: u32,
+    pub max_multiplier_bps: u32,
+}
+
+/// Emitted when the game controller spends one charge of a boost.
+#[contractevent]
+pub struct BoostConsumedEvent {
+    #[topic]
+    pub player: Address,
+    #[topic]
+    pub boost_id: u128,
+    /// Charges left; `0` means the boost was removed.
+    pub remaining: u32,
+}
+
+/// Emitted when a player activates a boost from a template.
+#[contractevent]
+pub struct PlayerBoostActivatedEvent {
+    #[topic]
+    pub player: Address,
+    #[topic]
+    pub boost_id: u128,
+    pub template_id: u32,
+    pub category: BoostCategory,
+    pub payment: BoostPayment,
+    pub expires_at_ledger: u32,
+    pub expires_at_timestamp: u64,
+    pub charges: Option<u32>,
+}
+
+/// Emitted when the admin grants a boost from a template.
+#[contractevent]
+pub struct TemplateBoostGrantedEvent {
+    #[topic]
+    pub player: Address,
+    #[topic]
+    pub boost_id: u128,
+    pub template_id: u32,
+    pub category: BoostCategory,
+    pub expires_at_ledger: u32,
+    pub expires_at_timestamp: u64,
+    pub charges: Option<u32>,
+}
+
+/// Emitted when the admin creates or replaces a boost template.
+#[contractevent]
+pub struct BoostTemplateSetEvent {
+    #[topic]
+    pub template_id: u32,
+    pub tyc_price: i128,
+    pub perk: Perk,
+}
+
 /// Emitted when a deprecated function is called.
 /// Helps track migration progress and identify integrations that need updating.
 #[contractevent]
@@ -170,58 +406,30 @@ impl TycoonBoostSystem {
     /// `add_boost` apply (value > 0, expiry in the future, cap, no duplicate id).
     ///
     /// # Errors (panic messages)
-    /// - `"CapExceeded"` — player already holds `MAX_BOOSTS_PER_PLAYER` active boosts
+    /// - `"CapExceeded"` — player already holds `max_boosts_per_player` active boosts
     /// - `"DuplicateId"` — a boost with the same `id` is already active
-    /// - `"InvalidValue"` — `boost.value` is 0
-    /// - `"InvalidExpiry"` — `boost.expires_at_ledger` is non-zero and ≤ current ledger
+    /// - `"InvalidValue"` — `boost.value` is 0 or `boost.charges` is `Some(0)`
+    /// - `"InvalidId"` — `boost.id` is at or above `ACTIVATED_BOOST_ID_BASE`
+    /// - `"InvalidExpiry"` — `boost.expires_at_ledger` is non-zero and ≤ current ledger,
+    ///   or `boost.expires_at_timestamp` is non-zero and ≤ current timestamp
     pub fn admin_grant_boost(env: Env, player: Address, boost: Boost) {
         let admin = get_admin(&env);
         admin.require_auth();
 
-        // Validate value
-        if boost.value == 0 {
-            panic!("InvalidValue");
-        }
-
-        // Validate expiry: if set, must be strictly in the future
-        let current_ledger = env.ledger().sequence();
-        if boost.expires_at_ledger != 0 && boost.expires_at_ledger <= current_ledger {
-            panic!("InvalidExpiry");
-        }
-
-        let key = DataKey::PlayerBoosts(player.clone());
-        let mut boosts: Vec<Boost> = env
-            .storage()
-            .persistent()
-            .get(&key)
-            .unwrap_or(Vec::new(&env));
-
-        // Prune expired boosts before checking cap/duplicate
-        boosts = Self::prune_expired(&env, boosts, player.clone());
-
-        // Cap check
-        if boosts.len() >= MAX_BOOSTS_PER_PLAYER {
-            panic!("CapExceeded");
-        }
-
-        // Duplicate ID check
-        for i in 0..boosts.len() {
-            if boosts.get(i).unwrap().id == boost.id {
-                panic!("DuplicateId");
-            }
-        }
+        Self::require_admin_id(&boost);
+        Self::insert_boost(&env, &player, &boost);
 
         AdminBoostGrantedEvent {
             player: player.clone(),
             boost_id: boost.id,
             boost_type: boost.boost_type.clone(),
+            category: boost.category,
             value: boost.value,
             expires_at_ledger: boost.expires_at_ledger,
+            expires_at_timestamp: boost.expires_at_timestamp,
+            charges: boost.charges,
         }
         .publish(&env);
-
-        boosts.push_back(boost);
-        env.storage().persistent().set(&key, &boosts);
     }
 
     /// Revoke a specific boost from a player by boost id (admin only).
@@ -232,12 +440,7 @@ impl TycoonBoostSystem {
         let admin = get_admin(&env);
         admin.require_auth();
 
-        let key = DataKey::PlayerBoosts(player.clone());
-        let boosts: Vec<Boost> = env
-            .storage()
-            .persistent()
-            .get(&key)
-            .unwrap_or(Vec::new(&env));
+        let boosts = Self::read_boosts(&env, &player);
 
         let mut updated: Vec<Boost> = Vec::new(&env);
         let mut found = false;
@@ -252,7 +455,7 @@ impl TycoonBoostSystem {
         }
 
         if found {
-            env.storage().persistent().set(&key, &updated);
+            Self::write_boosts(&env, &player, &updated);
             AdminBoostRevokedEvent { player, boost_id }.publish(&env);
         }
     }
@@ -261,79 +464,366 @@ impl TycoonBoostSystem {
     pub fn admin(env: Env) -> Address {
         get_admin(&env)
     }
+
+    /// Set the per-player boost cap and the multiplier ceiling (admin only).
+    ///
+    /// Lowering the cap does not remove boosts a player already holds; it
+    /// only blocks new ones until the player is under the cap.
+    ///
+    /// # Errors (panic messages)
+    /// - `"InvalidValue"` — `max_boosts_per_player` is 0 or `max_multiplier_bps`
+    ///   is below the 10 000 bp base
+    pub fn admin_set_stacking_config(
+        env: Env,
+        max_boosts_per_player: u32,
+        max_multiplier_bps: u32,
+    ) {
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        if max_boosts_per_player == 0 || max_multiplier_bps < BASE_BPS {
+            panic!("InvalidValue");
+        }
+
+        env.storage().instance().set(
+            &DataKey::StackingConfig,
+            &StackingConfig {
+                max_boosts_per_player,
+                max_multiplier_bps,
+            },
+        );
+
+        StackingConfigUpdatedEvent {
+            max_boosts_per_player,
+            max_multiplier_bps,
+        }
+        .publish(&env);
+    }
+
+    /// Register the game contract allowed to spend boost charges through
+    /// `consume_boost` (admin only). Replaces any previous controller.
+    pub fn admin_set_game_controller(env: Env, controller: Address) {
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::GameController, &controller);
+    }
+
+    /// Return the registered game controller, if any.
+    pub fn game_controller(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::GameController)
+    }
+
+    /// Set the TYC token and tycoon-collectibles contract that
+    /// `activate_boost` accepts payment from (admin only).
+    pub fn admin_set_payment_contracts(env: Env, tyc_token: Address, collectibles: Address) {
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::TycToken, &tyc_token);
+        env.storage()
+            .instance()
+            .set(&DataKey::Collectibles, &collectibles);
+    }
+
+    /// Create or replace the boost template with `template_id` (admin only).
+    ///
+    /// Replacing or removing and re-creating a template keeps its grant
+    /// count, so `max_grants` still counts boosts created under the old
+    /// definition.
+    ///
+    /// # Errors (panic messages)
+    /// - `"InvalidValue"` — `template.value` is 0, `template.tyc_price` is negative,
+    ///   or `template.charges` / `template.max_grants` is `Some(0)`
+    pub fn admin_set_template(env: Env, template_id: u32, template: BoostTemplate) {
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        if template.value == 0
+            || template.tyc_price < 0
+            || template.charges == Some(0)
+            || template.max_grants == Some(0)
+        {
+            panic!("InvalidValue");
+        }
+
+        let key = DataKey::Template(template_id);
+        if !env.storage().persistent().has(&key) {
+            let mut ids = Self::template_ids(&env);
+            ids.push_back(template_id);
+            env.storage().persistent().set(&DataKey::TemplateIds, &ids);
+        }
+        env.storage().persistent().set(&key, &template);
+
+        BoostTemplateSetEvent {
+            template_id,
+            tyc_price: template.tyc_price,
+            perk: template.perk,
+        }
+        .publish(&env);
+    }
+
+    /// Remove a boost template (admin only). Boosts already created from it
+    /// are unaffected. The grant count is kept, so re-creating the template
+    /// under the same id cannot reset `max_grants`.
+    pub fn admin_remove_template(env: Env, template_id: u32) {
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        let mut ids = Self::template_ids(&env);
+        if let Some(index) = ids.first_index_of(template_id) {
+            ids.remove(index);
+            env.storage().persistent().set(&DataKey::TemplateIds, &ids);
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Template(template_id));
+    }
+
+    /// Grant a player the boost described by `template_id` (admin only).
+    ///
+    /// The contract assigns the boost's id and sets its expiry
+    /// `duration_ledgers` / `duration_seconds` from now. No payment is taken.
+    /// Returns the new boost's id.
+    ///
+    /// # Errors (panic messages)
+    /// - `"TemplateNotFound"` — no template with `template_id`
+    /// - `"MaxGrantsReached"` — the template's `max_grants` is used up
+    /// - `"CapExceeded"` — player already holds `max_boosts_per_player` active boosts
+    pub fn grant_from_template(env: Env, player: Address, template_id: u32) -> u128 {
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        let template = Self::read_template(&env, template_id);
+        let boost = Self::boost_from_template(&env, template_id, &template);
+        Self::insert_boost(&env, &player, &boost);
+
+        TemplateBoostGrantedEvent {
+            player,
+            boost_id: boost.id,
+            template_id,
+            category: boost.category,
+            expires_at_ledger: boost.expires_at_ledger,
+            expires_at_timestamp: boost.expires_at_timestamp,
+            charges: boost.charges,
+        }
+        .publish(&env);
+
+        boost.id
+    }
+
+    /// Send TYC collected by `activate_boost` to `to` (admin only).
+    ///
+    /// # Errors (panic messages)
+    /// - `"PaymentsNotConfigured"` — no TYC token has been set
+    pub fn admin_withdraw_tyc(env: Env, to: Address, amount: i128) {
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        let tyc: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::TycToken)
+            .expect("PaymentsNotConfigured");
+        token::Client::new(&env, &tyc).transfer(&env.current_contract_address(), &to, &amount);
+    }
 }
 
 // ── Public (player-initiated) entrypoints ─────────────────────────────────────
 
 #[contractimpl]
 impl TycoonBoostSystem {
-    /// Grant a boost to a player. Admin-only.
+    /// Activate the boost described by `template_id`, paid for by the player.
+    ///
+    /// The player either pays the template's `tyc_price` in TYC into this
+    /// contract, or burns one unit of a tycoon-collectibles token whose perk
+    /// matches the template's `perk`. The boost gets a contract-assigned id
+    /// and expires `duration_ledgers` / `duration_seconds` from now. The same
+    /// cap and duplicate-id checks as `admin_grant_boost` apply.
     ///
-    /// The `player` must authorize this call (self-service).
-    /// To grant a boost on behalf of a player, use `admin_grant_boost`.
+    /// The `player` must authorize this call. Returns the new boost's id.
     ///
     /// # Errors (panic messages)
-    /// - `"NotInitialized"` — contract has not been initialized
-    /// - `"CapExceeded"` — player already holds `MAX_BOOSTS_PER_PLAYER` active boosts
-    /// - `"DuplicateId"` — a boost with the same `id` is already active
-    /// - `"InvalidValue"` — `boost.value` is 0
-    /// - `"InvalidExpiry"` — `boost.expires_at_ledger` is non-zero and ≤ current ledger
-    pub fn add_boost(env: Env, player: Address, boost: Boost) {
-        Self::require_admin(&env);
+    /// - `"TemplateNotFound"` — no template with `template_id`
+    /// - `"MaxGrantsReached"` — the template's `max_grants` is used up
+    /// - `"PaymentNotAccepted"` — the template has no TYC price / accepted perk
+    /// - `"PaymentsNotConfigured"` — payment contracts have not been set
+    /// - `"PerkMismatch"` — the collectible's perk is not the template's perk
+    /// - `"CapExceeded"` — player already holds `max_boosts_per_player` active boosts
+    pub fn activate_boost(
+        env: Env,
+        player: Address,
+        template_id: u32,
+        payment: BoostPayment,
+    ) -> u128 {
+        player.require_auth();
+
+        let template = Self::read_template(&env, template_id);
+
+        let accepted = match payment {
+            BoostPayment::Tyc => template.tyc_price > 0,
+            BoostPayment::Collectible(_) => template.perk != Perk::None,
+        };
+        if !accepted {
+            panic!("PaymentNotAccepted");
+        }
 
-        if boost.value == 0 {
-            panic!("InvalidValue");
+        let boost = Self::boost_from_template(&env, template_id, &template);
+        Self::insert_boost(&env, &player, &boost);
+
+        Self::collect_payment(&env, &player, &template, &payment);
+
+        PlayerBoostActivatedEvent {
+            player,
+            boost_id: boost.id,
+            template_id,
+            category: boost.category,
+            payment,
+            expires_at_ledger: boost.expires_at_ledger,
+            expires_at_timestamp: boost.expires_at_timestamp,
+            charges: boost.charges,
         }
+        .publish(&env);
 
-        let current_ledger = env.ledger().sequence();
-        if boost.expires_at_ledger != 0 && boost.expires_at_ledger <= current_ledger {
-            panic!("InvalidExpiry");
+        boost.id
+    }
+
+    /// Spend one charge of a player's charge-based boost (game controller only).
+    ///
+    /// The boost is removed when its last charge is spent. Returns the charges
+    /// left.
+    ///
+    /// # Errors (panic messages)
+    /// - `"Unauthorized"` — `controller` is not the registered game controller
+    /// - `"BoostNotFound"` — the player holds no active boost with `boost_id`
+    /// - `"NotChargeBased"` — the boost has no `charges`
+    pub fn consume_boost(env: Env, controller: Address, player: Address, boost_id: u128) -> u32 {
+        controller.require_auth();
+        let registered: Option<Address> = env.storage().instance().get(&DataKey::GameController);
+        if registered != Some(controller) {
+            panic!("Unauthorized");
         }
 
-        let key = DataKey::PlayerBoosts(player.clone());
-        let mut boosts: Vec<Boost> = env
-            .storage()
-            .persistent()
-            .get(&key)
-            .unwrap_or(Vec::new(&env));
+        let mut boosts = Self::read_boosts(&env, &player);
 
-        boosts = Self::prune_expired(&env, boosts, player.clone());
+        let index = boosts
+            .iter()
+            .position(|b| b.id == boost_id && Self::is_active(&env, &b))
+            .expect("BoostNotFound") as u32;
+        let mut boost = boosts.get(index).unwrap();
+        let remaining = match boost.charges {
+            Some(charges) => charges - 1,
+            None => panic!("NotChargeBased"),
+        };
 
-        if boosts.len() >= MAX_BOOSTS_PER_PLAYER {
-            panic!("CapExceeded");
+        if remaining == 0 {
+            boosts.remove(index);
+        } else {
+            boost.charges = Some(remaining);
+            boosts.set(index, boost);
         }
+        Self::write_boosts(&env, &player, &boosts);
 
-        for i in 0..boosts.len() {
-            if boosts.get(i).unwrap().id == boost.id {
-                panic!("DuplicateId");
+        BoostConsumedEvent {
+            player,
+            boost_id,
+            remaining,
+        }
+        .publish(&env);
+
+        remaining
+    }
+
+    /// Return the boost template with `template_id`, if any.
+    pub fn get_template(env: Env, template_id: u32) -> Option<BoostTemplate> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Template(template_id))
+    }
+
+    /// Return page `page` (0-indexed) of the template catalog as
+    /// `(template_id, template)` pairs, in creation order. Pages past the end
+    /// are empty.
+    ///
+    /// # Errors (panic messages)
+    /// - `"InvalidPageSize"` — unless `1 <= page_size <= MAX_TEMPLATE_PAGE`
+    pub fn get_templates(env: Env, page: u32, page_size: u32) -> Vec<(u32, BoostTemplate)> {
+        if page_size == 0 || page_size > MAX_TEMPLATE_PAGE {
+            panic!("InvalidPageSize");
+        }
+
+        let ids = Self::template_ids(&env);
+        let mut result = Vec::new(&env);
+        let start = match page.checked_mul(page_size) {
+            Some(start) if start < ids.len() => start,
+            _ => return result,
+        };
+        for index in start..(start + page_size).min(ids.len()) {
+            let template_id = ids.get(index).unwrap();
+            if let Some(template) = Self::get_template(env.clone(), template_id) {
+                result.push_back((template_id, template));
             }
         }
+        result
+    }
+
+    /// Return the number of templates in the catalog.
+    pub fn template_count(env: Env) -> u32 {
+        Self::template_ids(&env).len()
+    }
+
+    /// Return how many boosts have been created from `template_id`.
+    pub fn template_grants(env: Env, template_id: u32) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TemplateGrants(template_id))
+            .unwrap_or(0)
+    }
+
+    /// Grant a boost to a player. Admin-only.
+    ///
+    /// Players activate boosts themselves through `activate_boost`.
+    ///
+    /// # Errors (panic messages)
+    /// - `"NotInitialized"` — contract has not been initialized
+    /// - `"CapExceeded"` — player already holds `max_boosts_per_player` active boosts
+    /// - `"DuplicateId"` — a boost with the same `id` is already active
+    /// - `"InvalidValue"` — `boost.value` is 0 or `boost.charges` is `Some(0)`
+    /// - `"InvalidId"` — `boost.id` is at or above `ACTIVATED_BOOST_ID_BASE`
+    /// - `"InvalidExpiry"` — `boost.expires_at_ledger` is non-zero and ≤ current ledger,
+    ///   or `boost.expires_at_timestamp` is non-zero and ≤ current timestamp
+    pub fn add_boost(env: Env, player: Address, boost: Boost) {
+        Self::require_admin(&env);
+
+        Self::require_admin_id(&boost);
+        Self::insert_boost(&env, &player, &boost);
 
         BoostActivatedEvent {
             player: player.clone(),
             boost_id: boost.id,
             boost_type: boost.boost_type.clone(),
+            category: boost.category,
             value: boost.value,
             expires_at_ledger: boost.expires_at_ledger,
+            expires_at_timestamp: boost.expires_at_timestamp,
+            charges: boost.charges,
         }
         .publish(&env);
-
-        boosts.push_back(boost);
-        env.storage().persistent().set(&key, &boosts);
     }
 
     /// Remove all boosts for a player. Admin-only.
     pub fn clear_boosts(env: Env, player: Address) {
         Self::require_admin(&env);
 
-        let key = DataKey::PlayerBoosts(player.clone());
-        let count = env
-            .storage()
+        let count = Self::read_boosts(&env, &player).len();
+        env.storage()
+            .persistent()
+            .remove(&DataKey::PlayerBoosts(player.clone()));
+        env.storage()
             .persistent()
-            .get::<DataKey, Vec<Boost>>(&key)
-            .map(|v| v.len())
-            .unwrap_or(0);
-        env.storage().persistent().remove(&key);
+            .remove(&DataKey::PlayerBoostsV2(player.clone()));
 
         BoostsClearedEvent { player, count }.publish(&env);
     }
@@ -351,39 +841,52 @@ impl TycoonBoostSystem {
         }
         .publish(&env);
 
-        let key = DataKey::PlayerBoosts(player.clone());
-        let boosts: Vec<Boost> = env
-            .storage()
-            .persistent()
-            .get(&key)
-            .unwrap_or(Vec::new(&env));
+        let boosts = Self::read_boosts(&env, &player);
 
         let before = boosts.len();
         let pruned = Self::prune_expired(&env, boosts, player.clone());
         let after = pruned.len();
-        env.storage().persistent().set(&key, &pruned);
+        Self::write_boosts(&env, &player, &pruned);
         before - after
     }
 
     /// Calculate the final boost multiplier for a player in basis points (10000 = 100%).
+    ///
+    /// Legacy view: only boosts in `BoostCategory::General` count. Use
+    /// `calculate_total_boost_for` for a specific effect.
     pub fn calculate_total_boost(env: Env, player: Address) -> u32 {
-        let key = DataKey::PlayerBoosts(player.clone());
-        let boosts: Vec<Boost> = env
-            .storage()
-            .persistent()
-            .get(&key)
-            .unwrap_or(Vec::new(&env));
+        Self::calculate_total_boost_for(env, player, BoostCategory::General)
+    }
 
-        let current_ledger = env.ledger().sequence();
-        let mut active: Vec<Boost> = Vec::new(&env);
-        for i in 0..boosts.len() {
-            let b = boosts.get(i).unwrap();
-            if b.expires_at_ledger == 0 || b.expires_at_ledger > current_ledger {
-                active.push_back(b);
+    /// Calculate the multiplier in basis points from the player's active
+    /// boosts in `category`. Returns 10 000 if there are none.
+    pub fn calculate_total_boost_for(env: Env, player: Address, category: BoostCategory) -> u32 {
+        let mut in_category: Vec<Boost> = Vec::new(&env);
+        for b in Self::active_boosts(&env, &player).iter() {
+            if b.category == category {
+                in_category.push_back(b);
             }
         }
+        Self::apply_stacking_rules(&env, in_category)
+    }
 
-        Self::apply_stacking_rules(&env, active)
+    /// Calculate the multiplier for every category in which the player has
+    /// at least one active boost. Categories without boosts are omitted and
+    /// stand at 10 000.
+    pub fn calculate_all_boosts(env: Env, player: Address) -> Map<BoostCategory, u32> {
+        let mut by_category: Map<BoostCategory, Vec<Boost>> = Map::new(&env);
+        for b in Self::active_boosts(&env, &player).iter() {
+            let category = b.category;
+            let mut group = by_category.get(category).unwrap_or_else(|| Vec::new(&env));
+            group.push_back(b);
+            by_category.set(category, group);
+        }
+
+        let mut totals: Map<BoostCategory, u32> = Map::new(&env);
+        for (category, group) in by_category.iter() {
+            totals.set(category, Self::apply_stacking_rules(&env, group));
+        }
+        totals
     }
 
     /// Get all boosts for a player (including expired ones still in storage).
@@ -413,33 +916,19 @@ impl TycoonBoostSystem {
         }
         .publish(&env);
 
-        let key = DataKey::PlayerBoosts(player);
-        env.storage()
-            .persistent()
-            .get(&key)
-            .unwrap_or(Vec::new(&env))
+        Self::read_boosts(&env, &player)
+    }
+
+    /// Return the per-player cap and multiplier ceiling in effect.
+    pub fn stacking_config(env: Env) -> StackingConfig {
+        Self::read_stacking_config(&env)
     }
 
     /// Get only the active (non-expired) boosts for a player.
     ///
     /// This is the recommended replacement for the deprecated `get_boosts`.
     pub fn get_active_boosts(env: Env, player: Address) -> Vec<Boost> {
-        let key = DataKey::PlayerBoosts(player.clone());
-        let boosts: Vec<Boost> = env
-            .storage()
-            .persistent()
-            .get(&key)
-            .unwrap_or(Vec::new(&env));
-
-        let current_ledger = env.ledger().sequence();
-        let mut active: Vec<Boost> = Vec::new(&env);
-        for i in 0..boosts.len() {
-            let b = boosts.get(i).unwrap();
-            if b.expires_at_ledger == 0 || b.expires_at_ledger > current_ledger {
-                active.push_back(b);
-            }
-        }
-        active
+        Self::active_boosts(&env, &player)
     }
 }
 
@@ -455,16 +944,220 @@ impl TycoonBoostSystem {
         admin.require_auth();
     }
 
+    /// Reject admin-assigned ids in the range reserved for template boosts,
+    /// so the two can never collide.
+    fn require_admin_id(boost: &Boost) {
+        if boost.id >= ACTIVATED_BOOST_ID_BASE {
+            panic!("InvalidId");
+        }
+    }
+
+    /// The player's stored boosts, converting a list still in the
+    /// `LegacyBoost` layout. Does not touch storage.
+    fn read_boosts(env: &Env, player: &Address) -> Vec<Boost> {
+        let storage = env.storage().persistent();
+        if let Some(boosts) = storage.get(&DataKey::PlayerBoostsV2(player.clone())) {
+            return boosts;
+        }
+        let legacy: Vec<LegacyBoost> = storage
+            .get(&DataKey::PlayerBoosts(player.clone()))
+            .unwrap_or(Vec::new(env));
+        let mut boosts = Vec::new(env);
+        for b in legacy.iter() {
+            boosts.push_back(Boost::from(b));
+        }
+        boosts
+    }
+
+    /// Store the player's boosts in the current layout, dropping any legacy
+    /// list they replace.
+    fn write_boosts(env: &Env, player: &Address, boosts: &Vec<Boost>) {
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::PlayerBoostsV2(player.clone()), boosts);
+        storage.remove(&DataKey::PlayerBoosts(player.clone()));
+    }
+
+    /// The player's stored boosts that have not expired and have charges left.
+    /// Does not touch storage.
+    fn active_boosts(env: &Env, player: &Address) -> Vec<Boost> {
+        let boosts = Self::read_boosts(env, player);
+
+        let mut active: Vec<Boost> = Vec::new(env);
+        for i in 0..boosts.len() {
+            let b = boosts.get(i).unwrap();
+            if Self::is_active(env, &b) {
+                active.push_back(b);
+            }
+        }
+        active
+    }
+
+    /// The stored `StackingConfig`, or the defaults if none has been set.
+    fn read_stacking_config(env: &Env) -> StackingConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::StackingConfig)
+            .unwrap_or(StackingConfig {
+                max_boosts_per_player: MAX_BOOSTS_PER_PLAYER,
+                max_multiplier_bps: DEFAULT_MAX_MULTIPLIER_BPS,
+            })
+    }
+
+    /// True while the boost is unexpired and, if charge-based, has charges left.
+    fn is_active(env: &Env, boost: &Boost) -> bool {
+        !Self::is_expired(env, boost) && boost.charges != Some(0)
+    }
+
+    /// True once either of the boost's expiry bounds has been reached.
+    fn is_expired(env: &Env, boost: &Boost) -> bool {
+        let ledger_reached =
+            boost.expires_at_ledger != 0 && boost.expires_at_ledger <= env.ledger().sequence();
+        let timestamp_reached = boost.expires_at_timestamp != 0
+            && boost.expires_at_timestamp <= env.ledger().timestamp();
+        ledger_reached || timestamp_reached
+    }
+
+    fn template_ids(env: &Env) -> Vec<u32> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TemplateIds)
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn read_template(env: &Env, template_id: u32) -> BoostTemplate {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Template(template_id))
+            .expect("TemplateNotFound")
+    }
+
+    /// Build a boost from `template`: count the grant against `max_grants`,
+    /// assign the next contract id and compute expiry from now.
+    fn boost_from_template(env: &Env, template_id: u32, template: &BoostTemplate) -> Boost {
+        let grants_key = DataKey::TemplateGrants(template_id);
+        let grants: u32 = env.storage().persistent().get(&grants_key).unwrap_or(0);
+        if let Some(max_grants) = template.max_grants {
+            if grants >= max_grants {
+                panic!("MaxGrantsReached");
+            }
+        }
+        env.storage().persistent().set(&grants_key, &(grants + 1));
+
+        let boost_id: u128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextBoostId)
+            .unwrap_or(ACTIVATED_BOOST_ID_BASE);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextBoostId, &(boost_id + 1));
+
+        Boost {
+            id: boost_id,
+            boost_type: template.boost_type.clone(),
+            category: template.category,
+            value: template.value,
+            priority: template.priority,
+            expires_at_ledger: if template.duration_ledgers == 0 {
+                0
+            } else {
+                env.ledger()
+                    .sequence()
+                    .saturating_add(template.duration_ledgers)
+            },
+            expires_at_timestamp: if template.duration_seconds == 0 {
+                0
+            } else {
+                env.ledger()
+                    .timestamp()
+                    .saturating_add(template.duration_seconds)
+            },
+            charges: template.charges,
+        }
+    }
+
+    /// Validate `boost` and append it to the player's list. Expired boosts are
+    /// pruned before the cap and duplicate-id checks. Shared by every path
+    /// that gives a player a boost.
+    fn insert_boost(env: &Env, player: &Address, boost: &Boost) {
+        if boost.value == 0 || boost.charges == Some(0) {
+            panic!("InvalidValue");
+        }
+
+        // Each expiry bound, if set, must be strictly in the future
+        if Self::is_expired(env, boost) {
+            panic!("InvalidExpiry");
+        }
+
+        let mut boosts = Self::prune_expired(env, Self::read_boosts(env, player), player.clone());
+
+        if boosts.len() >= Self::read_stacking_config(env).max_boosts_per_player {
+            panic!("CapExceeded");
+        }
+
+        for i in 0..boosts.len() {
+            if boosts.get(i).unwrap().id == boost.id {
+                panic!("DuplicateId");
+            }
+        }
+
+        boosts.push_back(boost.clone());
+        Self::write_boosts(env, player, &boosts);
+    }
+
+    /// Take the player's payment for `template`: transfer TYC in, or burn the
+    /// collectible after checking its perk.
+    fn collect_payment(
+        env: &Env,
+        player: &Address,
+        template: &BoostTemplate,
+        payment: &BoostPayment,
+    ) {
+        match payment {
+            BoostPayment::Tyc => {
+                let tyc: Address = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::TycToken)
+                    .expect("PaymentsNotConfigured");
+                token::Client::new(env, &tyc).transfer(
+                    player,
+                    env.current_contract_address(),
+                    &template.tyc_price,
+                );
+            }
+            BoostPayment::Collectible(token_id) => {
+                let collectibles: Address = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::Collectibles)
+                    .expect("PaymentsNotConfigured");
+                let perk: Perk = env.invoke_contract(
+                    &collectibles,
+                    &Symbol::new(env, "get_token_perk"),
+                    vec![env, token_id.into_val(env)],
+                );
+                if perk != template.perk {
+                    panic!("PerkMismatch");
+                }
+                env.invoke_contract::<()>(
+                    &collectibles,
+                    &Symbol::new(env, "burn_collectible_for_perk"),
+                    vec![env, player.into_val(env), token_id.into_val(env)],
+                );
+            }
+        }
+    }
+
     /// Remove expired boosts from `boosts`, emitting `BoostExpiredEvent` for each.
     fn prune_expired(env: &Env, boosts: Vec<Boost>, player: Address) -> Vec<Boost> {
-        let current_ledger = env.ledger().sequence();
         let mut active: Vec<Boost> = Vec::new(env);
         for i in 0..boosts.len() {
             let b = boosts.get(i).unwrap();
-            if b.expires_at_ledger != 0 && b.expires_at_ledger <= current_ledger {
+            if Self::is_expired(env, &b) {
                 BoostExpiredEvent {
                     player: player.clone(),
-                    boost_id: b.id as u32,
+                    boost_id: b.id,
                 }
                 .publish(env);
             } else {
@@ -474,13 +1167,16 @@ impl TycoonBoostSystem {
         active
     }
 
-    fn apply_stacking_rules(_env: &Env, boosts: Vec<Boost>) -> u32 {
+    /// Combine `boosts` into one multiplier in basis points. Intermediate
+    /// values saturate rather than wrap, and the result is capped at the
+    /// configured `max_multiplier_bps`.
+    fn apply_stacking_rules(env: &Env, boosts: Vec<Boost>) -> u32 {
         if boosts.is_empty() {
-            return 10000; // Base 100% in basis points
+            return BASE_BPS; // Base 100% in basis points
         }
 
-        let mut multiplicative_total: u32 = 10000;
-        let mut additive_total: u32 = 0;
+        let mut multiplicative_total: u128 = BASE_BPS as u128;
+        let mut additive_total: u128 = 0;
         let mut override_boost: Option<Boost> = None;
 
         for i in 0..boosts.len() {
@@ -489,10 +1185,10 @@ impl TycoonBoostSystem {
             match boost.boost_type {
                 BoostType::Multiplicative => {
                     multiplicative_total =
-                        (multiplicative_total as u64 * boost.value as u64 / 10000) as u32;
+                        multiplicative_total.saturating_mul(boost.value as u128) / BASE_BPS as u128;
                 }
                 BoostType::Additive => {
-                    additive_total += boost.value;
+                    additive_total = additive_total.saturating_add(boost.value as u128);
                 }
                 BoostType::Override => {
                     if let Some(ref current) = override_boost {
@@ -506,11 +1202,14 @@ impl TycoonBoostSystem {
             }
         }
 
-        if let Some(override_val) = override_boost {
-            override_val.value
+        let total: u128 = if let Some(override_val) = override_boost {
+            override_val.value as u128
         } else {
-            (multiplicative_total as u64 * (10000 + additive_total as u64) / 10000) as u32
-        }
+            multiplicative_total.saturating_mul(BASE_BPS as u128 + additive_total)
+                / BASE_BPS as u128
+        };
+        let ceiling = Self::read_stacking_config(env).max_multiplier_bps;
+        total.min(ceiling as u128) as u32
     }
 }
 
@@ -537,3 +1236,21 @@ mod security_review_tests;
 
 #[cfg(test)]
 mod simulation_scenarios;
+
+#[cfg(test)]
+mod category_tests;
+
+#[cfg(test)]
+mod activation_tests;
+
+#[cfg(test)]
+mod charge_tests;
+
+#[cfg(test)]
+mod stacking_config_tests;
+
+#[cfg(test)]
+mod template_catalog_tests;
+
+#[cfg(test)]
+mod storage_upgrade_tests;
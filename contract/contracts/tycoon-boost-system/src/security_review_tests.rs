@@ -33,6 +33,8 @@
 /// |------|-----------|
 /// | `stacking_no_overflow_at_max_cap`      | 10 max-value boosts do not overflow u32 |
 /// | `additive_sum_stays_in_u32`            | 10 × 10000 bp additive = 110000 fits u32 |
+/// | `test_additive_overflow_saturates`     | SEC-02: an additive sum past u32 saturates instead of wrapping |
+/// | `test_mixed_overflow_saturates`        | SEC-03: a chain past u32::MAX saturates instead of truncating |
 ///
 /// ## Expiry Semantics
 /// | Test | Assertion |
@@ -44,7 +46,8 @@
 mod tests {
     extern crate std;
     use crate::{
-        Boost, BoostType, TycoonBoostSystem, TycoonBoostSystemClient, MAX_BOOSTS_PER_PLAYER,
+        Boost, BoostCategory, BoostType, TycoonBoostSystem, TycoonBoostSystemClient,
+        MAX_BOOSTS_PER_PLAYER,
     };
     use soroban_sdk::{
         testutils::{Address as _, Ledger, LedgerInfo, MockAuth, MockAuthInvoke},
@@ -85,9 +88,12 @@ mod tests {
         Boost {
             id,
             boost_type: BoostType::Additive,
+            category: BoostCategory::General,
             value,
             priority: 0,
             expires_at_ledger: 0,
+            expires_at_timestamp: 0,
+            charges: None,
         }
     }
 
@@ -95,9 +101,12 @@ mod tests {
         Boost {
             id,
             boost_type: BoostType::Additive,
+            category: BoostCategory::General,
             value,
             priority: 0,
             expires_at_ledger: expires,
+            expires_at_timestamp: 0,
+            charges: None,
         }
     }
 
@@ -389,9 +398,12 @@ mod tests {
                 &Boost {
                     id: i as u128 + 1,
                     boost_type: BoostType::Multiplicative,
+                    category: BoostCategory::General,
                     value: 20000, // 2x
                     priority: 0,
                     expires_at_ledger: 0,
+                    expires_at_timestamp: 0,
+                    charges: None,
                 },
             );
         }
@@ -462,75 +474,54 @@ mod tests {
         assert_eq!(client.get_boosts(&player).len(), 1);
     }
 
-    // ── SEC-02: additive_total u32 wrapping overflow ──────────────────────────
+    // ── SEC-02: additive_total u32 wrapping overflow (fixed) ─────────────────
 
-    /// Documents that additive_total wraps on u32 overflow.
-    /// Pins current (wrapping) behavior so any future fix is visible.
+    /// An additive sum past `u32::MAX` saturates at the ceiling instead of
+    /// wrapping to a small value.
     #[test]
-    #[should_panic]
-    fn test_additive_overflow_wraps() {
+    fn test_additive_overflow_saturates() {
         let env = make_env();
         let (client, _, player) = setup(&env);
 
-        // Each value = 429_496_730 (≈ u32::MAX / 10 + 1)
-        // 10 × 429_496_730 = 4_294_967_300 which wraps to 4 in u32
+        // 10 × 429_496_730 = 4_294_967_300, which used to wrap to 4 in u32
         let per_boost: u32 = u32::MAX / 10 + 1;
         for i in 0..10u128 {
             client.add_boost(&player, &nb(i, per_boost));
         }
 
-        let total = client.calculate_total_boost(&player);
-        let correct_additive_sum = (per_boost as u64) * 10;
-        let expected_if_no_overflow = (10000u64 * (10000 + correct_additive_sum) / 10000) as u32;
-        assert_ne!(
-            total, expected_if_no_overflow,
-            "SEC-02: additive overflow no longer wraps — update checklist"
-        );
+        assert_eq!(client.calculate_total_boost(&player), u32::MAX);
     }
 
-    // ── SEC-03: mixed-stacking final cast truncation ──────────────────────────
+    // ── SEC-03: mixed-stacking final cast truncation (fixed) ──────────────────
 
-    /// Documents that the final `as u32` cast in apply_stacking_rules silently
-    /// truncates when the result exceeds u32::MAX.
-    /// Pins current behavior; a future fix will cause this test to fail.
+    /// A multiplicative chain whose result exceeds `u32::MAX` saturates
+    /// instead of being truncated by the final cast.
     #[test]
-    fn test_mixed_overflow_truncates() {
+    fn test_mixed_overflow_saturates() {
         let env = make_env();
         let (client, _, player) = setup(&env);
 
         let large = u32::MAX / 2;
-        client.add_boost(
-            &player,
-            &Boost {
-                id: 1,
-                boost_type: BoostType::Multiplicative,
-                value: large,
-                priority: 0,
-                expires_at_ledger: 0,
-            },
-        );
-        client.add_boost(
-            &player,
-            &Boost {
-                id: 2,
-                boost_type: BoostType::Multiplicative,
-                value: large,
-                priority: 0,
-                expires_at_ledger: 0,
-            },
-        );
-
-        let total = client.calculate_total_boost(&player);
-
-        let step1 = 10000u64 * large as u64 / 10000;
-        let step2 = step1 * large as u64 / 10000;
-        let correct_u64 = step2 * 10000 / 10000;
-
-        if correct_u64 > u32::MAX as u64 {
-            assert_eq!(
-                total, correct_u64 as u32,
-                "SEC-03: truncation behavior changed — update checklist"
+        for id in 1..=2u128 {
+            client.add_boost(
+                &player,
+                &Boost {
+                    id,
+                    boost_type: BoostType::Multiplicative,
+                    category: BoostCategory::General,
+                    value: large,
+                    priority: 0,
+                    expires_at_ledger: 0,
+                    expires_at_timestamp: 0,
+                    charges: None,
+                },
             );
         }
+
+        assert_eq!(client.calculate_total_boost(&player), u32::MAX);
+
+        // With a ceiling configured, the result saturates there instead.
+        client.admin_set_stacking_config(&MAX_BOOSTS_PER_PLAYER, &50_000);
+        assert_eq!(client.calculate_total_boost(&player), 50_000);
     }
 }
@@ -46,9 +46,12 @@ fn nb(id: u128, boost_type: BoostType, value: u32) -> Boost {
     Boost {
         id,
         boost_type,
+        category: BoostCategory::General,
         value,
         priority: 0,
         expires_at_ledger: 0,
+        expires_at_timestamp: 0,
+        charges: None,
     }
 }
 
@@ -56,9 +59,12 @@ fn eb(id: u128, boost_type: BoostType, value: u32, expires: u32) -> Boost {
     Boost {
         id,
         boost_type,
+        category: BoostCategory::General,
         value,
         priority: 0,
         expires_at_ledger: expires,
+        expires_at_timestamp: 0,
+        charges: None,
     }
 }
 
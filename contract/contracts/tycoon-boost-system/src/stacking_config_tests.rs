@@ -0,0 +1,204 @@
+/// # Stacking Config Tests
+///
+/// Covers `admin_set_stacking_config`, `stacking_config` and the saturating
+/// multiplier ceiling in `apply_stacking_rules`.
+///
+/// | Rule  | Behaviour |
+/// |-------|-----------|
+/// | STK-1 | Without a config the cap is `MAX_BOOSTS_PER_PLAYER` and the ceiling `DEFAULT_MAX_MULTIPLIER_BPS` |
+/// | STK-2 | A raised cap admits more boosts; a lowered cap keeps held boosts but blocks new ones |
+/// | STK-3 | Every stacking path saturates at `max_multiplier_bps`, per category |
+/// | STK-4 | The config is admin-only and validated |
+/// | STK-5 | `StackingConfigUpdatedEvent` carries the new settings |
+#[cfg(test)]
+extern crate std;
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    Env,
+};
+
+// ── helpers ───────────────────────────────────────────────────────────────────
+
+fn setup(env: &Env) -> (TycoonBoostSystemClient, Address) {
+    env.mock_all_auths();
+    let client = TycoonBoostSystemClient::new(env, &env.register(TycoonBoostSystem, ()));
+    client.initialize(&Address::generate(env));
+    (client, Address::generate(env))
+}
+
+fn boost(id: u128, category: BoostCategory, boost_type: BoostType, value: u32) -> Boost {
+    Boost {
+        id,
+        boost_type,
+        category,
+        value,
+        priority: 0,
+        expires_at_ledger: 0,
+        expires_at_timestamp: 0,
+        charges: None,
+    }
+}
+
+fn additive(id: u128, value: u32) -> Boost {
+    boost(id, BoostCategory::General, BoostType::Additive, value)
+}
+
+// ── STK-1 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_default_config() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    assert_eq!(
+        client.stacking_config(),
+        StackingConfig {
+            max_boosts_per_player: MAX_BOOSTS_PER_PLAYER,
+            max_multiplier_bps: DEFAULT_MAX_MULTIPLIER_BPS,
+        }
+    );
+}
+
+// ── STK-2 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_raised_cap_admits_more_boosts() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+    client.admin_set_stacking_config(&12, &DEFAULT_MAX_MULTIPLIER_BPS);
+
+    for i in 0..12 {
+        client.add_boost(&player, &additive(i, 100));
+    }
+    assert_eq!(client.get_active_boosts(&player).len(), 12);
+    assert!(client.try_add_boost(&player, &additive(12, 100)).is_err());
+}
+
+#[test]
+fn test_lowered_cap_keeps_held_boosts() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+    for i in 0..5 {
+        client.add_boost(&player, &additive(i, 100));
+    }
+
+    client.admin_set_stacking_config(&3, &DEFAULT_MAX_MULTIPLIER_BPS);
+
+    assert_eq!(client.get_active_boosts(&player).len(), 5);
+    assert_eq!(client.calculate_total_boost(&player), 10500);
+    assert!(client.try_add_boost(&player, &additive(5, 100)).is_err());
+
+    for i in 0..3 {
+        client.admin_revoke_boost(&player, &i);
+    }
+    client.add_boost(&player, &additive(5, 100));
+    assert_eq!(client.get_active_boosts(&player).len(), 3);
+}
+
+#[test]
+#[should_panic(expected = "CapExceeded")]
+fn test_lowered_cap_applies_to_admin_grant() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+    client.admin_set_stacking_config(&1, &DEFAULT_MAX_MULTIPLIER_BPS);
+    client.admin_grant_boost(&player, &additive(1, 100));
+    client.admin_grant_boost(&player, &additive(2, 100));
+}
+
+// ── STK-3 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_ceiling_applies_to_every_stacking_path() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+    client.admin_set_stacking_config(&MAX_BOOSTS_PER_PLAYER, &30_000);
+
+    client.add_boost(
+        &player,
+        &boost(1, BoostCategory::Rent, BoostType::Additive, 25_000),
+    );
+    client.add_boost(
+        &player,
+        &boost(2, BoostCategory::Roll, BoostType::Multiplicative, 20_000),
+    );
+    client.add_boost(
+        &player,
+        &boost(3, BoostCategory::Roll, BoostType::Multiplicative, 20_000),
+    );
+    client.add_boost(
+        &player,
+        &boost(4, BoostCategory::Cash, BoostType::Override, 50_000),
+    );
+    client.add_boost(
+        &player,
+        &boost(5, BoostCategory::Xp, BoostType::Additive, 5_000),
+    );
+
+    let all = client.calculate_all_boosts(&player);
+    assert_eq!(all.get(BoostCategory::Rent), Some(30_000));
+    assert_eq!(all.get(BoostCategory::Roll), Some(30_000));
+    assert_eq!(all.get(BoostCategory::Cash), Some(30_000));
+    // Totals under the ceiling are unchanged.
+    assert_eq!(all.get(BoostCategory::Xp), Some(15_000));
+}
+
+#[test]
+fn test_ceiling_at_base_flattens_boosts() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+    client.add_boost(&player, &additive(1, 5_000));
+
+    client.admin_set_stacking_config(&MAX_BOOSTS_PER_PLAYER, &10_000);
+
+    assert_eq!(client.calculate_total_boost(&player), 10_000);
+}
+
+// ── STK-4 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_config_validated() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    assert!(client.try_admin_set_stacking_config(&0, &20_000).is_err());
+    assert!(client.try_admin_set_stacking_config(&5, &9_999).is_err());
+    assert_eq!(
+        client.stacking_config().max_boosts_per_player,
+        MAX_BOOSTS_PER_PLAYER
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_config_is_admin_only() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    env.mock_auths(&[]);
+    client.admin_set_stacking_config(&20, &20_000);
+}
+
+// ── STK-5 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_config_event() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    client.admin_set_stacking_config(&20, &40_000);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let name: Symbol = topics.get(0).unwrap().into_val(&env);
+    let data: Map<Symbol, soroban_sdk::Val> = data.into_val(&env);
+    let cap: u32 = data
+        .get(Symbol::new(&env, "max_boosts_per_player"))
+        .unwrap()
+        .into_val(&env);
+    let ceiling: u32 = data
+        .get(Symbol::new(&env, "max_multiplier_bps"))
+        .unwrap()
+        .into_val(&env);
+    assert_eq!(name, Symbol::new(&env, "stacking_config_updated_event"));
+    assert_eq!((cap, ceiling), (20, 40_000));
+}
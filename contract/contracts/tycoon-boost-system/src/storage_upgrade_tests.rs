@@ -0,0 +1,185 @@
+/// # Storage Upgrade Tests
+///
+/// Covers boost lists written by 0.2.0 and earlier, in the `LegacyBoost`
+/// layout under `DataKey::PlayerBoosts`, after the contract is upgraded.
+///
+/// | Rule  | Behaviour |
+/// |-------|-----------|
+/// | UPG-1 | Legacy lists are readable: `General`, no timestamp bound, no charges |
+/// | UPG-2 | The first write moves the list to `PlayerBoostsV2` and drops the legacy entry |
+/// | UPG-3 | Revoke, clear and consume work on a legacy list |
+/// | UPG-4 | Expired legacy boosts are pruned on the first write |
+#[cfg(test)]
+extern crate std;
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Env,
+};
+
+// ── helpers ───────────────────────────────────────────────────────────────────
+
+fn setup(env: &Env) -> (TycoonBoostSystemClient, Address) {
+    env.mock_all_auths();
+    let client = TycoonBoostSystemClient::new(env, &env.register(TycoonBoostSystem, ()));
+    client.initialize(&Address::generate(env));
+    (client, Address::generate(env))
+}
+
+fn legacy(id: u128, value: u32, expires_at_ledger: u32) -> LegacyBoost {
+    LegacyBoost {
+        id,
+        boost_type: BoostType::Additive,
+        value,
+        priority: 0,
+        expires_at_ledger,
+    }
+}
+
+/// Write `boosts` as 0.2.0 stored them.
+fn seed_v1(env: &Env, client: &TycoonBoostSystemClient, player: &Address, boosts: &[LegacyBoost]) {
+    let mut list = Vec::new(env);
+    for b in boosts {
+        list.push_back(b.clone());
+    }
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::PlayerBoosts(player.clone()), &list);
+    });
+}
+
+/// Which of the two list keys hold an entry for `player`: `(legacy, v2)`.
+fn stored_keys(env: &Env, client: &TycoonBoostSystemClient, player: &Address) -> (bool, bool) {
+    env.as_contract(&client.address, || {
+        let storage = env.storage().persistent();
+        (
+            storage.has(&DataKey::PlayerBoosts(player.clone())),
+            storage.has(&DataKey::PlayerBoostsV2(player.clone())),
+        )
+    })
+}
+
+fn additive(id: u128, value: u32) -> Boost {
+    Boost {
+        id,
+        boost_type: BoostType::Additive,
+        category: BoostCategory::General,
+        value,
+        priority: 0,
+        expires_at_ledger: 0,
+        expires_at_timestamp: 0,
+        charges: None,
+    }
+}
+
+// ── UPG-1 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_legacy_list_readable() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+    seed_v1(
+        &env,
+        &client,
+        &player,
+        &[legacy(1, 1000, 0), legacy(2, 500, 0)],
+    );
+
+    let boosts = client.get_active_boosts(&player);
+    assert_eq!(boosts.len(), 2);
+    assert_eq!(boosts.get(0).unwrap(), additive(1, 1000));
+    assert_eq!(client.calculate_total_boost(&player), 11500);
+    assert_eq!(
+        client.calculate_total_boost_for(&player, &BoostCategory::Rent),
+        10000
+    );
+    // Views leave the legacy entry in place.
+    assert_eq!(stored_keys(&env, &client, &player), (true, false));
+}
+
+// ── UPG-2 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_first_write_moves_list() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+    seed_v1(&env, &client, &player, &[legacy(1, 1000, 0)]);
+
+    client.add_boost(&player, &additive(2, 500));
+
+    assert_eq!(stored_keys(&env, &client, &player), (false, true));
+    let boosts = client.get_active_boosts(&player);
+    assert_eq!(boosts.len(), 2);
+    assert_eq!(boosts.get(0).unwrap(), additive(1, 1000));
+    assert_eq!(client.calculate_total_boost(&player), 11500);
+}
+
+#[test]
+#[should_panic(expected = "DuplicateId")]
+fn test_legacy_ids_still_unique() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+    seed_v1(&env, &client, &player, &[legacy(1, 1000, 0)]);
+    client.add_boost(&player, &additive(1, 500));
+}
+
+// ── UPG-3 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_revoke_and_clear_legacy_list() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+    seed_v1(
+        &env,
+        &client,
+        &player,
+        &[legacy(1, 1000, 0), legacy(2, 500, 0)],
+    );
+
+    client.admin_revoke_boost(&player, &1);
+    assert_eq!(client.get_active_boosts(&player).len(), 1);
+    assert_eq!(stored_keys(&env, &client, &player), (false, true));
+
+    seed_v1(&env, &client, &player, &[legacy(3, 100, 0)]);
+    client.clear_boosts(&player);
+    assert_eq!(stored_keys(&env, &client, &player), (false, false));
+    assert!(client.get_active_boosts(&player).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "NotChargeBased")]
+fn test_legacy_boost_has_no_charges() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+    let controller = Address::generate(&env);
+    client.admin_set_game_controller(&controller);
+    seed_v1(&env, &client, &player, &[legacy(1, 1000, 0)]);
+    client.consume_boost(&controller, &player, &1);
+}
+
+// ── UPG-4 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_expired_legacy_boosts_pruned_on_write() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    seed_v1(
+        &env,
+        &client,
+        &player,
+        &[legacy(1, 1000, 50), legacy(2, 500, 200)],
+    );
+    assert_eq!(client.get_active_boosts(&player).len(), 1);
+
+    client.add_boost(&player, &additive(3, 100));
+
+    let ids: std::vec::Vec<u128> = client
+        .get_active_boosts(&player)
+        .iter()
+        .map(|b| b.id)
+        .collect();
+    assert_eq!(ids, [2, 3]);
+}
@@ -0,0 +1,315 @@
+/// # Template Catalog Tests
+///
+/// Covers `grant_from_template`, `BoostTemplate.max_grants` and the paginated
+/// catalog views `get_templates`, `template_count` and `template_grants`.
+///
+/// | Rule  | Behaviour |
+/// |-------|-----------|
+/// | TPL-1 | `grant_from_template` assigns a unique contract id and computes expiry from now; admin-assigned ids below `ACTIVATED_BOOST_ID_BASE` never collide with it |
+/// | TPL-2 | `max_grants` limits the boosts created from a template; failed grants do not count; replace and remove keep the count |
+/// | TPL-3 | `get_templates` pages the catalog in creation order; replace and remove keep the index exact |
+/// | TPL-4 | Granting is admin-only and rejects unknown templates; `max_grants` of zero is invalid |
+#[cfg(test)]
+extern crate std;
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    Env,
+};
+
+// ── helpers ───────────────────────────────────────────────────────────────────
+
+fn setup(env: &Env) -> (TycoonBoostSystemClient, Address) {
+    env.mock_all_auths();
+    let client = TycoonBoostSystemClient::new(env, &env.register(TycoonBoostSystem, ()));
+    client.initialize(&Address::generate(env));
+    (client, Address::generate(env))
+}
+
+fn template(value: u32, max_grants: Option<u32>) -> BoostTemplate {
+    BoostTemplate {
+        boost_type: BoostType::Additive,
+        category: BoostCategory::Rent,
+        value,
+        priority: 0,
+        duration_ledgers: 100,
+        duration_seconds: 3600,
+        charges: None,
+        max_grants,
+        tyc_price: 0,
+        perk: Perk::None,
+    }
+}
+
+// ── TPL-1 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_grant_assigns_id_and_expiry() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 500;
+        li.timestamp = 1_000_000;
+    });
+    client.admin_set_template(&1, &template(1000, None));
+
+    let boost_id = client.grant_from_template(&player, &1);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let name: Symbol = topics.get(0).unwrap().into_val(&env);
+    let data: Map<Symbol, soroban_sdk::Val> = data.into_val(&env);
+    let template_id: u32 = data
+        .get(Symbol::new(&env, "template_id"))
+        .unwrap()
+        .into_val(&env);
+    assert_eq!(name, Symbol::new(&env, "template_boost_granted_event"));
+    assert_eq!(template_id, 1);
+
+    assert_eq!(boost_id, ACTIVATED_BOOST_ID_BASE);
+    let boost = client.get_active_boosts(&player).get(0).unwrap();
+    assert_eq!(boost.id, boost_id);
+    assert_eq!(boost.category, BoostCategory::Rent);
+    assert_eq!(boost.expires_at_ledger, 600);
+    assert_eq!(boost.expires_at_timestamp, 1_003_600);
+    assert_eq!(
+        client.calculate_total_boost_for(&player, &BoostCategory::Rent),
+        11000
+    );
+}
+
+#[test]
+fn test_grant_ids_never_collide() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+    let other = Address::generate(&env);
+    client.admin_set_template(&1, &template(1000, None));
+    client.admin_set_template(&2, &template(500, None));
+
+    let a = client.grant_from_template(&player, &1);
+    let b = client.grant_from_template(&player, &1);
+    let c = client.grant_from_template(&other, &2);
+
+    assert_eq!((b, c), (a + 1, a + 2));
+    assert_eq!(client.get_active_boosts(&player).len(), 2);
+    assert_eq!(
+        client.calculate_total_boost_for(&player, &BoostCategory::Rent),
+        12000
+    );
+}
+
+#[test]
+fn test_admin_ids_in_template_range_rejected() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+    let boost = |id| Boost {
+        id,
+        boost_type: BoostType::Additive,
+        category: BoostCategory::Rent,
+        value: 1000,
+        priority: 0,
+        expires_at_ledger: 0,
+        expires_at_timestamp: 0,
+        charges: None,
+    };
+
+    assert!(client
+        .try_add_boost(&player, &boost(ACTIVATED_BOOST_ID_BASE))
+        .is_err());
+    assert!(client
+        .try_admin_grant_boost(&player, &boost(ACTIVATED_BOOST_ID_BASE))
+        .is_err());
+    client.admin_grant_boost(&player, &boost(ACTIVATED_BOOST_ID_BASE - 1));
+    client.admin_set_template(&1, &template(1000, None));
+    client.grant_from_template(&player, &1);
+    assert_eq!(client.get_active_boosts(&player).len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "InvalidId")]
+fn test_admin_id_in_template_range_panic_message() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+    client.add_boost(
+        &player,
+        &Boost {
+            id: ACTIVATED_BOOST_ID_BASE + 1,
+            boost_type: BoostType::Additive,
+            category: BoostCategory::Rent,
+            value: 1000,
+            priority: 0,
+            expires_at_ledger: 0,
+            expires_at_timestamp: 0,
+            charges: None,
+        },
+    );
+}
+
+#[test]
+fn test_expired_event_carries_full_id() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+    client.admin_set_template(&1, &template(1000, None));
+    let id = client.grant_from_template(&player, &1);
+
+    env.ledger().with_mut(|li| li.sequence_number += 100);
+    client.prune_expired_boosts(&player);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let name: Symbol = topics.get(0).unwrap().into_val(&env);
+    let data: Map<Symbol, soroban_sdk::Val> = data.into_val(&env);
+    let boost_id: u128 = data
+        .get(Symbol::new(&env, "boost_id"))
+        .unwrap()
+        .into_val(&env);
+    assert_eq!(name, Symbol::new(&env, "boost_expired_event"));
+    assert_eq!(boost_id, id);
+    assert_ne!(boost_id, 0);
+}
+
+// ── TPL-2 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_max_grants_enforced() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+    client.admin_set_template(&1, &template(1000, Some(2)));
+
+    client.grant_from_template(&player, &1);
+    client.grant_from_template(&Address::generate(&env), &1);
+
+    assert_eq!(client.template_grants(&1), 2);
+    assert!(client.try_grant_from_template(&player, &1).is_err());
+    assert_eq!(client.template_grants(&1), 2);
+}
+
+#[test]
+#[should_panic(expected = "MaxGrantsReached")]
+fn test_max_grants_panic_message() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+    client.admin_set_template(&1, &template(1000, Some(1)));
+    client.grant_from_template(&player, &1);
+    client.grant_from_template(&player, &1);
+}
+
+#[test]
+fn test_failed_grant_not_counted() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+    client.admin_set_stacking_config(&1, &DEFAULT_MAX_MULTIPLIER_BPS);
+    client.admin_set_template(&1, &template(1000, Some(2)));
+
+    client.grant_from_template(&player, &1);
+    assert!(client.try_grant_from_template(&player, &1).is_err());
+
+    assert_eq!(client.template_grants(&1), 1);
+    client.grant_from_template(&Address::generate(&env), &1);
+    assert_eq!(client.template_grants(&1), 2);
+}
+
+#[test]
+fn test_replace_and_remove_keep_grants() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+    client.admin_set_template(&1, &template(1000, Some(1)));
+    client.grant_from_template(&player, &1);
+
+    client.admin_set_template(&1, &template(2000, Some(1)));
+    assert!(client.try_grant_from_template(&player, &1).is_err());
+
+    client.admin_remove_template(&1);
+    assert_eq!(client.template_grants(&1), 1);
+    client.admin_set_template(&1, &template(2000, Some(1)));
+    assert!(client.try_grant_from_template(&player, &1).is_err());
+    assert_eq!(client.get_active_boosts(&player).len(), 1);
+
+    client.admin_set_template(&1, &template(2000, Some(2)));
+    client.grant_from_template(&player, &1);
+    assert_eq!(client.template_grants(&1), 2);
+}
+
+// ── TPL-3 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_templates_paginated_in_creation_order() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    for id in [30u32, 10, 20, 50, 40] {
+        client.admin_set_template(&id, &template(id * 10, None));
+    }
+
+    assert_eq!(client.template_count(), 5);
+    let ids = |page: u32| -> std::vec::Vec<u32> {
+        client
+            .get_templates(&page, &2)
+            .iter()
+            .map(|(id, _)| id)
+            .collect()
+    };
+    assert_eq!(ids(0), [30, 10]);
+    assert_eq!(ids(1), [20, 50]);
+    assert_eq!(ids(2), [40]);
+    assert!(ids(3).is_empty());
+    assert!(client.get_templates(&u32::MAX, &2).is_empty());
+
+    let (id, first) = client.get_templates(&0, &1).get(0).unwrap();
+    assert_eq!((id, first.value), (30, 300));
+}
+
+#[test]
+fn test_index_tracks_replace_and_remove() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    client.admin_set_template(&1, &template(1000, None));
+    client.admin_set_template(&2, &template(1000, None));
+    client.admin_set_template(&1, &template(3000, None));
+    assert_eq!(client.template_count(), 2);
+
+    client.admin_remove_template(&1);
+    client.admin_remove_template(&7);
+
+    let page = client.get_templates(&0, &MAX_TEMPLATE_PAGE);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().0, 2);
+}
+
+#[test]
+fn test_invalid_page_size_rejected() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    assert!(client.try_get_templates(&0, &0).is_err());
+    assert!(client
+        .try_get_templates(&0, &(MAX_TEMPLATE_PAGE + 1))
+        .is_err());
+    assert!(client.get_templates(&0, &MAX_TEMPLATE_PAGE).is_empty());
+}
+
+// ── TPL-4 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic]
+fn test_grant_is_admin_only() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+    client.admin_set_template(&1, &template(1000, None));
+    env.mock_auths(&[]);
+    client.grant_from_template(&player, &1);
+}
+
+#[test]
+#[should_panic(expected = "TemplateNotFound")]
+fn test_grant_unknown_template() {
+    let env = Env::default();
+    let (client, player) = setup(&env);
+    client.grant_from_template(&player, &1);
+}
+
+#[test]
+#[should_panic(expected = "InvalidValue")]
+fn test_zero_max_grants_rejected() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    client.admin_set_template(&1, &template(1000, Some(0)));
+}
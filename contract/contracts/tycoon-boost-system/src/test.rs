@@ -16,9 +16,12 @@ fn boost(id: u128, boost_type: BoostType, value: u32, priority: u32) -> Boost {
     Boost {
         id,
         boost_type,
+        category: BoostCategory::General,
         value,
         priority,
         expires_at_ledger: 0,
+        expires_at_timestamp: 0,
+        charges: None,
     }
 }
 
@@ -1,20 +1,23 @@
 /// # Time Boundary Tests (#408)
 ///
-/// Tests for edge-case behaviour at ledger sequence boundaries.
-/// All time-dependent on-chain logic uses `env.ledger().sequence()` (ledger sequence),
-/// never wall-clock time. See `contract/docs/TIME_BASED_LOGIC.md` for the full policy.
+/// Tests for edge-case behaviour at ledger sequence and ledger timestamp
+/// boundaries. A boost may carry a ledger bound, a timestamp bound, or both;
+/// it expires at whichever is reached first. See
+/// `contract/docs/TIME_BASED_LOGIC.md` for the full policy.
 ///
 /// Boundary rules under test:
-/// - `expires_at_ledger == 0`          → boost never expires (sentinel)
+/// - `expires_at_ledger == 0`          → no ledger bound (sentinel)
 /// - `expires_at_ledger <= current`    → boost is expired
 /// - `expires_at_ledger > current`     → boost is active
+/// - `expires_at_timestamp` follows the same three rules against
+///   `env.ledger().timestamp()`
 #[cfg(test)]
 extern crate std;
 
-use crate::{Boost, BoostType, TycoonBoostSystem, TycoonBoostSystemClient};
+use crate::{Boost, BoostCategory, BoostType, TycoonBoostSystem, TycoonBoostSystemClient};
 use soroban_sdk::{
-    testutils::{Address as _, Ledger, LedgerInfo},
-    Address, Env,
+    testutils::{Address as _, Events, Ledger, LedgerInfo},
+    Address, Env, IntoVal, Symbol,
 };
 
 // ── helpers ───────────────────────────────────────────────────────────────────
@@ -52,9 +55,28 @@ fn additive(id: u128, value: u32, expires_at_ledger: u32) -> Boost {
     Boost {
         id,
         boost_type: BoostType::Additive,
+        category: BoostCategory::General,
         value,
         priority: 0,
         expires_at_ledger,
+        expires_at_timestamp: 0,
+        charges: None,
+    }
+}
+
+/// Set the ledger sequence and timestamp independently, to model drift in
+/// ledger close times.
+fn set_time(env: &Env, seq: u32, timestamp: u64) {
+    env.ledger().with_mut(|li| {
+        li.sequence_number = seq;
+        li.timestamp = timestamp;
+    });
+}
+
+fn timed(id: u128, value: u32, expires_at_ledger: u32, expires_at_timestamp: u64) -> Boost {
+    Boost {
+        expires_at_timestamp,
+        ..additive(id, value, expires_at_ledger)
     }
 }
 
@@ -219,3 +241,112 @@ fn test_expiry_boundary_at_genesis() {
     set_seq(&env, 1);
     assert_eq!(client.calculate_total_boost(&player), 10000);
 }
+
+// ── timestamp bound ──────────────────────────────────────────────────────────
+
+/// A boost whose `expires_at_timestamp` equals the current ledger timestamp is
+/// expired; one second earlier it is still active.
+#[test]
+fn test_timestamp_expiry_boundary() {
+    let env = make_env();
+    let (client, player) = setup(&env);
+
+    set_time(&env, 100, 10_000);
+    client.add_boost(&player, &timed(1, 1000, 0, 96_400)); // 24 h later
+
+    set_time(&env, 101, 96_399);
+    assert_eq!(client.calculate_total_boost(&player), 11000);
+
+    set_time(&env, 102, 96_400);
+    assert_eq!(client.calculate_total_boost(&player), 10000);
+    assert!(client.get_active_boosts(&player).is_empty());
+}
+
+/// Timestamp expiry does not depend on how many ledgers have closed.
+#[test]
+fn test_timestamp_expiry_ignores_ledger_drift() {
+    let env = make_env();
+    let (client, player) = setup(&env);
+
+    set_time(&env, 100, 10_000);
+    client.add_boost(&player, &timed(1, 1000, 0, 20_000));
+
+    // Ledgers closed slower than expected: few sequences, lots of time.
+    set_time(&env, 110, 20_000);
+    assert_eq!(client.calculate_total_boost(&player), 10000);
+}
+
+/// With both bounds set, the ledger bound can be reached first.
+#[test]
+fn test_both_bounds_ledger_first() {
+    let env = make_env();
+    let (client, player) = setup(&env);
+
+    set_time(&env, 100, 10_000);
+    client.add_boost(&player, &timed(1, 1000, 200, 50_000));
+
+    set_time(&env, 199, 10_995);
+    assert_eq!(client.calculate_total_boost(&player), 11000);
+    set_time(&env, 200, 11_000);
+    assert_eq!(client.calculate_total_boost(&player), 10000);
+}
+
+/// With both bounds set, the timestamp bound can be reached first.
+#[test]
+fn test_both_bounds_timestamp_first() {
+    let env = make_env();
+    let (client, player) = setup(&env);
+
+    set_time(&env, 100, 10_000);
+    client.add_boost(&player, &timed(1, 1000, 200, 10_300));
+
+    set_time(&env, 150, 10_299);
+    assert_eq!(client.calculate_total_boost(&player), 11000);
+    set_time(&env, 151, 10_300);
+    assert_eq!(client.calculate_total_boost(&player), 10000);
+}
+
+/// A timestamp bound at or before the current timestamp is rejected, even if
+/// the ledger bound is still in the future.
+#[test]
+#[should_panic(expected = "InvalidExpiry")]
+fn test_timestamp_expiry_at_current_time_rejected() {
+    let env = make_env();
+    let (client, player) = setup(&env);
+
+    set_time(&env, 100, 10_000);
+    client.add_boost(&player, &timed(1, 1000, 200, 10_000));
+}
+
+#[test]
+#[should_panic(expected = "InvalidExpiry")]
+fn test_timestamp_expiry_in_past_rejected_on_admin_grant() {
+    let env = make_env();
+    let (client, player) = setup(&env);
+
+    set_time(&env, 100, 10_000);
+    client.admin_grant_boost(&player, &timed(1, 1000, 0, 9_999));
+}
+
+/// Boosts past their timestamp bound are pruned with `BoostExpiredEvent`,
+/// freeing their slot.
+#[test]
+fn test_timestamp_expired_boost_pruned_with_event() {
+    let env = make_env();
+    let (client, player) = setup(&env);
+
+    set_time(&env, 100, 10_000);
+    client.add_boost(&player, &timed(1, 1000, 0, 10_500));
+    client.add_boost(&player, &timed(2, 500, 0, 0));
+
+    set_time(&env, 101, 10_500);
+    client.add_boost(&player, &timed(3, 500, 0, 0));
+
+    // Pruning boost 1 emits `BoostExpiredEvent` before boost 3 is activated.
+    let events = env.events().all();
+    assert_eq!(events.len(), 2);
+    let (_, topics, _) = events.get(0).unwrap();
+    let name: Symbol = topics.get(0).unwrap().into_val(&env);
+    assert_eq!(name, Symbol::new(&env, "boost_expired_event"));
+    assert_eq!(client.get_boosts(&player).len(), 2);
+}
@@ -20,4 +20,6 @@ pub enum CollectibleError {
     InvalidPageSize = 14,
     InvalidURIType = 15,
     MetadataFrozen = 16,
+    InvalidPayment = 17,
+    InvalidPayload = 18,
 }
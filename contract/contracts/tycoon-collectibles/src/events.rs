@@ -150,3 +150,10 @@ pub fn emit_perk_activated_event(
         (token_id, perk, strength),
     );
 }
+
+/// Emit event when accrued shop fees are paid out to a recipient
+pub fn emit_pending_fees_withdrawn_event(env: &Env, recipient: &Address, amount: u128) {
+    #[allow(deprecated)]
+    env.events()
+        .publish((symbol_short!("fee_wd"), recipient.clone()), amount);
+}
@@ -14,7 +14,9 @@ pub use storage::*;
 pub use transfer::*;
 pub use types::*;
 
-use soroban_sdk::{contract, contractimpl, symbol_short, token, Address, Env, Vec};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, token, xdr::FromXdr, Address, Bytes, Env, Vec,
+};
 use tycoon_lib::fees::FeeConfig;
 
 /// Convert a u128 to a Soroban String without std (no_std compatible)
@@ -355,6 +357,97 @@ impl TycoonCollectibles {
         Ok(())
     }
 
+    /// Receiver hook for `TycoonToken::transfer_and_call`: buy one unit of a
+    /// shop collectible with TYC in a single transaction.
+    ///
+    /// Only the shop's TYC token may call this, and the payment is already in
+    /// the contract when it runs. `payload` is the XDR-encoded `u128` token id
+    /// and `amount` must equal its TYC price.
+    ///
+    /// Soroban forbids calling back into the token from inside its own call,
+    /// so the fee split cannot be paid out here. The platform, pool and
+    /// creator shares are accrued instead and paid by `withdraw_pending_fees`.
+    pub fn on_token_received(
+        env: Env,
+        from: Address,
+        amount: i128,
+        payload: Bytes,
+    ) -> Result<bool, CollectibleError> {
+        let shop_config = get_shop_config(&env).ok_or(CollectibleError::ShopNotInitialized)?;
+        shop_config.tyc_token.require_auth();
+
+        let token_id =
+            u128::from_xdr(&env, &payload).map_err(|_| CollectibleError::InvalidPayload)?;
+        let price = get_collectible_price(&env, token_id)
+            .ok_or(CollectibleError::ZeroPrice)?
+            .tyc_price;
+        if price <= 0 {
+            return Err(CollectibleError::ZeroPrice);
+        }
+        if amount != price {
+            return Err(CollectibleError::InvalidPayment);
+        }
+
+        let current_stock = get_shop_stock(&env, token_id);
+        if current_stock < 1 {
+            return Err(CollectibleError::InsufficientStock);
+        }
+
+        set_shop_stock(&env, token_id, current_stock - 1);
+        _safe_mint(&env, &from, token_id, 1)?;
+
+        if let Some(fee_config) = get_fee_config(&env) {
+            let split = tycoon_lib::fees::calculate_fee_split(price as u128, &fee_config);
+
+            add_pending_fees(&env, &fee_config.platform_address, split.platform_amount);
+            add_pending_fees(&env, &fee_config.pool_address, split.pool_amount);
+            add_pending_fees(&env, &get_admin(&env), split.creator_amount);
+
+            emit_fee_distributed_event(
+                &env,
+                token_id,
+                &fee_config.platform_address,
+                split.platform_amount,
+                &fee_config.pool_address,
+                split.pool_amount,
+                split.creator_amount,
+            );
+        }
+
+        emit_collectible_bought_event(&env, token_id, &from, price, false);
+
+        Ok(true)
+    }
+
+    /// Pay out TYC fees accrued for `recipient` by `on_token_received` purchases.
+    ///
+    /// Callable by anyone since the funds can only go to `recipient`.
+    /// Returns the amount paid (0 if nothing was owed).
+    pub fn withdraw_pending_fees(env: Env, recipient: Address) -> Result<u128, CollectibleError> {
+        let shop_config = get_shop_config(&env).ok_or(CollectibleError::ShopNotInitialized)?;
+
+        let amount = get_pending_fees(&env, &recipient);
+        if amount == 0 {
+            return Ok(0);
+        }
+
+        // CEI: clear before the external transfer
+        clear_pending_fees(&env, &recipient);
+        token::Client::new(&env, &shop_config.tyc_token).transfer(
+            &env.current_contract_address(),
+            &recipient,
+            &(amount as i128),
+        );
+
+        emit_pending_fees_withdrawn_event(&env, &recipient, amount);
+        Ok(amount)
+    }
+
+    /// Get the TYC fees accrued for `recipient` and not yet withdrawn
+    pub fn pending_fees(env: Env, recipient: Address) -> u128 {
+        get_pending_fees(&env, &recipient)
+    }
+
     pub fn buy_collectible(
         env: Env,
         buyer: Address,
@@ -805,3 +898,5 @@ mod coverage_tests;
 mod entrypoint_auth_tests;
 #[cfg(test)]
 mod test;
+#[cfg(test)]
+mod token_hook_tests;
@@ -140,6 +140,7 @@ const SHOP_CONFIG_KEY: &str = "SHOP_CFG";
 const FEE_CONFIG_KEY: &str = "FEE_CFG";
 const PRICE_PREFIX: &str = "PRICE";
 const STOCK_PREFIX: &str = "STOCK";
+const PENDING_FEE_PREFIX: &str = "PFEE";
 
 /// Check if shop configuration is set
 pub fn has_shop_config(env: &Env) -> bool {
@@ -194,6 +195,28 @@ pub fn get_shop_stock(env: &Env, token_id: u128) -> u64 {
     env.storage().persistent().get(&key).unwrap_or(0)
 }
 
+/// Get TYC fees accrued for a recipient but not yet withdrawn
+pub fn get_pending_fees(env: &Env, recipient: &Address) -> u128 {
+    let key = (PENDING_FEE_PREFIX, recipient.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Add to a recipient's accrued TYC fees
+pub fn add_pending_fees(env: &Env, recipient: &Address, amount: u128) {
+    if amount == 0 {
+        return;
+    }
+    let key = (PENDING_FEE_PREFIX, recipient.clone());
+    let current = get_pending_fees(env, recipient);
+    env.storage().persistent().set(&key, &(current + amount));
+}
+
+/// Clear a recipient's accrued TYC fees
+pub fn clear_pending_fees(env: &Env, recipient: &Address) {
+    let key = (PENDING_FEE_PREFIX, recipient.clone());
+    env.storage().persistent().remove(&key);
+}
+
 pub fn set_minter(env: &Env, minter: &Address) {
     env.storage().instance().set(&MINTER_KEY, minter);
 }
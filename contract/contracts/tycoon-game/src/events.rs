@@ -39,3 +39,58 @@ pub fn emit_ownership_transferred(env: &Env, old_owner: &Address, new_owner: &Ad
     #[allow(deprecated)]
     env.events().publish(topics, ());
 }
+
+/// Emit a StakedGameCreated event
+pub fn emit_staked_game_created(env: &Env, game_id: u128, stake: u128, max_players: u32) {
+    let topics = (Symbol::new(env, "StakedGameCreated"), game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, (stake, max_players));
+}
+
+/// Emit a PlayerJoinedGame event
+pub fn emit_player_joined_game(env: &Env, game_id: u128, player: &Address, stake: u128) {
+    let topics = (Symbol::new(env, "PlayerJoinedGame"), game_id, player);
+    #[allow(deprecated)]
+    env.events().publish(topics, stake);
+}
+
+/// Emit a StakedGameSettled event
+pub fn emit_staked_game_settled(env: &Env, game_id: u128, winner: &Address, pot: u128) {
+    let topics = (Symbol::new(env, "StakedGameSettled"), game_id, winner);
+    #[allow(deprecated)]
+    env.events().publish(topics, pot);
+}
+
+/// Emit a StakedGameCancelled event
+pub fn emit_staked_game_cancelled(env: &Env, game_id: u128, refunded_players: u32) {
+    let topics = (Symbol::new(env, "StakedGameCancelled"), game_id);
+    #[allow(deprecated)]
+    env.events().publish(topics, refunded_players);
+}
+
+/// Emit a PlayerReferred event
+pub fn emit_player_referred(env: &Env, referrer: &Address, player: &Address) {
+    let topics = (Symbol::new(env, "PlayerReferred"), referrer, player);
+    #[allow(deprecated)]
+    env.events().publish(topics, ());
+}
+
+/// Emit a GameRecorded event
+pub fn emit_game_recorded(env: &Env, player: &Address, won: bool, games_played: u32) {
+    let topics = (Symbol::new(env, "GameRecorded"), player);
+    #[allow(deprecated)]
+    env.events().publish(topics, (won, games_played));
+}
+
+/// Emit a ReferralRewardClaimed event
+pub fn emit_referral_reward_claimed(
+    env: &Env,
+    claimant: &Address,
+    player: &Address,
+    voucher_id: u128,
+    value: u128,
+) {
+    let topics = (Symbol::new(env, "ReferralRewardClaimed"), claimant, player);
+    #[allow(deprecated)]
+    env.events().publish(topics, (voucher_id, value));
+}
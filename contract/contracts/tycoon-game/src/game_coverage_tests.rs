@@ -16,7 +16,7 @@
 /// | GCT-06 | `admin_transfer_ownership` changes owner; old owner loses admin rights |
 /// | GCT-07 | `admin_set_collectible_info` overwrites existing entry correctly |
 /// | GCT-08 | `admin_set_cash_tier_value` stores and retrieves multiple tiers |
-/// | GCT-09 | `get_user` returns None for unregistered address |
+/// | GCT-09 | `get_user` returns None and `is_registered` false for unregistered address |
 /// | GCT-10 | `register_player` emits an event |
 #[cfg(test)]
 mod tests {
@@ -305,7 +305,8 @@ mod tests {
 
     // ── GCT-09 ───────────────────────────────────────────────────────────────
 
-    /// GCT-09: `get_user` returns `None` for an address that has never registered.
+    /// GCT-09: `get_user` returns `None` and `is_registered` returns `false` for an
+    /// address that has never registered.
     #[test]
     fn gct_09_get_user_unregistered_returns_none() {
         let env = Env::default();
@@ -317,6 +318,10 @@ mod tests {
             client.get_user(&stranger).is_none(),
             "GCT-09: unregistered address must return None"
         );
+        assert!(
+            !client.is_registered(&stranger),
+            "GCT-09: unregistered address must not be registered"
+        );
     }
 
     // ── GCT-10 ───────────────────────────────────────────────────────────────
@@ -329,7 +334,11 @@ mod tests {
         let (_, client, _, _, _) = setup(&env);
 
         let player = Address::generate(&env);
-        client.register_player(&soroban_sdk::String::from_str(&env, "tester"), &player);
+        client.register_player(
+            &soroban_sdk::String::from_str(&env, "tester"),
+            &player,
+            &None,
+        );
 
         let events = env.events().all();
         assert!(
@@ -4,12 +4,18 @@ mod events;
 pub(crate) mod storage;
 mod treasury;
 
-use soroban_sdk::{contract, contractimpl, token, Address, Env, IntoVal, String, Symbol};
+use soroban_sdk::{
+    contract, contractimpl, token, xdr::FromXdr, Address, Bytes, Env, IntoVal, String, Symbol, Vec,
+};
 use storage::{
-    get_backend_game_controller, get_owner, get_tyc_token, get_usdc_token, CollectibleInfo, User,
+    get_backend_game_controller, get_owner, get_tyc_token, get_usdc_token, CollectibleInfo,
+    ReferralConfig, StakedGame, User,
 };
 pub use treasury::TreasurySnapshot;
 
+/// Largest `page_size` accepted by `get_referrals`.
+pub const MAX_REFERRAL_PAGE: u32 = 50;
+
 #[contract]
 pub struct TycoonContract;
 
@@ -27,6 +33,22 @@ impl TycoonContract {
         owner.require_auth();
         owner
     }
+
+    /// Require `caller`'s signature and that it is the owner or the backend
+    /// game controller.
+    fn require_owner_or_controller(env: &Env, caller: &Address) {
+        caller.require_auth();
+
+        let owner = get_owner(env);
+        let backend_controller = get_backend_game_controller(env);
+
+        let is_owner = *caller == owner;
+        let is_backend_controller = backend_controller.as_ref().is_some_and(|c| caller == c);
+
+        if !is_owner && !is_backend_controller {
+            panic!("Unauthorized: caller must be owner or backend game controller");
+        }
+    }
 }
 
 // ── Admin-only entrypoints ────────────────────────────────────────────────────
@@ -170,6 +192,38 @@ impl TycoonContract {
             soroban_sdk::vec![&env, player.into_val(&env), 2_0000000u128.into_val(&env)],
         );
     }
+
+    /// Configure the referral program (admin only).
+    ///
+    /// Once a referred player has `games_required` recorded games, the
+    /// referrer may claim a `referrer_reward` voucher and the referred player
+    /// a `referee_reward` voucher. A referrer is never paid more than
+    /// `referrer_cap` in total.
+    ///
+    /// # Errors
+    /// - Panics with `"Games required must be positive"` if `games_required == 0`.
+    pub fn admin_set_referral_config(
+        env: Env,
+        games_required: u32,
+        referrer_reward: u128,
+        referee_reward: u128,
+        referrer_cap: u128,
+    ) {
+        Self::require_admin(&env);
+
+        if games_required == 0 {
+            panic!("Games required must be positive");
+        }
+        storage::set_referral_config(
+            &env,
+            &ReferralConfig {
+                games_required,
+                referrer_reward,
+                referee_reward,
+                referrer_cap,
+            },
+        );
+    }
 }
 
 // ── Public entrypoints ────────────────────────────────────────────────────────
@@ -181,10 +235,16 @@ impl TycoonContract {
 impl TycoonContract {
     /// Register a new player. The `caller` must authorize this call.
     ///
+    /// `referrer`, if given, must already be registered. Referrals are fixed
+    /// at registration and a referrer always registered first, so referral
+    /// chains cannot form cycles.
+    ///
     /// # Errors
     /// - Panics with `"Address already registered"` if `caller` is already registered.
     /// - Panics with `"Username must be 3-20 characters"` for invalid username length.
-    pub fn register_player(env: Env, username: String, caller: Address) {
+    /// - Panics with `"Cannot refer yourself"` if `referrer == caller`.
+    /// - Panics with `"Referrer not registered"` if `referrer` has no profile.
+    pub fn register_player(env: Env, username: String, caller: Address, referrer: Option<Address>) {
         caller.require_auth();
 
         if storage::is_registered(&env, &caller) {
@@ -196,6 +256,15 @@ impl TycoonContract {
             panic!("Username must be 3-20 characters");
         }
 
+        if let Some(referrer) = &referrer {
+            if *referrer == caller {
+                panic!("Cannot refer yourself");
+            }
+            if !storage::is_registered(&env, referrer) {
+                panic!("Referrer not registered");
+            }
+        }
+
         let user = User {
             id: env.ledger().sequence() as u64,
             username: username.clone(),
@@ -209,6 +278,106 @@ impl TycoonContract {
         storage::set_registered(&env, &caller);
         // OI-4: emit PlayerRegistered for off-chain indexing
         events::emit_player_registered(&env, &caller);
+
+        if let Some(referrer) = referrer {
+            storage::add_referral(&env, &referrer, &caller);
+            events::emit_player_referred(&env, &referrer, &caller);
+        }
+    }
+
+    /// Record a finished game for `player`, updating `games_played` and
+    /// `games_won`.
+    ///
+    /// Authorized callers: the stored `owner` **or** the `backend_game_controller`.
+    ///
+    /// # Errors
+    /// - Panics with `"Unauthorized: caller must be owner or backend game controller"`.
+    /// - Panics with `"Player not registered"` if `player` has no profile.
+    pub fn record_game(env: Env, caller: Address, player: Address, won: bool) {
+        Self::require_owner_or_controller(&env, &caller);
+
+        let mut user =
+            storage::get_user(&env, &player).unwrap_or_else(|| panic!("Player not registered"));
+        user.games_played += 1;
+        if won {
+            user.games_won += 1;
+        }
+        storage::set_user(&env, &player, &user);
+        events::emit_game_recorded(&env, &player, won, user.games_played);
+    }
+
+    /// Claim the referral reward for the referral of `player`, as either the
+    /// referrer or `player` themselves. Mints a voucher through the reward
+    /// system, which must have this contract set as its referral minter, and
+    /// returns its id.
+    ///
+    /// If the reward system's circuit breaker refuses the mint, returns 0
+    /// without recording the claim. The call does not panic, so the reward
+    /// system stays paused, and the claim stays open for a later retry.
+    ///
+    /// # Errors
+    /// - Panics with `"Referral program not configured"`.
+    /// - Panics with `"Player was not referred"` if `player` has no referrer.
+    /// - Panics with `"Not part of this referral"` if `claimant` is neither side.
+    /// - Panics with `"Referral not yet eligible"` before `games_required` games.
+    /// - Panics with `"Referral reward already claimed"` on a second claim.
+    /// - Panics with `"Referral payout cap reached"` if the referrer's total
+    ///   would exceed `referrer_cap`.
+    pub fn claim_referral_reward(env: Env, claimant: Address, player: Address) -> u128 {
+        claimant.require_auth();
+
+        let config = storage::get_referral_config(&env)
+            .unwrap_or_else(|| panic!("Referral program not configured"));
+        let referrer = storage::get_referrer(&env, &player)
+            .unwrap_or_else(|| panic!("Player was not referred"));
+        if claimant != referrer && claimant != player {
+            panic!("Not part of this referral");
+        }
+        if !Self::is_referral_eligible(env.clone(), player.clone()) {
+            panic!("Referral not yet eligible");
+        }
+        if storage::is_referral_claimed(&env, &player, &claimant) {
+            panic!("Referral reward already claimed");
+        }
+
+        let (value, paid) = if claimant == referrer {
+            let paid = storage::get_referral_paid(&env, &referrer)
+                .checked_add(config.referrer_reward)
+                .expect("Referral payout overflow");
+            if paid > config.referrer_cap {
+                panic!("Referral payout cap reached");
+            }
+            (config.referrer_reward, Some(paid))
+        } else {
+            (config.referee_reward, None)
+        };
+
+        let reward_system = storage::get_reward_system(&env);
+        let voucher_id: u128 = env.invoke_contract(
+            &reward_system,
+            &Symbol::new(&env, "mint_voucher"),
+            soroban_sdk::vec![
+                &env,
+                env.current_contract_address().into_val(&env),
+                claimant.into_val(&env),
+                value.into_val(&env),
+            ],
+        );
+
+        // The reward system's circuit breaker refuses a mint by returning 0.
+        // Returning instead of panicking keeps the breaker's pause.
+        if voucher_id == 0 {
+            return 0;
+        }
+
+        // Record the claim only once the voucher exists.
+        if let Some(paid) = paid {
+            storage::set_referral_paid(&env, &referrer, paid);
+        }
+        storage::set_referral_claimed(&env, &player, &claimant);
+
+        events::emit_referral_reward_claimed(&env, &claimant, &player, voucher_id, value);
+        voucher_id
     }
 
     /// Remove a player from an active game.
@@ -226,19 +395,152 @@ impl TycoonContract {
         player: Address,
         turn_count: u32,
     ) {
-        caller.require_auth();
+        Self::require_owner_or_controller(&env, &caller);
 
-        let owner = get_owner(&env);
-        let backend_controller = get_backend_game_controller(&env);
+        events::emit_player_removed_from_game(&env, game_id, &player, turn_count);
+    }
 
-        let is_owner = caller == owner;
-        let is_backend_controller = backend_controller.as_ref().is_some_and(|c| caller == *c);
+    /// Open a game that players join by staking exactly `stake` TYC.
+    ///
+    /// Authorized callers: the stored `owner` **or** the `backend_game_controller`.
+    /// Players join through `TycoonToken::transfer_and_call` with the
+    /// XDR-encoded `game_id` as payload; see `on_token_received`. The stakes
+    /// stay in this contract until `settle_staked_game` pays the winner or
+    /// `cancel_staked_game` refunds every player.
+    ///
+    /// If the TYC transfer tax is enabled, this contract must be tax-exempt
+    /// (`TycoonToken::set_tax_exempt`): otherwise the hook receives the stake
+    /// minus tax and every join panics with `"Incorrect stake amount"`.
+    ///
+    /// # Errors
+    /// - Panics with `"Unauthorized: caller must be owner or backend game controller"`.
+    /// - Panics with `"Game already exists"` if `game_id` is taken.
+    /// - Panics with `"Stake must be positive"` if `stake == 0`.
+    /// - Panics with `"Max players must be 2-8"` for an invalid seat count.
+    pub fn create_staked_game(
+        env: Env,
+        caller: Address,
+        game_id: u128,
+        stake: u128,
+        max_players: u32,
+    ) {
+        Self::require_owner_or_controller(&env, &caller);
 
-        if !is_owner && !is_backend_controller {
-            panic!("Unauthorized: caller must be owner or backend game controller");
+        if storage::get_staked_game(&env, game_id).is_some() {
+            panic!("Game already exists");
+        }
+        if stake == 0 {
+            panic!("Stake must be positive");
+        }
+        if !(2..=8).contains(&max_players) {
+            panic!("Max players must be 2-8");
         }
 
-        events::emit_player_removed_from_game(&env, game_id, &player, turn_count);
+        let game = StakedGame {
+            stake,
+            max_players,
+            players: Vec::new(&env),
+        };
+        storage::set_staked_game(&env, game_id, &game);
+        events::emit_staked_game_created(&env, game_id, stake, max_players);
+    }
+
+    /// Receiver hook for `TycoonToken::transfer_and_call`: join a staked game.
+    ///
+    /// Only the configured TYC token may call this; the tokens are already in
+    /// the contract when it runs. `payload` is the XDR-encoded `u128` game id
+    /// and `amount` must equal the game's stake. Any panic reverts the payment.
+    ///
+    /// # Errors
+    /// - Panics with `"Game does not exist"` if the game id is unknown.
+    /// - Panics with `"Player not registered"` if `from` has no profile.
+    /// - Panics with `"Incorrect stake amount"` if `amount` differs from the stake.
+    /// - Panics with `"Player already joined"` / `"Game is full"`.
+    pub fn on_token_received(env: Env, from: Address, amount: i128, payload: Bytes) -> bool {
+        get_tyc_token(&env).require_auth();
+
+        let game_id = u128::from_xdr(&env, &payload).unwrap_or_else(|_| panic!("Invalid payload"));
+        let mut game = storage::get_staked_game(&env, game_id)
+            .unwrap_or_else(|| panic!("Game does not exist"));
+
+        if !storage::is_registered(&env, &from) {
+            panic!("Player not registered");
+        }
+        if amount < 0 || amount as u128 != game.stake {
+            panic!("Incorrect stake amount");
+        }
+        if game.players.contains(&from) {
+            panic!("Player already joined");
+        }
+        if game.players.len() >= game.max_players {
+            panic!("Game is full");
+        }
+
+        game.players.push_back(from.clone());
+        storage::set_staked_game(&env, game_id, &game);
+        events::emit_player_joined_game(&env, game_id, &from, game.stake);
+        true
+    }
+
+    /// Pay the whole pot of a staked game to `winner` and close the game.
+    ///
+    /// Authorized callers: the stored `owner` **or** the `backend_game_controller`.
+    /// The pot is `stake × players`. Returns the amount paid.
+    ///
+    /// # Errors
+    /// - Panics with `"Unauthorized: caller must be owner or backend game controller"`.
+    /// - Panics with `"Game does not exist"` if the game id is unknown.
+    /// - Panics with `"Winner not in game"` if `winner` did not stake.
+    pub fn settle_staked_game(env: Env, caller: Address, game_id: u128, winner: Address) -> u128 {
+        Self::require_owner_or_controller(&env, &caller);
+
+        let game = storage::get_staked_game(&env, game_id)
+            .unwrap_or_else(|| panic!("Game does not exist"));
+        if !game.players.contains(&winner) {
+            panic!("Winner not in game");
+        }
+
+        let pot = game
+            .stake
+            .checked_mul(game.players.len() as u128)
+            .filter(|pot| *pot <= i128::MAX as u128)
+            .expect("Pot overflow");
+        storage::remove_staked_game(&env, game_id);
+
+        token::Client::new(&env, &get_tyc_token(&env)).transfer(
+            &env.current_contract_address(),
+            &winner,
+            &(pot as i128),
+        );
+        events::emit_staked_game_settled(&env, game_id, &winner, pot);
+        pot
+    }
+
+    /// Refund every player's stake and close the game.
+    ///
+    /// Authorized callers: the stored `owner` **or** the `backend_game_controller`.
+    ///
+    /// # Errors
+    /// - Panics with `"Unauthorized: caller must be owner or backend game controller"`.
+    /// - Panics with `"Game does not exist"` if the game id is unknown.
+    pub fn cancel_staked_game(env: Env, caller: Address, game_id: u128) {
+        Self::require_owner_or_controller(&env, &caller);
+
+        let game = storage::get_staked_game(&env, game_id)
+            .unwrap_or_else(|| panic!("Game does not exist"));
+        storage::remove_staked_game(&env, game_id);
+
+        let tyc = token::Client::new(&env, &get_tyc_token(&env));
+        let contract_address = env.current_contract_address();
+        for player in game.players.iter() {
+            tyc.transfer(&contract_address, &player, &(game.stake as i128));
+        }
+        events::emit_staked_game_cancelled(&env, game_id, game.players.len());
+    }
+
+    /// Return a staked game, or `None` if `game_id` is unknown.
+    pub fn get_staked_game(env: Env, game_id: u128) -> Option<StakedGame> {
+        storage::get_staked_game(&env, game_id)
     }
 
     /// Return the stored profile for `address`, or `None` if not registered.
@@ -246,6 +548,12 @@ impl TycoonContract {
         storage::get_user(&env, &address)
     }
 
+    /// Return whether `address` has registered. The reward system calls this
+    /// to gate daily streak claims.
+    pub fn is_registered(env: Env, address: Address) -> bool {
+        storage::is_registered(&env, &address)
+    }
+
     /// Return the metadata tuple `(perk, strength, tyc_price, usdc_price, shop_stock)`
     /// for a collectible.
     ///
@@ -275,6 +583,63 @@ impl TycoonContract {
         }
     }
 
+    /// Return the referral program parameters, or `None` if not configured.
+    pub fn get_referral_config(env: Env) -> Option<ReferralConfig> {
+        storage::get_referral_config(&env)
+    }
+
+    /// Return the player who referred `player`, if any.
+    pub fn get_referrer(env: Env, player: Address) -> Option<Address> {
+        storage::get_referrer(&env, &player)
+    }
+
+    /// Return how many players `referrer` has referred.
+    pub fn get_referral_count(env: Env, referrer: Address) -> u32 {
+        storage::get_referral_count(&env, &referrer)
+    }
+
+    /// Return page `page` (0-indexed) of the players `referrer` has referred,
+    /// in registration order. Pages past the end are empty.
+    ///
+    /// # Errors
+    /// - Panics with `"Invalid page size"` unless `1 <= page_size <= MAX_REFERRAL_PAGE`.
+    pub fn get_referrals(env: Env, referrer: Address, page: u32, page_size: u32) -> Vec<Address> {
+        if page_size == 0 || page_size > MAX_REFERRAL_PAGE {
+            panic!("Invalid page size");
+        }
+
+        let count = storage::get_referral_count(&env, &referrer);
+        let mut result = Vec::new(&env);
+        let start = match page.checked_mul(page_size) {
+            Some(start) if start < count => start,
+            _ => return result,
+        };
+        for index in start..(start + page_size).min(count) {
+            if let Some(player) = storage::get_referral(&env, &referrer, index) {
+                result.push_back(player);
+            }
+        }
+        result
+    }
+
+    /// Return the total referral value paid to `referrer` so far.
+    pub fn get_referral_paid(env: Env, referrer: Address) -> u128 {
+        storage::get_referral_paid(&env, &referrer)
+    }
+
+    /// Return whether the referral of `player` has unlocked its rewards.
+    pub fn is_referral_eligible(env: Env, player: Address) -> bool {
+        let config = match storage::get_referral_config(&env) {
+            Some(config) => config,
+            None => return false,
+        };
+        if storage::get_referrer(&env, &player).is_none() {
+            return false;
+        }
+        storage::get_user(&env, &player)
+            .is_some_and(|user| user.games_played >= config.games_required)
+    }
+
     /// Export a snapshot of critical contract state for debugging / support.
     ///
     /// This is a read-only view; no auth is required.
@@ -359,3 +724,9 @@ mod admin_access_control_tests;
 
 #[cfg(test)]
 mod deprecated_entrypoints_tests;
+
+#[cfg(test)]
+mod staked_game_tests;
+
+#[cfg(test)]
+mod referral_tests;
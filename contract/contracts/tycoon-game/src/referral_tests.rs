@@ -0,0 +1,397 @@
+/// # Referral Tests — tycoon-game
+///
+/// Covers the `referrer` argument of `register_player`, `record_game`,
+/// `admin_set_referral_config`, `claim_referral_reward` and the referral
+/// views. Rewards go to a mock reward system that records each
+/// `mint_voucher` call; the real reward system is exercised in
+/// `integration-tests/src/referral_flow.rs`.
+///
+/// ## Scenarios
+///
+/// | ID     | Scenario |
+/// |--------|----------|
+/// | REF-01 | A registered referrer is stored and listed; `PlayerReferred` is emitted |
+/// | REF-02 | Self-referral and unregistered referrers are rejected |
+/// | REF-03 | `record_game` counts games and wins; owner or controller only |
+/// | REF-04 | Both sides claim once the referee has played enough games |
+/// | REF-05 | Early, repeated and third-party claims are rejected |
+/// | REF-06 | The referrer payout cap is enforced across referrals |
+/// | REF-07 | Config is admin-only and validated; claims need a config |
+/// | REF-08 | `get_referrals` pages in registration order |
+/// | REF-09 | A mint refused by the reward system's breaker returns 0 and leaves the claim open |
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::{TycoonContract, TycoonContractClient, MAX_REFERRAL_PAGE};
+    use soroban_sdk::{
+        contract, contractimpl, symbol_short,
+        testutils::{Address as _, Events},
+        Address, Env, IntoVal, String, Symbol, Vec,
+    };
+
+    /// Stand-in for the reward system: records `(to, value)` per minted voucher.
+    /// While `refuse` is set it returns 0 without minting, like a tripped
+    /// circuit breaker.
+    #[contract]
+    pub struct MockRewardSystem;
+
+    #[contractimpl]
+    impl MockRewardSystem {
+        pub fn mint_voucher(env: Env, _caller: Address, to: Address, value: u128) -> u128 {
+            if env.storage().instance().has(&symbol_short!("refuse")) {
+                return 0;
+            }
+            let key = symbol_short!("minted");
+            let mut minted: Vec<(Address, u128)> = env
+                .storage()
+                .instance()
+                .get(&key)
+                .unwrap_or_else(|| Vec::new(&env));
+            minted.push_back((to, value));
+            env.storage().instance().set(&key, &minted);
+            minted.len() as u128
+        }
+
+        pub fn refuse(env: Env, refuse: bool) {
+            if refuse {
+                env.storage()
+                    .instance()
+                    .set(&symbol_short!("refuse"), &true);
+            } else {
+                env.storage().instance().remove(&symbol_short!("refuse"));
+            }
+        }
+
+        pub fn minted(env: Env) -> Vec<(Address, u128)> {
+            env.storage()
+                .instance()
+                .get(&symbol_short!("minted"))
+                .unwrap_or_else(|| Vec::new(&env))
+        }
+    }
+
+    struct H<'a> {
+        env: Env,
+        client: TycoonContractClient<'a>,
+        reward: MockRewardSystemClient<'a>,
+        owner: Address,
+        referrer: Address,
+    }
+
+    impl H<'_> {
+        /// Game wired to the mock reward system with a registered `referrer`
+        /// and a config of 3 games, rewards 100 / 50 and cap 250.
+        fn new() -> Self {
+            let env = Env::default();
+            env.mock_all_auths();
+            let client = TycoonContractClient::new(&env, &env.register(TycoonContract, ()));
+            let reward_id = env.register(MockRewardSystem, ());
+            let owner = Address::generate(&env);
+            client.initialize(
+                &Address::generate(&env),
+                &Address::generate(&env),
+                &owner,
+                &reward_id,
+            );
+            client.admin_set_referral_config(&3, &100, &50, &250);
+            let referrer = Address::generate(&env);
+            client.register_player(&String::from_str(&env, "referrer"), &referrer, &None);
+            H {
+                reward: MockRewardSystemClient::new(&env, &reward_id),
+                env,
+                client,
+                owner,
+                referrer,
+            }
+        }
+
+        /// Register a new player referred by `self.referrer`.
+        fn referee(&self) -> Address {
+            let player = Address::generate(&self.env);
+            self.client.register_player(
+                &String::from_str(&self.env, "referee"),
+                &player,
+                &Some(self.referrer.clone()),
+            );
+            player
+        }
+
+        fn play(&self, player: &Address, games: u32) {
+            for _ in 0..games {
+                self.client.record_game(&self.owner, player, &false);
+            }
+        }
+    }
+
+    // ── REF-01 ───────────────────────────────────────────────────────────────
+
+    #[test]
+    fn ref_01_referrer_stored_and_listed() {
+        let h = H::new();
+        let player = h.referee();
+
+        let (_, topics, _) = h.env.events().all().last().unwrap();
+        let name: Symbol = topics.get(0).unwrap().into_val(&h.env);
+        let referrer: Address = topics.get(1).unwrap().into_val(&h.env);
+        assert_eq!(name, Symbol::new(&h.env, "PlayerReferred"));
+        assert_eq!(referrer, h.referrer);
+
+        assert_eq!(h.client.get_referrer(&player), Some(h.referrer.clone()));
+        assert_eq!(h.client.get_referral_count(&h.referrer), 1);
+        assert_eq!(
+            h.client.get_referrals(&h.referrer, &0, &MAX_REFERRAL_PAGE),
+            soroban_sdk::vec![&h.env, player]
+        );
+        assert_eq!(h.client.get_referrer(&h.referrer), None);
+    }
+
+    // ── REF-02 ───────────────────────────────────────────────────────────────
+
+    #[test]
+    #[should_panic(expected = "Cannot refer yourself")]
+    fn ref_02_self_referral_rejected() {
+        let h = H::new();
+        let player = Address::generate(&h.env);
+        h.client.register_player(
+            &String::from_str(&h.env, "selfish"),
+            &player,
+            &Some(player.clone()),
+        );
+    }
+
+    #[test]
+    fn ref_02_unregistered_referrer_rejected() {
+        let h = H::new();
+        let player = Address::generate(&h.env);
+        let stranger = Address::generate(&h.env);
+        assert!(h
+            .client
+            .try_register_player(
+                &String::from_str(&h.env, "player"),
+                &player,
+                &Some(stranger)
+            )
+            .is_err());
+        assert!(h.client.get_user(&player).is_none());
+    }
+
+    // ── REF-03 ───────────────────────────────────────────────────────────────
+
+    #[test]
+    fn ref_03_record_game_counts_games_and_wins() {
+        let h = H::new();
+        let controller = Address::generate(&h.env);
+        h.client.admin_set_game_controller(&controller);
+
+        h.client.record_game(&h.owner, &h.referrer, &true);
+        h.client.record_game(&controller, &h.referrer, &false);
+
+        let user = h.client.get_user(&h.referrer).unwrap();
+        assert_eq!((user.games_played, user.games_won), (2, 1));
+    }
+
+    #[test]
+    fn ref_03_record_game_access_control() {
+        let h = H::new();
+        assert!(h
+            .client
+            .try_record_game(&Address::generate(&h.env), &h.referrer, &true)
+            .is_err());
+        assert!(h
+            .client
+            .try_record_game(&h.owner, &Address::generate(&h.env), &true)
+            .is_err());
+    }
+
+    // ── REF-04 ───────────────────────────────────────────────────────────────
+
+    #[test]
+    fn ref_04_both_sides_claim_when_eligible() {
+        let h = H::new();
+        let player = h.referee();
+        h.play(&player, 3);
+        assert!(h.client.is_referral_eligible(&player));
+
+        h.client.claim_referral_reward(&h.referrer, &player);
+        h.client.claim_referral_reward(&player, &player);
+
+        let (_, topics, data) = h.env.events().all().last().unwrap();
+        let name: Symbol = topics.get(0).unwrap().into_val(&h.env);
+        let data: (u128, u128) = data.into_val(&h.env);
+        assert_eq!(name, Symbol::new(&h.env, "ReferralRewardClaimed"));
+        assert_eq!(data, (2, 50));
+
+        assert_eq!(
+            h.reward.minted(),
+            soroban_sdk::vec![
+                &h.env,
+                (h.referrer.clone(), 100u128),
+                (player.clone(), 50u128)
+            ]
+        );
+        assert_eq!(h.client.get_referral_paid(&h.referrer), 100);
+    }
+
+    // ── REF-05 ───────────────────────────────────────────────────────────────
+
+    #[test]
+    #[should_panic(expected = "Referral not yet eligible")]
+    fn ref_05_early_claim_rejected() {
+        let h = H::new();
+        let player = h.referee();
+        h.play(&player, 2);
+        h.client.claim_referral_reward(&h.referrer, &player);
+    }
+
+    #[test]
+    #[should_panic(expected = "Referral reward already claimed")]
+    fn ref_05_second_claim_rejected() {
+        let h = H::new();
+        let player = h.referee();
+        h.play(&player, 3);
+        h.client.claim_referral_reward(&player, &player);
+        h.client.claim_referral_reward(&player, &player);
+    }
+
+    #[test]
+    fn ref_05_outsiders_and_unreferred_rejected() {
+        let h = H::new();
+        let player = h.referee();
+        h.play(&player, 3);
+
+        assert!(h
+            .client
+            .try_claim_referral_reward(&Address::generate(&h.env), &player)
+            .is_err());
+        assert!(h
+            .client
+            .try_claim_referral_reward(&h.referrer, &h.referrer)
+            .is_err());
+        assert!(h.reward.minted().is_empty());
+    }
+
+    // ── REF-06 ───────────────────────────────────────────────────────────────
+
+    #[test]
+    fn ref_06_referrer_cap_enforced() {
+        let h = H::new();
+        let players: std::vec::Vec<Address> = (0..3).map(|_| h.referee()).collect();
+        for player in &players {
+            h.play(player, 3);
+        }
+
+        h.client.claim_referral_reward(&h.referrer, &players[0]);
+        h.client.claim_referral_reward(&h.referrer, &players[1]);
+        assert!(h
+            .client
+            .try_claim_referral_reward(&h.referrer, &players[2])
+            .is_err());
+        assert_eq!(h.client.get_referral_paid(&h.referrer), 200);
+
+        // The cap only limits the referrer; the referee still gets paid.
+        h.client.claim_referral_reward(&players[2], &players[2]);
+        assert_eq!(h.reward.minted().len(), 3);
+    }
+
+    // ── REF-07 ───────────────────────────────────────────────────────────────
+
+    #[test]
+    fn ref_07_config_validated_and_readable() {
+        let h = H::new();
+        assert!(h
+            .client
+            .try_admin_set_referral_config(&0, &1, &1, &1)
+            .is_err());
+
+        let config = h.client.get_referral_config().unwrap();
+        assert_eq!(
+            (
+                config.games_required,
+                config.referrer_reward,
+                config.referee_reward,
+                config.referrer_cap
+            ),
+            (3, 100, 50, 250)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn ref_07_config_is_admin_only() {
+        let h = H::new();
+        h.env.mock_auths(&[]);
+        h.client.admin_set_referral_config(&1, &1, &1, &1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Referral program not configured")]
+    fn ref_07_claim_without_config_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let client = TycoonContractClient::new(&env, &env.register(TycoonContract, ()));
+        let owner = Address::generate(&env);
+        client.initialize(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &owner,
+            &env.register(MockRewardSystem, ()),
+        );
+        let referrer = Address::generate(&env);
+        let player = Address::generate(&env);
+        client.register_player(&String::from_str(&env, "referrer"), &referrer, &None);
+        client.register_player(&String::from_str(&env, "player"), &player, &Some(referrer));
+        assert!(!client.is_referral_eligible(&player));
+        client.claim_referral_reward(&player, &player);
+    }
+
+    // ── REF-08 ───────────────────────────────────────────────────────────────
+
+    #[test]
+    fn ref_08_referrals_paged_in_order() {
+        let h = H::new();
+        let players: std::vec::Vec<Address> = (0..5).map(|_| h.referee()).collect();
+
+        assert_eq!(
+            h.client.get_referrals(&h.referrer, &1, &2),
+            soroban_sdk::vec![&h.env, players[2].clone(), players[3].clone()]
+        );
+        assert_eq!(
+            h.client.get_referrals(&h.referrer, &2, &2),
+            soroban_sdk::vec![&h.env, players[4].clone()]
+        );
+        assert!(h.client.get_referrals(&h.referrer, &3, &2).is_empty());
+        assert!(h
+            .client
+            .try_get_referrals(&h.referrer, &0, &(MAX_REFERRAL_PAGE + 1))
+            .is_err());
+    }
+
+    // ── REF-09 ───────────────────────────────────────────────────────────────
+
+    #[test]
+    fn ref_09_refused_mint_returns_zero() {
+        let h = H::new();
+        let player = h.referee();
+        h.play(&player, 3);
+        h.reward.refuse(&true);
+        assert_eq!(h.client.claim_referral_reward(&h.referrer, &player), 0);
+        assert_eq!(h.reward.minted().len(), 0);
+    }
+
+    #[test]
+    fn ref_09_refused_mint_leaves_claim_open() {
+        let h = H::new();
+        let player = h.referee();
+        h.play(&player, 3);
+
+        h.reward.refuse(&true);
+        h.client.claim_referral_reward(&h.referrer, &player);
+        assert_eq!(h.client.get_referral_paid(&h.referrer), 0);
+
+        h.reward.refuse(&false);
+        h.client.claim_referral_reward(&h.referrer, &player);
+        assert_eq!(h.client.get_referral_paid(&h.referrer), 100);
+        assert_eq!(h.reward.minted().len(), 1);
+    }
+}
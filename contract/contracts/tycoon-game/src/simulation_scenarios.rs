@@ -164,7 +164,7 @@ mod tests {
         let names = ["alice", "bob", "carol", "dave", "eve"];
 
         for (player, name) in players.iter().zip(names.iter()) {
-            client.register_player(&String::from_str(&env, name), player);
+            client.register_player(&String::from_str(&env, name), player, &None);
         }
 
         for (player, name) in players.iter().zip(names.iter()) {
@@ -332,7 +332,7 @@ mod tests {
         let (_, client, owner, _, _) = setup(&env);
 
         let player = Address::generate(&env);
-        client.register_player(&String::from_str(&env, "tycoon_pro"), &player);
+        client.register_player(&String::from_str(&env, "tycoon_pro"), &player, &None);
 
         let user = client
             .get_user(&player)
@@ -478,7 +478,7 @@ mod tests {
         let turn_counts = [3_u32, 7, 12];
 
         for (player, turns) in players.iter().zip(turn_counts.iter()) {
-            client.register_player(&String::from_str(&env, "player"), player);
+            client.register_player(&String::from_str(&env, "player"), player, &None);
             client.remove_player_from_game(&owner, &game_id, player, turns);
         }
 
@@ -0,0 +1,370 @@
+/// # Staked Game Tests — tycoon-game
+///
+/// Covers `create_staked_game` and the `on_token_received` hook that lets a
+/// player join with a single `TycoonToken::transfer_and_call`. The hook is
+/// called directly here with mocked auth; the full token → game path is in
+/// `integration-tests/src/transfer_and_call_flow.rs`.
+///
+/// ## Scenarios
+///
+/// | ID     | Scenario |
+/// |--------|----------|
+/// | SGT-01 | Owner and backend controller can create a game; others cannot |
+/// | SGT-02 | Invalid stake, seat count or duplicate id is rejected |
+/// | SGT-03 | The hook seats a registered player and emits `PlayerJoinedGame` |
+/// | SGT-04 | Wrong stake, unknown game, unregistered player are rejected |
+/// | SGT-05 | Duplicate joins and full games are rejected |
+/// | SGT-06 | Only the TYC token can call the hook |
+/// | SGT-07 | Settling pays the whole pot to the winner and closes the game |
+/// | SGT-08 | Cancelling refunds every stake and closes the game |
+/// | SGT-09 | Settle / cancel reject strangers, unknown games and non-player winners |
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::{TycoonContract, TycoonContractClient};
+    use soroban_sdk::{
+        testutils::{Address as _, Events, MockAuth, MockAuthInvoke},
+        token::{Client as TokenClient, StellarAssetClient},
+        xdr::ToXdr,
+        Address, Bytes, Env, IntoVal, String,
+    };
+
+    const STAKE: u128 = 50_000_000_000_000_000_000; // 50 TYC
+
+    // ── helpers ───────────────────────────────────────────────────────────────
+
+    /// Returns `(client, owner, tyc_token)`. Auths are mocked.
+    fn setup(env: &Env) -> (TycoonContractClient<'_>, Address, Address) {
+        env.mock_all_auths();
+        let contract_id = env.register(TycoonContract, ());
+        let client = TycoonContractClient::new(env, &contract_id);
+        let owner = Address::generate(env);
+        let tyc = Address::generate(env);
+        let usdc = Address::generate(env);
+        let reward = Address::generate(env);
+        client.initialize(&tyc, &usdc, &owner, &reward);
+        (client, owner, tyc)
+    }
+
+    /// Like `setup`, but TYC is a real token contract so payouts move balances.
+    fn setup_with_token(env: &Env) -> (TycoonContractClient<'_>, Address, TokenClient<'_>) {
+        env.mock_all_auths();
+        let contract_id = env.register(TycoonContract, ());
+        let client = TycoonContractClient::new(env, &contract_id);
+        let owner = Address::generate(env);
+        let tyc = env
+            .register_stellar_asset_contract_v2(owner.clone())
+            .address();
+        client.initialize(
+            &tyc,
+            &Address::generate(env),
+            &owner,
+            &Address::generate(env),
+        );
+        (client, owner, TokenClient::new(env, &tyc))
+    }
+
+    /// Seat `names` in game 1, crediting the contract with each stake as
+    /// `transfer_and_call` would.
+    fn seat_players(
+        env: &Env,
+        client: &TycoonContractClient,
+        tyc: &TokenClient,
+        names: &[&str],
+    ) -> std::vec::Vec<Address> {
+        let mut players = std::vec::Vec::new();
+        for name in names {
+            let player = registered_player(env, client, name);
+            StellarAssetClient::new(env, &tyc.address).mint(&client.address, &(STAKE as i128));
+            client.on_token_received(&player, &(STAKE as i128), &payload(env, 1));
+            players.push(player);
+        }
+        players
+    }
+
+    fn registered_player(env: &Env, client: &TycoonContractClient, name: &str) -> Address {
+        let player = Address::generate(env);
+        client.register_player(&String::from_str(env, name), &player, &None);
+        player
+    }
+
+    fn payload(env: &Env, game_id: u128) -> Bytes {
+        game_id.to_xdr(env)
+    }
+
+    // ── SGT-01 ───────────────────────────────────────────────────────────────
+
+    /// SGT-01a: the owner can open a game.
+    #[test]
+    fn sgt_01_owner_creates_game() {
+        let env = Env::default();
+        let (client, owner, _) = setup(&env);
+
+        client.create_staked_game(&owner, &1, &STAKE, &4);
+
+        let game = client.get_staked_game(&1).unwrap();
+        assert_eq!(game.stake, STAKE);
+        assert_eq!(game.max_players, 4);
+        assert_eq!(game.players.len(), 0);
+        assert!(client.get_staked_game(&2).is_none());
+    }
+
+    /// SGT-01b: the backend controller can open a game too.
+    #[test]
+    fn sgt_01_backend_controller_creates_game() {
+        let env = Env::default();
+        let (client, _, _) = setup(&env);
+        let backend = Address::generate(&env);
+        client.admin_set_game_controller(&backend);
+
+        client.create_staked_game(&backend, &7, &STAKE, &2);
+
+        assert!(client.get_staked_game(&7).is_some());
+    }
+
+    /// SGT-01c: anyone else is rejected.
+    #[test]
+    #[should_panic(expected = "Unauthorized: caller must be owner or backend game controller")]
+    fn sgt_01_stranger_cannot_create_game() {
+        let env = Env::default();
+        let (client, _, _) = setup(&env);
+        let stranger = Address::generate(&env);
+        client.create_staked_game(&stranger, &1, &STAKE, &4);
+    }
+
+    // ── SGT-02 ───────────────────────────────────────────────────────────────
+
+    /// SGT-02a: a zero stake is rejected.
+    #[test]
+    #[should_panic(expected = "Stake must be positive")]
+    fn sgt_02_zero_stake_rejected() {
+        let env = Env::default();
+        let (client, owner, _) = setup(&env);
+        client.create_staked_game(&owner, &1, &0, &4);
+    }
+
+    /// SGT-02b: seat counts outside 2-8 are rejected.
+    #[test]
+    #[should_panic(expected = "Max players must be 2-8")]
+    fn sgt_02_invalid_max_players_rejected() {
+        let env = Env::default();
+        let (client, owner, _) = setup(&env);
+        client.create_staked_game(&owner, &1, &STAKE, &9);
+    }
+
+    /// SGT-02c: a game id cannot be reused.
+    #[test]
+    #[should_panic(expected = "Game already exists")]
+    fn sgt_02_duplicate_game_rejected() {
+        let env = Env::default();
+        let (client, owner, _) = setup(&env);
+        client.create_staked_game(&owner, &1, &STAKE, &4);
+        client.create_staked_game(&owner, &1, &STAKE, &4);
+    }
+
+    // ── SGT-03 ───────────────────────────────────────────────────────────────
+
+    /// SGT-03: the hook seats the payer and emits `PlayerJoinedGame`.
+    #[test]
+    fn sgt_03_hook_joins_player() {
+        let env = Env::default();
+        let (client, owner, _) = setup(&env);
+        let alice = registered_player(&env, &client, "alice");
+        client.create_staked_game(&owner, &1, &STAKE, &4);
+
+        let accepted = client.on_token_received(&alice, &(STAKE as i128), &payload(&env, 1));
+        assert!(accepted);
+        assert_eq!(env.events().all().len(), 1);
+
+        let game = client.get_staked_game(&1).unwrap();
+        assert_eq!(game.players.len(), 1);
+        assert_eq!(game.players.get(0).unwrap(), alice);
+    }
+
+    // ── SGT-04 ───────────────────────────────────────────────────────────────
+
+    /// SGT-04a: paying less than the stake is rejected.
+    #[test]
+    #[should_panic(expected = "Incorrect stake amount")]
+    fn sgt_04_wrong_amount_rejected() {
+        let env = Env::default();
+        let (client, owner, _) = setup(&env);
+        let alice = registered_player(&env, &client, "alice");
+        client.create_staked_game(&owner, &1, &STAKE, &4);
+        client.on_token_received(&alice, &(STAKE as i128 - 1), &payload(&env, 1));
+    }
+
+    /// SGT-04b: an unknown game id is rejected.
+    #[test]
+    #[should_panic(expected = "Game does not exist")]
+    fn sgt_04_unknown_game_rejected() {
+        let env = Env::default();
+        let (client, _, _) = setup(&env);
+        let alice = registered_player(&env, &client, "alice");
+        client.on_token_received(&alice, &(STAKE as i128), &payload(&env, 99));
+    }
+
+    /// SGT-04c: a player must register before staking.
+    #[test]
+    #[should_panic(expected = "Player not registered")]
+    fn sgt_04_unregistered_player_rejected() {
+        let env = Env::default();
+        let (client, owner, _) = setup(&env);
+        let stranger = Address::generate(&env);
+        client.create_staked_game(&owner, &1, &STAKE, &4);
+        client.on_token_received(&stranger, &(STAKE as i128), &payload(&env, 1));
+    }
+
+    /// SGT-04d: a payload that is not a `u128` is rejected.
+    #[test]
+    #[should_panic]
+    fn sgt_04_malformed_payload_rejected() {
+        let env = Env::default();
+        let (client, owner, _) = setup(&env);
+        let alice = registered_player(&env, &client, "alice");
+        client.create_staked_game(&owner, &1, &STAKE, &4);
+        client.on_token_received(
+            &alice,
+            &(STAKE as i128),
+            &Bytes::from_slice(&env, b"game-1"),
+        );
+    }
+
+    // ── SGT-05 ───────────────────────────────────────────────────────────────
+
+    /// SGT-05a: a player cannot take two seats.
+    #[test]
+    #[should_panic(expected = "Player already joined")]
+    fn sgt_05_duplicate_join_rejected() {
+        let env = Env::default();
+        let (client, owner, _) = setup(&env);
+        let alice = registered_player(&env, &client, "alice");
+        client.create_staked_game(&owner, &1, &STAKE, &4);
+        client.on_token_received(&alice, &(STAKE as i128), &payload(&env, 1));
+        client.on_token_received(&alice, &(STAKE as i128), &payload(&env, 1));
+    }
+
+    /// SGT-05b: a full game rejects further stakes.
+    #[test]
+    #[should_panic(expected = "Game is full")]
+    fn sgt_05_full_game_rejected() {
+        let env = Env::default();
+        let (client, owner, _) = setup(&env);
+        client.create_staked_game(&owner, &1, &STAKE, &2);
+        for name in ["alice", "bob", "carol"] {
+            let player = registered_player(&env, &client, name);
+            client.on_token_received(&player, &(STAKE as i128), &payload(&env, 1));
+        }
+    }
+
+    // ── SGT-06 ───────────────────────────────────────────────────────────────
+
+    /// SGT-06: a player calling the hook directly (without paying) is rejected
+    /// because the TYC token's authorization is missing.
+    #[test]
+    #[should_panic]
+    fn sgt_06_hook_requires_tyc_token() {
+        let env = Env::default();
+        let (client, owner, _) = setup(&env);
+        let alice = registered_player(&env, &client, "alice");
+        client.create_staked_game(&owner, &1, &STAKE, &4);
+
+        let args = (alice.clone(), STAKE as i128, payload(&env, 1));
+        env.mock_auths(&[MockAuth {
+            address: &alice,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "on_token_received",
+                args: args.clone().into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        client.on_token_received(&args.0, &args.1, &args.2);
+    }
+
+    // ── SGT-07 ───────────────────────────────────────────────────────────────
+
+    /// SGT-07: the winner receives `stake × players` and the game is closed.
+    #[test]
+    fn sgt_07_settle_pays_pot_to_winner() {
+        let env = Env::default();
+        let (client, owner, tyc) = setup_with_token(&env);
+        client.create_staked_game(&owner, &1, &STAKE, &4);
+        let players = seat_players(&env, &client, &tyc, &["alice", "bob", "carol"]);
+
+        let pot = client.settle_staked_game(&owner, &1, &players[1]);
+
+        assert_eq!(pot, 3 * STAKE);
+        assert_eq!(tyc.balance(&players[1]), 3 * STAKE as i128);
+        assert_eq!(tyc.balance(&client.address), 0);
+        assert!(client.get_staked_game(&1).is_none());
+    }
+
+    // ── SGT-08 ───────────────────────────────────────────────────────────────
+
+    /// SGT-08: every player gets their stake back and the game is closed.
+    #[test]
+    fn sgt_08_cancel_refunds_every_stake() {
+        let env = Env::default();
+        let (client, owner, tyc) = setup_with_token(&env);
+        let backend = Address::generate(&env);
+        client.admin_set_game_controller(&backend);
+        client.create_staked_game(&owner, &1, &STAKE, &4);
+        let players = seat_players(&env, &client, &tyc, &["alice", "bob"]);
+
+        client.cancel_staked_game(&backend, &1);
+
+        for player in &players {
+            assert_eq!(tyc.balance(player), STAKE as i128);
+        }
+        assert_eq!(tyc.balance(&client.address), 0);
+        assert!(client.get_staked_game(&1).is_none());
+    }
+
+    // ── SGT-09 ───────────────────────────────────────────────────────────────
+
+    /// SGT-09a: a stranger cannot settle.
+    #[test]
+    #[should_panic(expected = "Unauthorized: caller must be owner or backend game controller")]
+    fn sgt_09_stranger_cannot_settle() {
+        let env = Env::default();
+        let (client, owner, tyc) = setup_with_token(&env);
+        client.create_staked_game(&owner, &1, &STAKE, &4);
+        let players = seat_players(&env, &client, &tyc, &["alice"]);
+        client.settle_staked_game(&players[0], &1, &players[0]);
+    }
+
+    /// SGT-09b: a stranger cannot cancel.
+    #[test]
+    #[should_panic(expected = "Unauthorized: caller must be owner or backend game controller")]
+    fn sgt_09_stranger_cannot_cancel() {
+        let env = Env::default();
+        let (client, owner, _) = setup(&env);
+        client.create_staked_game(&owner, &1, &STAKE, &4);
+        client.cancel_staked_game(&Address::generate(&env), &1);
+    }
+
+    /// SGT-09c: the winner must hold a seat.
+    #[test]
+    #[should_panic(expected = "Winner not in game")]
+    fn sgt_09_winner_must_be_player() {
+        let env = Env::default();
+        let (client, owner, tyc) = setup_with_token(&env);
+        client.create_staked_game(&owner, &1, &STAKE, &4);
+        seat_players(&env, &client, &tyc, &["alice"]);
+        client.settle_staked_game(&owner, &1, &Address::generate(&env));
+    }
+
+    /// SGT-09d: a closed game cannot be settled or cancelled again.
+    #[test]
+    #[should_panic(expected = "Game does not exist")]
+    fn sgt_09_closed_game_rejected() {
+        let env = Env::default();
+        let (client, owner, tyc) = setup_with_token(&env);
+        client.create_staked_game(&owner, &1, &STAKE, &4);
+        seat_players(&env, &client, &tyc, &["alice"]);
+        client.cancel_staked_game(&owner, &1);
+        client.cancel_staked_game(&owner, &1);
+    }
+}
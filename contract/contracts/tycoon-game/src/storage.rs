@@ -4,7 +4,7 @@
 // getters/setters may appear unused to the compiler when called only through
 // the contractimpl macro expansion. Removing this attribute produces spurious
 // dead_code warnings on every storage accessor.
-use soroban_sdk::{contracttype, Address, Env, String};
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
 
 /// Storage keys for the contract
 #[derive(Clone)]
@@ -14,13 +14,20 @@ pub enum DataKey {
     TycToken,
     UsdcToken,
     IsInitialized,
-    Collectible(u128),     // token_id -> CollectibleInfo
-    CashTier(u32),         // tier -> value
-    User(Address),         // address -> User
-    Registered(Address),   // address -> bool
-    RewardSystem,          // reward system contract address
-    BackendGameController, // backend game controller address
-    StateVersion,          // u32 version of the state schema
+    Collectible(u128),                 // token_id -> CollectibleInfo
+    CashTier(u32),                     // tier -> value
+    User(Address),                     // address -> User
+    Registered(Address),               // address -> bool
+    RewardSystem,                      // reward system contract address
+    BackendGameController,             // backend game controller address
+    StateVersion,                      // u32 version of the state schema
+    StakedGame(u128),                  // game_id -> StakedGame
+    ReferralConfig,                    // ReferralConfig
+    Referrer(Address),                 // referred player -> referrer
+    ReferralCount(Address),            // referrer -> number of players referred
+    Referral(Address, u32),            // (referrer, index) -> referred player
+    ReferralPaid(Address),             // referrer -> total referral value paid to them
+    ReferralClaimed(Address, Address), // (referred player, claimant) -> bool
 }
 
 /// Information about a collectible NFT
@@ -46,6 +53,29 @@ pub struct User {
     pub games_won: u32,
 }
 
+/// A game that players join by staking TYC into the contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct StakedGame {
+    pub stake: u128,
+    pub max_players: u32,
+    pub players: Vec<Address>,
+}
+
+/// Referral program parameters set by the owner
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ReferralConfig {
+    /// Recorded games the referred player must finish before rewards unlock
+    pub games_required: u32,
+    /// Voucher value paid to the referrer per eligible referral
+    pub referrer_reward: u128,
+    /// Voucher value paid to the referred player
+    pub referee_reward: u128,
+    /// Total referral value a single referrer can ever be paid
+    pub referrer_cap: u128,
+}
+
 /// A snapshot of the contract's critical state
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
@@ -200,3 +230,103 @@ pub fn set_state_version(env: &Env, version: u32) {
         .instance()
         .set(&DataKey::StateVersion, &version);
 }
+
+/// Get a staked game by id
+pub fn get_staked_game(env: &Env, game_id: u128) -> Option<StakedGame> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StakedGame(game_id))
+}
+
+/// Set a staked game
+pub fn set_staked_game(env: &Env, game_id: u128, game: &StakedGame) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::StakedGame(game_id), game);
+}
+
+/// Remove a settled or cancelled staked game
+pub fn remove_staked_game(env: &Env, game_id: u128) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::StakedGame(game_id));
+}
+
+/// Get the referral program parameters, if configured
+pub fn get_referral_config(env: &Env) -> Option<ReferralConfig> {
+    env.storage().instance().get(&DataKey::ReferralConfig)
+}
+
+/// Set the referral program parameters
+pub fn set_referral_config(env: &Env, config: &ReferralConfig) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ReferralConfig, config);
+}
+
+/// Get the player who referred `player`
+pub fn get_referrer(env: &Env, player: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Referrer(player.clone()))
+}
+
+/// Get the number of players `referrer` has referred
+pub fn get_referral_count(env: &Env, referrer: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReferralCount(referrer.clone()))
+        .unwrap_or(0)
+}
+
+/// Get the `index`-th player referred by `referrer`
+pub fn get_referral(env: &Env, referrer: &Address, index: u32) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Referral(referrer.clone(), index))
+}
+
+/// Record that `referrer` referred `player`
+pub fn add_referral(env: &Env, referrer: &Address, player: &Address) {
+    let count = get_referral_count(env, referrer);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Referrer(player.clone()), referrer);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Referral(referrer.clone(), count), player);
+    env.storage()
+        .persistent()
+        .set(&DataKey::ReferralCount(referrer.clone()), &(count + 1));
+}
+
+/// Get the total referral value paid to `referrer`
+pub fn get_referral_paid(env: &Env, referrer: &Address) -> u128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReferralPaid(referrer.clone()))
+        .unwrap_or(0)
+}
+
+/// Set the total referral value paid to `referrer`
+pub fn set_referral_paid(env: &Env, referrer: &Address, amount: u128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ReferralPaid(referrer.clone()), &amount);
+}
+
+/// Check if `claimant` has claimed their reward for referral of `player`
+pub fn is_referral_claimed(env: &Env, player: &Address, claimant: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReferralClaimed(player.clone(), claimant.clone()))
+        .unwrap_or(false)
+}
+
+/// Mark `claimant`'s reward for the referral of `player` as claimed
+pub fn set_referral_claimed(env: &Env, player: &Address, claimant: &Address) {
+    env.storage().persistent().set(
+        &DataKey::ReferralClaimed(player.clone(), claimant.clone()),
+        &true,
+    );
+}
@@ -425,7 +425,7 @@ fn test_register_player_success() {
     let player = Address::generate(&env);
     let username = String::from_str(&env, "player1");
 
-    client.register_player(&username, &player);
+    client.register_player(&username, &player, &None);
 
     let user = client.get_user(&player);
     assert!(user.is_some());
@@ -450,8 +450,8 @@ fn test_register_player_duplicate() {
     let player = Address::generate(&env);
     let username = String::from_str(&env, "player1");
 
-    client.register_player(&username, &player);
-    client.register_player(&username, &player); // Should panic
+    client.register_player(&username, &player, &None);
+    client.register_player(&username, &player, &None); // Should panic
 }
 
 #[test]
@@ -467,7 +467,7 @@ fn test_register_player_username_too_short() {
 
     let player = Address::generate(&env);
     let username = String::from_str(&env, "ab");
-    client.register_player(&username, &player);
+    client.register_player(&username, &player, &None);
 }
 
 #[test]
@@ -483,7 +483,7 @@ fn test_register_player_username_too_long() {
 
     let player = Address::generate(&env);
     let username = String::from_str(&env, "thisusernameiswaytoolong");
-    client.register_player(&username, &player);
+    client.register_player(&username, &player, &None);
 }
 
 // ===== BACKEND GAME CONTROLLER TESTS =====
@@ -632,8 +632,8 @@ fn test_backend_controller_integration() {
     // Register players
     let player1 = Address::generate(&env);
     let player2 = Address::generate(&env);
-    client.register_player(&String::from_str(&env, "player1"), &player1);
-    client.register_player(&String::from_str(&env, "player2"), &player2);
+    client.register_player(&String::from_str(&env, "player1"), &player1, &None);
+    client.register_player(&String::from_str(&env, "player2"), &player2, &None);
 
     // Backend removes players from games
     client.remove_player_from_game(&backend_controller, &1, &player1, &5);
@@ -756,7 +756,7 @@ fn test_register_player_username_exactly_3_chars() {
 
     let player = Address::generate(&env);
     let username = String::from_str(&env, "abc");
-    client.register_player(&username, &player);
+    client.register_player(&username, &player, &None);
 
     let user = client.get_user(&player).unwrap();
     assert_eq!(user.username, username);
@@ -772,7 +772,7 @@ fn test_register_player_username_exactly_20_chars() {
 
     let player = Address::generate(&env);
     let username = String::from_str(&env, "abcdefghij1234567890");
-    client.register_player(&username, &player);
+    client.register_player(&username, &player, &None);
 
     let user = client.get_user(&player).unwrap();
     assert_eq!(user.username, username);
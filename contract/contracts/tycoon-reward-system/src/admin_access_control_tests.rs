@@ -99,8 +99,9 @@ fn test_mint_voucher_admin_succeeds() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (client, admin, _tyc, _usdc) = setup(&env);
+    let (client, admin, tyc, _usdc) = setup(&env);
     let user = Address::generate(&env);
+    StellarAssetClient::new(&env, &tyc).mint(&client.address, &500);
 
     let token_id = client.mint_voucher(&admin, &user, &500);
     assert_eq!(client.get_balance(&user, &token_id), 1);
@@ -111,9 +112,10 @@ fn test_mint_voucher_backend_minter_succeeds() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (client, _admin, _tyc, _usdc) = setup(&env);
+    let (client, _admin, tyc, _usdc) = setup(&env);
     let minter = Address::generate(&env);
     let user = Address::generate(&env);
+    StellarAssetClient::new(&env, &tyc).mint(&client.address, &200);
 
     client.set_backend_minter(&minter);
     let token_id = client.mint_voucher(&minter, &user, &200);
@@ -248,6 +250,7 @@ fn test_transfer_user_succeeds() {
         .address();
 
     client.initialize(&admin, &tyc_token, &usdc_token);
+    StellarAssetClient::new(&env, &tyc_token).mint(&contract_id, &500);
 
     let token_id = client.mint_voucher(&admin, &alice, &500);
     client.transfer(&alice, &bob, &token_id, &1);
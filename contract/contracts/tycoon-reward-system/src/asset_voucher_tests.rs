@@ -0,0 +1,216 @@
+/// # Multi-asset vouchers
+///
+/// Covers `mint_asset_voucher`, the asset allowlist and asset-aware
+/// redemption.
+///
+/// | ID    | What it pins |
+/// |-------|--------------|
+/// | AV-01 | A USDC voucher redeems in USDC and leaves TYC untouched |
+/// | AV-02 | An allowlisted SEP-41 token voucher redeems in that token |
+/// | AV-03 | Tokens that are not allowlisted are rejected at mint |
+/// | AV-04 | Untagged vouchers read and redeem as TYC |
+/// | AV-05 | Non-TYC mints emit `V_Asset`; TYC mints do not |
+/// | AV-06 | The allowlist is admin-only and extends `withdraw_funds` |
+/// | AV-07 | Only the admin or backend minter can mint asset vouchers |
+extern crate std;
+
+use crate::test_support::{deref_setup, Setup};
+use crate::VoucherAsset;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{symbol_short, token, Address, IntoVal, Symbol};
+
+const PRIZE: u128 = 250_000_000; // 25 USDC at 7 decimals
+const FUNDING: i128 = 1_000_000_000_000;
+
+// ── Harness ───────────────────────────────────────────────────────────────────
+
+struct H<'a> {
+    base: Setup<'a>,
+    /// A third SEP-41 token, not allowlisted until a test does so.
+    other: token::Client<'a>,
+}
+deref_setup!(H);
+
+impl H<'_> {
+    /// Contract funded with `FUNDING` of TYC, USDC and the third token.
+    fn new() -> Self {
+        let base = Setup::new();
+        let other = Setup::token(&base.env);
+        for token in [&base.tyc, &base.usdc, &other] {
+            base.fund_contract(token, FUNDING);
+        }
+        H { base, other }
+    }
+}
+
+// ── AV-01 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn av_01_usdc_voucher_redeems_in_usdc() {
+    let h = H::new();
+    let winner = Address::generate(&h.env);
+
+    let token_id = h
+        .client
+        .mint_asset_voucher(&h.admin, &winner, &VoucherAsset::Usdc, &PRIZE);
+    assert_eq!(
+        h.client.voucher_info(&token_id).unwrap().asset,
+        VoucherAsset::Usdc
+    );
+
+    h.client.redeem_voucher_from(&winner, &token_id);
+
+    assert_eq!(h.usdc.balance(&winner), PRIZE as i128);
+    assert_eq!(h.tyc.balance(&winner), 0);
+    assert_eq!(h.tyc.balance(&h.client.address), FUNDING);
+    assert_eq!(h.client.voucher_info(&token_id), None);
+}
+
+// ── AV-02 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn av_02_allowlisted_token_voucher_redeems_in_token() {
+    let h = H::new();
+    let winner = Address::generate(&h.env);
+    let asset = VoucherAsset::Token(h.other.address.clone());
+    h.client.set_asset_allowed(&h.other.address, &true);
+
+    let token_id = h
+        .client
+        .mint_asset_voucher(&h.admin, &winner, &asset, &PRIZE);
+    h.client.redeem_voucher_from(&winner, &token_id);
+
+    assert_eq!(h.other.balance(&winner), PRIZE as i128);
+    assert_eq!(h.usdc.balance(&winner), 0);
+}
+
+/// Removing a token from the allowlist stops new mints, not redemptions.
+#[test]
+fn av_02_disallowed_token_voucher_still_redeems() {
+    let h = H::new();
+    let winner = Address::generate(&h.env);
+    let asset = VoucherAsset::Token(h.other.address.clone());
+    h.client.set_asset_allowed(&h.other.address, &true);
+    let token_id = h
+        .client
+        .mint_asset_voucher(&h.admin, &winner, &asset, &PRIZE);
+
+    h.client.set_asset_allowed(&h.other.address, &false);
+    assert!(!h.client.is_asset_allowed(&h.other.address));
+    h.client.redeem_voucher_from(&winner, &token_id);
+
+    assert_eq!(h.other.balance(&winner), PRIZE as i128);
+}
+
+// ── AV-03 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic(expected = "Asset not allowed")]
+fn av_03_unlisted_token_rejected() {
+    let h = H::new();
+    h.client.mint_asset_voucher(
+        &h.admin,
+        &Address::generate(&h.env),
+        &VoucherAsset::Token(h.other.address.clone()),
+        &PRIZE,
+    );
+}
+
+// ── AV-04 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn av_04_untagged_voucher_is_tyc() {
+    let h = H::new();
+    let user = Address::generate(&h.env);
+
+    let legacy = h.client.mint_voucher(&h.admin, &user, &PRIZE);
+    let tagged = h
+        .client
+        .mint_asset_voucher(&h.admin, &user, &VoucherAsset::Tyc, &PRIZE);
+
+    assert_eq!(
+        h.client.voucher_info(&legacy).unwrap().asset,
+        VoucherAsset::Tyc
+    );
+    assert_eq!(
+        h.client.voucher_info(&tagged).unwrap().asset,
+        VoucherAsset::Tyc
+    );
+    h.client.redeem_voucher_from(&user, &legacy);
+    h.client.redeem_voucher_from(&user, &tagged);
+    assert_eq!(h.tyc.balance(&user), 2 * PRIZE as i128);
+}
+
+// ── AV-05 ─────────────────────────────────────────────────────────────────────
+
+fn v_asset_events(h: &H) -> std::vec::Vec<(u128, VoucherAsset)> {
+    h.env
+        .events()
+        .all()
+        .iter()
+        .filter_map(|(_, topics, data)| {
+            let name: Symbol = topics.get(0).unwrap().into_val(&h.env);
+            (name == symbol_short!("V_Asset")).then(|| {
+                let token_id: u128 = topics.get(1).unwrap().into_val(&h.env);
+                (token_id, data.into_val(&h.env))
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn av_05_non_tyc_mint_emits_v_asset() {
+    let h = H::new();
+    let token_id = h.client.mint_asset_voucher(
+        &h.admin,
+        &Address::generate(&h.env),
+        &VoucherAsset::Usdc,
+        &PRIZE,
+    );
+    assert_eq!(v_asset_events(&h), [(token_id, VoucherAsset::Usdc)]);
+
+    h.client.mint_asset_voucher(
+        &h.admin,
+        &Address::generate(&h.env),
+        &VoucherAsset::Tyc,
+        &PRIZE,
+    );
+    assert!(v_asset_events(&h).is_empty());
+}
+
+// ── AV-06 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic]
+fn av_06_set_asset_allowed_requires_admin() {
+    let h = H::new();
+    h.env.set_auths(&[]);
+    h.client.set_asset_allowed(&h.other.address, &true);
+}
+
+#[test]
+fn av_06_withdraw_accepts_allowlisted_token() {
+    let h = H::new();
+    let treasury = Address::generate(&h.env);
+    assert!(h
+        .client
+        .try_withdraw_funds(&h.other.address, &treasury, &1)
+        .is_err());
+
+    h.client.set_asset_allowed(&h.other.address, &true);
+    h.client
+        .withdraw_funds(&h.other.address, &treasury, &(PRIZE));
+
+    assert_eq!(h.other.balance(&treasury), PRIZE as i128);
+}
+
+// ── AV-07 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic(expected = "Unauthorized: only admin or backend minter can mint")]
+fn av_07_stranger_cannot_mint_asset_voucher() {
+    let h = H::new();
+    let stranger = Address::generate(&h.env);
+    h.client
+        .mint_asset_voucher(&stranger, &stranger, &VoucherAsset::Usdc, &PRIZE);
+}
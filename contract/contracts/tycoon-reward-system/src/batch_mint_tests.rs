@@ -0,0 +1,195 @@
+/// # Batch voucher minting
+///
+/// Covers `mint_vouchers_batch`.
+///
+/// | ID    | What it pins |
+/// |-------|--------------|
+/// | BM-01 | Each recipient gets one voucher with its value; ids are returned in order |
+/// | BM-02 | Ids are consecutive and `mint_voucher` continues after the batch |
+/// | BM-03 | One `V_Mint` event per voucher |
+/// | BM-04 | The caller authorizes once; only admin or backend minter may call |
+/// | BM-05 | Empty and oversized batches are rejected |
+/// | BM-06 | A `MAX_MINT_BATCH` batch fits the sandbox resource budget |
+extern crate std;
+
+use crate::test_support::{deref_setup, Setup};
+use crate::MAX_MINT_BATCH;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{symbol_short, vec, Address, IntoVal, Symbol, Vec};
+
+const VOUCHER_ID_START: u128 = 1_000_000_000;
+const ONE_TYC: u128 = 1_000_000_000_000_000_000;
+
+// ── Harness ───────────────────────────────────────────────────────────────────
+
+struct H<'a> {
+    base: Setup<'a>,
+}
+deref_setup!(H);
+
+impl H<'_> {
+    fn new() -> Self {
+        let base = Setup::new();
+        base.fund_contract(&base.tyc, (1_000_000 * ONE_TYC) as i128);
+        H { base }
+    }
+
+    /// `n` fresh recipients, recipient `i` receiving `(i + 1)` TYC.
+    fn recipients(&self, n: u32) -> Vec<(Address, u128)> {
+        let mut list = Vec::new(&self.env);
+        for i in 0..n {
+            list.push_back((Address::generate(&self.env), (i as u128 + 1) * ONE_TYC));
+        }
+        list
+    }
+}
+
+// ── BM-01 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn bm_01_batch_mints_one_voucher_per_recipient() {
+    let h = H::new();
+    let list = h.recipients(3);
+
+    let ids = h.client.mint_vouchers_batch(&h.admin, &list);
+
+    assert_eq!(ids.len(), 3);
+    for ((to, value), id) in list.iter().zip(ids.iter()) {
+        assert_eq!(h.client.get_balance(&to, &id), 1);
+        assert_eq!(h.client.owned_token_count(&to), 1);
+        assert_eq!(h.client.voucher_info(&id).unwrap().value, value);
+    }
+}
+
+/// The same recipient may appear more than once.
+#[test]
+fn bm_01_duplicate_recipient_gets_separate_vouchers() {
+    let h = H::new();
+    let user = Address::generate(&h.env);
+
+    let ids = h.client.mint_vouchers_batch(
+        &h.admin,
+        &vec![&h.env, (user.clone(), ONE_TYC), (user.clone(), 2 * ONE_TYC)],
+    );
+
+    assert_eq!(h.client.owned_token_count(&user), 2);
+    assert_eq!(
+        h.client.voucher_info(&ids.get(1).unwrap()).unwrap().value,
+        2 * ONE_TYC
+    );
+}
+
+// ── BM-02 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn bm_02_ids_are_consecutive() {
+    let h = H::new();
+    let first = h
+        .client
+        .mint_voucher(&h.admin, &Address::generate(&h.env), &ONE_TYC);
+    assert_eq!(first, VOUCHER_ID_START);
+
+    let ids = h.client.mint_vouchers_batch(&h.admin, &h.recipients(4));
+    let next = h
+        .client
+        .mint_voucher(&h.admin, &Address::generate(&h.env), &ONE_TYC);
+
+    assert_eq!(
+        ids,
+        vec![
+            &h.env,
+            VOUCHER_ID_START + 1,
+            VOUCHER_ID_START + 2,
+            VOUCHER_ID_START + 3,
+            VOUCHER_ID_START + 4
+        ]
+    );
+    assert_eq!(next, VOUCHER_ID_START + 5);
+}
+
+// ── BM-03 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn bm_03_emits_v_mint_per_voucher() {
+    let h = H::new();
+    let list = h.recipients(3);
+
+    let ids = h.client.mint_vouchers_batch(&h.admin, &list);
+
+    let v_mints: std::vec::Vec<_> = h
+        .env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            let name: Symbol = topics.get(0).unwrap().into_val(&h.env);
+            name == symbol_short!("V_Mint")
+        })
+        .collect();
+    assert_eq!(v_mints.len(), 3);
+    for (i, (_, topics, data)) in v_mints.iter().enumerate() {
+        let (to, value) = list.get(i as u32).unwrap();
+        let topic_to: Address = topics.get(1).unwrap().into_val(&h.env);
+        let topic_id: u128 = topics.get(2).unwrap().into_val(&h.env);
+        let data: u128 = data.into_val(&h.env);
+        assert_eq!(topic_to, to);
+        assert_eq!(topic_id, ids.get(i as u32).unwrap());
+        assert_eq!(data, value);
+    }
+}
+
+// ── BM-04 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn bm_04_backend_minter_authorizes_once() {
+    let h = H::new();
+    let backend = Address::generate(&h.env);
+    h.client.set_backend_minter(&backend);
+
+    h.client.mint_vouchers_batch(&backend, &h.recipients(5));
+
+    let auths = h.env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, backend);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: only admin or backend minter can mint")]
+fn bm_04_stranger_rejected() {
+    let h = H::new();
+    let stranger = Address::generate(&h.env);
+    h.client.mint_vouchers_batch(&stranger, &h.recipients(1));
+}
+
+// ── BM-05 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic(expected = "Batch is empty")]
+fn bm_05_empty_batch_rejected() {
+    let h = H::new();
+    h.client.mint_vouchers_batch(&h.admin, &Vec::new(&h.env));
+}
+
+#[test]
+#[should_panic(expected = "Batch too large")]
+fn bm_05_oversized_batch_rejected() {
+    let h = H::new();
+    h.client
+        .mint_vouchers_batch(&h.admin, &h.recipients(MAX_MINT_BATCH + 1));
+}
+
+// ── BM-06 ─────────────────────────────────────────────────────────────────────
+
+/// First-time recipients are the worst case for writes. Measured: 43 write
+/// entries / ~1.8M instructions for 8 vouchers.
+#[test]
+fn bm_06_max_batch_within_resource_limits() {
+    let h = H::new();
+
+    h.client
+        .mint_vouchers_batch(&h.admin, &h.recipients(MAX_MINT_BATCH));
+
+    let resources = h.env.cost_estimate().resources();
+    assert!(resources.write_entries <= 50, "{}", resources.write_entries);
+    assert!(resources.instructions <= 100_000_000);
+}
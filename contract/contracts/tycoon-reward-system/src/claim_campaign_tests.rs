@@ -0,0 +1,397 @@
+/// # Merkle-root claim campaigns
+///
+/// Covers the `merkle` helper, `create_campaign`, `claim`, `reclaim_campaign`
+/// and the `campaign` / `is_claimed` views.
+///
+/// | ID    | What it pins |
+/// |-------|--------------|
+/// | CC-01 | Every proof built by the helper verifies; tampered inputs do not |
+/// | CC-02 | Creating a campaign escrows `total` TYC from the admin |
+/// | CC-03 | A TYC campaign pays the claimed amount directly |
+/// | CC-04 | A voucher campaign mints a redeemable voucher worth the amount |
+/// | CC-05 | A leaf can only be claimed once |
+/// | CC-06 | Wrong amount, wrong claimant and out-of-range indices are rejected |
+/// | CC-07 | Claims close at the deadline; reclaim opens at it, once |
+/// | CC-08 | Claims never exceed the escrowed total |
+/// | CC-09 | Admin-only creation, parameter checks and pause |
+/// | CC-10 | Escrow counts as a liability until claimed as TYC or reclaimed |
+extern crate std;
+
+use crate::test_support::{deref_setup, Setup};
+use crate::{merkle, Campaign, CampaignPayout};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN, Vec};
+
+const ONE_TYC: u128 = 1_000_000_000_000_000_000;
+const NOW: u64 = 1_700_000_000;
+const DAY: u64 = 86_400;
+
+// ── Harness ───────────────────────────────────────────────────────────────────
+
+struct H<'a> {
+    base: Setup<'a>,
+    /// `(claimant, amount)` leaves of the campaign under test.
+    entries: std::vec::Vec<(Address, u128)>,
+    leaves: Vec<BytesN<32>>,
+}
+deref_setup!(H);
+
+impl H<'_> {
+    /// Admin holding 1 000 TYC and `n` claimants, claimant `i` entitled to
+    /// `(i + 1)` TYC. Ledger clock at `NOW`.
+    fn new(n: u32) -> Self {
+        let base = Setup::new();
+        let env = &base.env;
+        env.ledger().set_timestamp(NOW);
+        base.fund(&base.tyc, &base.admin, 1_000 * ONE_TYC as i128);
+
+        let entries: std::vec::Vec<_> = (0..n)
+            .map(|i| (Address::generate(env), (i as u128 + 1) * ONE_TYC))
+            .collect();
+        let mut leaves = Vec::new(env);
+        for (account, amount) in &entries {
+            leaves.push_back(merkle::leaf_hash(env, account, *amount));
+        }
+        H {
+            base,
+            entries,
+            leaves,
+        }
+    }
+
+    fn total(&self) -> u128 {
+        self.entries.iter().map(|(_, amount)| amount).sum()
+    }
+
+    /// Campaign over all entries, fully funded, ending at `NOW + DAY`.
+    fn create(&self, payout: CampaignPayout) -> u32 {
+        self.client.create_campaign(
+            &merkle::root(&self.env, &self.leaves),
+            &self.leaves.len(),
+            &payout,
+            &self.total(),
+            &(NOW + DAY),
+        )
+    }
+
+    fn claim(&self, campaign_id: u32, index: u32) -> Option<u128> {
+        let (claimant, amount) = &self.entries[index as usize];
+        self.client.claim(
+            claimant,
+            &campaign_id,
+            &index,
+            amount,
+            &merkle::proof(&self.env, &self.leaves, index),
+        )
+    }
+}
+
+// ── CC-01 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn cc_01_helper_proofs_verify_for_every_tree_size() {
+    for n in 1..=9 {
+        let h = H::new(n);
+        let root = merkle::root(&h.env, &h.leaves);
+        for index in 0..n {
+            let proof = merkle::proof(&h.env, &h.leaves, index);
+            let leaf = h.leaves.get(index).unwrap();
+            assert!(merkle::verify(&h.env, &root, index, leaf, &proof));
+        }
+    }
+}
+
+#[test]
+fn cc_01_tampered_inputs_do_not_verify() {
+    let h = H::new(4);
+    let root = merkle::root(&h.env, &h.leaves);
+    let (account, amount) = &h.entries[1];
+    let proof = merkle::proof(&h.env, &h.leaves, 1);
+
+    let wrong_amount = merkle::leaf_hash(&h.env, account, amount + 1);
+    assert!(!merkle::verify(&h.env, &root, 1, wrong_amount, &proof));
+    let leaf = merkle::leaf_hash(&h.env, account, *amount);
+    assert!(!merkle::verify(&h.env, &root, 0, leaf.clone(), &proof));
+    // High index bits beyond the proof length are not ignored.
+    assert!(!merkle::verify(&h.env, &root, 1 + 4, leaf, &proof));
+}
+
+// ── CC-02 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn cc_02_create_escrows_total() {
+    let h = H::new(3);
+
+    let id = h.create(CampaignPayout::Tyc);
+
+    assert_eq!(id, 0);
+    assert_eq!(h.tyc.balance(&h.client.address), (6 * ONE_TYC) as i128);
+    assert_eq!(h.tyc.balance(&h.admin), (994 * ONE_TYC) as i128);
+    assert_eq!(
+        h.client.campaign(&id),
+        Some(Campaign {
+            root: merkle::root(&h.env, &h.leaves),
+            leaf_count: 3,
+            payout: CampaignPayout::Tyc,
+            total: 6 * ONE_TYC,
+            claimed: 0,
+            deadline: NOW + DAY,
+            reclaimed: false,
+        })
+    );
+    assert_eq!(h.create(CampaignPayout::Voucher), 1);
+}
+
+// ── CC-03 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn cc_03_tyc_campaign_pays_directly() {
+    let h = H::new(5);
+    let id = h.create(CampaignPayout::Tyc);
+
+    assert_eq!(h.claim(id, 3), None);
+
+    let (claimant, amount) = &h.entries[3];
+    assert_eq!(h.tyc.balance(claimant), *amount as i128);
+    assert_eq!(h.client.campaign(&id).unwrap().claimed, *amount);
+    assert!(h.client.is_claimed(&id, &3));
+    assert!(!h.client.is_claimed(&id, &2));
+}
+
+// ── CC-04 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn cc_04_voucher_campaign_mints_redeemable_voucher() {
+    let h = H::new(5);
+    let id = h.create(CampaignPayout::Voucher);
+
+    let voucher_id = h.claim(id, 2).unwrap();
+
+    let (claimant, amount) = &h.entries[2];
+    assert_eq!(h.client.get_balance(claimant, &voucher_id), 1);
+    assert_eq!(h.client.voucher_info(&voucher_id).unwrap().value, *amount);
+    assert_eq!(h.tyc.balance(claimant), 0);
+
+    h.client.redeem_voucher_from(claimant, &voucher_id);
+    assert_eq!(h.tyc.balance(claimant), *amount as i128);
+}
+
+// ── CC-05 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic(expected = "Already claimed")]
+fn cc_05_double_claim_rejected() {
+    let h = H::new(3);
+    let id = h.create(CampaignPayout::Tyc);
+    h.claim(id, 1);
+    h.claim(id, 1);
+}
+
+/// Bits land in the right word for indices past the first 128.
+#[test]
+fn cc_05_bitmap_spans_words() {
+    let h = H::new(130);
+    let id = h.client.create_campaign(
+        &merkle::root(&h.env, &h.leaves),
+        &130,
+        &CampaignPayout::Tyc,
+        &(130 * ONE_TYC),
+        &(NOW + DAY),
+    );
+
+    h.claim(id, 0);
+    h.claim(id, 128);
+
+    assert!(h.client.is_claimed(&id, &0));
+    assert!(h.client.is_claimed(&id, &128));
+    assert!(!h.client.is_claimed(&id, &1));
+    assert!(!h.client.is_claimed(&id, &129));
+}
+
+// ── CC-06 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic(expected = "Invalid proof")]
+fn cc_06_wrong_amount_rejected() {
+    let h = H::new(4);
+    let id = h.create(CampaignPayout::Tyc);
+    let (claimant, amount) = &h.entries[0];
+    h.client.claim(
+        claimant,
+        &id,
+        &0,
+        &(amount * 2),
+        &merkle::proof(&h.env, &h.leaves, 0),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Invalid proof")]
+fn cc_06_someone_elses_leaf_rejected() {
+    let h = H::new(4);
+    let id = h.create(CampaignPayout::Tyc);
+    let thief = Address::generate(&h.env);
+    let (_, amount) = &h.entries[0];
+    h.client.claim(
+        &thief,
+        &id,
+        &0,
+        amount,
+        &merkle::proof(&h.env, &h.leaves, 0),
+    );
+}
+
+/// With an odd leaf count the last leaf is paired with itself, so its proof
+/// also verifies one slot to the right. The leaf count closes that gap.
+#[test]
+fn cc_06_duplicated_last_leaf_cannot_be_claimed_twice() {
+    let h = H::new(3);
+    let id = h.create(CampaignPayout::Tyc);
+    let (claimant, amount) = &h.entries[2];
+    let proof = merkle::proof(&h.env, &h.leaves, 2);
+    let root = merkle::root(&h.env, &h.leaves);
+    assert!(merkle::verify(
+        &h.env,
+        &root,
+        3,
+        h.leaves.get(2).unwrap(),
+        &proof
+    ));
+
+    h.claim(id, 2);
+    let second = h.client.try_claim(claimant, &id, &3, amount, &proof);
+
+    assert!(second.is_err());
+    assert_eq!(h.tyc.balance(claimant), *amount as i128);
+}
+
+// ── CC-07 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic(expected = "Campaign ended")]
+fn cc_07_claim_at_deadline_rejected() {
+    let h = H::new(2);
+    let id = h.create(CampaignPayout::Tyc);
+    h.env.ledger().set_timestamp(NOW + DAY);
+    h.claim(id, 0);
+}
+
+#[test]
+#[should_panic(expected = "Campaign still active")]
+fn cc_07_reclaim_before_deadline_rejected() {
+    let h = H::new(2);
+    let id = h.create(CampaignPayout::Tyc);
+    h.env.ledger().set_timestamp(NOW + DAY - 1);
+    h.client.reclaim_campaign(&id, &h.admin);
+}
+
+#[test]
+fn cc_07_reclaim_returns_unclaimed_remainder_once() {
+    let h = H::new(4);
+    let id = h.create(CampaignPayout::Voucher);
+    let treasury = Address::generate(&h.env);
+    h.claim(id, 0);
+    h.claim(id, 3);
+
+    h.env.ledger().set_timestamp(NOW + DAY);
+    let reclaimed = h.client.reclaim_campaign(&id, &treasury);
+
+    // 10 TYC escrowed, 1 + 4 claimed as vouchers that stay backed.
+    assert_eq!(reclaimed, 5 * ONE_TYC);
+    assert_eq!(h.tyc.balance(&treasury), (5 * ONE_TYC) as i128);
+    assert_eq!(h.tyc.balance(&h.client.address), (5 * ONE_TYC) as i128);
+    assert!(h.client.campaign(&id).unwrap().reclaimed);
+    assert!(h.client.try_reclaim_campaign(&id, &treasury).is_err());
+}
+
+// ── CC-08 ─────────────────────────────────────────────────────────────────────
+
+/// A root whose leaves add up to more than the escrow cannot drain other
+/// funds held by the contract.
+#[test]
+#[should_panic(expected = "Campaign budget exceeded")]
+fn cc_08_claims_capped_at_total() {
+    let h = H::new(3);
+    let id = h.client.create_campaign(
+        &merkle::root(&h.env, &h.leaves),
+        &3,
+        &CampaignPayout::Tyc,
+        &(4 * ONE_TYC),
+        &(NOW + DAY),
+    );
+    h.claim(id, 2); // 3 TYC
+    h.claim(id, 1); // 2 TYC more — over budget
+}
+
+// ── CC-09 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic]
+fn cc_09_create_requires_admin() {
+    let h = H::new(2);
+    h.env.set_auths(&[]);
+    h.create(CampaignPayout::Tyc);
+}
+
+#[test]
+fn cc_09_invalid_parameters_rejected() {
+    let h = H::new(2);
+    let root = merkle::root(&h.env, &h.leaves);
+    let voucher = CampaignPayout::Voucher;
+
+    let no_leaves = h
+        .client
+        .try_create_campaign(&root, &0, &voucher, &ONE_TYC, &(NOW + DAY));
+    let no_total = h
+        .client
+        .try_create_campaign(&root, &2, &voucher, &0, &(NOW + DAY));
+    let past = h
+        .client
+        .try_create_campaign(&root, &2, &voucher, &ONE_TYC, &NOW);
+
+    assert!(no_leaves.is_err());
+    assert!(no_total.is_err());
+    assert!(past.is_err());
+    assert_eq!(h.client.campaign(&0), None);
+}
+
+#[test]
+#[should_panic(expected = "Contract is paused")]
+fn cc_09_claim_rejected_while_paused() {
+    let h = H::new(2);
+    let id = h.create(CampaignPayout::Tyc);
+    h.client.pause();
+    h.claim(id, 0);
+}
+
+// ── CC-10 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn cc_10_escrow_is_reserved_until_paid_out() {
+    let h = H::new(3);
+    let tyc_id = h.create(CampaignPayout::Tyc);
+    let voucher_id = h.create(CampaignPayout::Voucher);
+    assert_eq!(
+        h.client.outstanding_liabilities(&h.tyc.address),
+        2 * h.total()
+    );
+    assert_eq!(h.client.free_balance(&h.tyc.address), 0);
+
+    // A TYC claim pays out; a voucher claim moves escrow into a voucher.
+    h.claim(tyc_id, 0);
+    h.claim(voucher_id, 1);
+    assert_eq!(
+        h.client.outstanding_liabilities(&h.tyc.address),
+        2 * h.total() - ONE_TYC
+    );
+
+    h.env.ledger().set_timestamp(NOW + DAY);
+    h.client.reclaim_campaign(&tyc_id, &h.admin);
+    h.client.reclaim_campaign(&voucher_id, &h.admin);
+    // Only the claimed voucher is still backed.
+    assert_eq!(
+        h.client.outstanding_liabilities(&h.tyc.address),
+        2 * ONE_TYC
+    );
+    assert_eq!(h.client.free_balance(&h.tyc.address), 0);
+}
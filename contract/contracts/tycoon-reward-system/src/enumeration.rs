@@ -0,0 +1,91 @@
+//! Per-owner voucher index.
+//!
+//! `OwnedTokens(owner)` lists the ids `owner` holds a non-zero balance of, in
+//! the order they were received. `_mint` and `_burn` keep it in step with
+//! `OwnedTokenCount` by touching it only on zero ↔ non-zero transitions.
+//! Vouchers held before the index existed are not listed until the owner's
+//! balance of them next goes from zero to non-zero.
+use crate::DataKey;
+use soroban_sdk::{Address, Env, Vec};
+
+/// Maximum page size for `tokens_of_owner_page`. Every listed voucher costs a
+/// `VoucherValue` read, so the cap keeps a page well inside the per-transaction
+/// read limit.
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+pub(crate) fn owned_tokens(e: &Env, owner: &Address) -> Vec<u128> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::OwnedTokens(owner.clone()))
+        .unwrap_or_else(|| Vec::new(e))
+}
+
+/// Append `token_id` to `owner`'s index. Call on the zero → non-zero
+/// transition only.
+pub(crate) fn add_token_to_owner(e: &Env, owner: &Address, token_id: u128) {
+    let mut tokens = owned_tokens(e, owner);
+    tokens.push_back(token_id);
+    e.storage()
+        .persistent()
+        .set(&DataKey::OwnedTokens(owner.clone()), &tokens);
+}
+
+/// Drop `token_id` from `owner`'s index, keeping the order of the rest. Call
+/// on the non-zero → zero transition only.
+pub(crate) fn remove_token_from_owner(e: &Env, owner: &Address, token_id: u128) {
+    let key = DataKey::OwnedTokens(owner.clone());
+    let mut tokens = owned_tokens(e, owner);
+    let index = match tokens.first_index_of(token_id) {
+        Some(index) => index,
+        // Held before the index existed.
+        None => return,
+    };
+    tokens.remove(index);
+    if tokens.is_empty() {
+        e.storage().persistent().remove(&key);
+    } else {
+        e.storage().persistent().set(&key, &tokens);
+    }
+}
+
+fn voucher_value(e: &Env, token_id: u128) -> u128 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::VoucherValue(token_id))
+        .unwrap_or(0)
+}
+
+/// `(token_id, value)` at `index` of `owner`'s index, if any.
+pub(crate) fn token_of_owner_by_index(
+    e: &Env,
+    owner: &Address,
+    index: u32,
+) -> Option<(u128, u128)> {
+    let token_id = owned_tokens(e, owner).get(index)?;
+    Some((token_id, voucher_value(e, token_id)))
+}
+
+/// Page `page` (0-indexed) of `owner`'s vouchers as `(token_id, value)`.
+/// Pages past the end are empty.
+pub(crate) fn tokens_of_owner_page(
+    e: &Env,
+    owner: &Address,
+    page: u32,
+    page_size: u32,
+) -> Vec<(u128, u128)> {
+    if page_size == 0 || page_size > MAX_PAGE_SIZE {
+        panic!("Invalid page size");
+    }
+
+    let tokens = owned_tokens(e, owner);
+    let start = match page.checked_mul(page_size) {
+        Some(start) if start < tokens.len() => start,
+        _ => return Vec::new(e),
+    };
+    let end = (start + page_size).min(tokens.len());
+    let mut result = Vec::new(e);
+    for token_id in tokens.slice(start..end).iter() {
+        result.push_back((token_id, voucher_value(e, token_id)));
+    }
+    result
+}
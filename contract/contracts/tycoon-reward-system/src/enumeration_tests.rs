@@ -0,0 +1,196 @@
+/// # Owner enumeration
+///
+/// Covers `tokens_of_owner_page`, `token_of_owner_by_index` and the
+/// `OwnedTokens` index kept by `_mint` / `_burn`.
+///
+/// | ID    | What it pins |
+/// |-------|--------------|
+/// | EN-01 | Minted vouchers are listed in order with their values |
+/// | EN-02 | Transfers and redemptions move and drop ids, keeping the rest in order |
+/// | EN-03 | Pages split the list; pages past the end are empty |
+/// | EN-04 | Zero and oversized page sizes and out-of-range indices are rejected |
+/// | EN-05 | Partial redemption and splitting are reflected in the listing |
+/// | EN-06 | The list length matches `owned_token_count` |
+extern crate std;
+
+use crate::enumeration::MAX_PAGE_SIZE;
+use crate::test_support::{deref_setup, Setup};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Vec};
+
+const FUNDING: i128 = 1_000_000;
+
+// ── Harness ───────────────────────────────────────────────────────────────────
+
+struct H<'a> {
+    base: Setup<'a>,
+}
+deref_setup!(H);
+
+impl H<'_> {
+    /// Contract funded with `FUNDING` TYC.
+    fn new() -> Self {
+        let base = Setup::new();
+        base.fund_contract(&base.tyc, FUNDING);
+        H { base }
+    }
+
+    /// Mint vouchers worth `values` to `to`, returning their ids.
+    fn mint(&self, to: &Address, values: &[u128]) -> std::vec::Vec<u128> {
+        values
+            .iter()
+            .map(|value| self.client.mint_voucher(&self.admin, to, value))
+            .collect()
+    }
+
+    /// Every `(token_id, value)` of `owner`, read page by page.
+    fn all(&self, owner: &Address, page_size: u32) -> std::vec::Vec<(u128, u128)> {
+        let mut all = std::vec::Vec::new();
+        for page in 0.. {
+            let items = self.client.tokens_of_owner_page(owner, &page, &page_size);
+            if items.is_empty() {
+                break;
+            }
+            all.extend(items.iter());
+        }
+        all
+    }
+}
+
+// ── EN-01 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn en_01_minted_vouchers_listed_with_values() {
+    let h = H::new();
+    let user = Address::generate(&h.env);
+    let ids = h.mint(&user, &[100, 200, 300]);
+
+    assert_eq!(
+        h.all(&user, MAX_PAGE_SIZE),
+        std::vec![(ids[0], 100), (ids[1], 200), (ids[2], 300)]
+    );
+    assert_eq!(h.client.token_of_owner_by_index(&user, &1), (ids[1], 200));
+    assert_eq!(h.client.max_page_size(), MAX_PAGE_SIZE);
+}
+
+// ── EN-02 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn en_02_transfer_and_redeem_update_lists() {
+    let h = H::new();
+    let alice = Address::generate(&h.env);
+    let bob = Address::generate(&h.env);
+    let ids = h.mint(&alice, &[10, 20, 30, 40]);
+
+    h.client.transfer(&alice, &bob, &ids[1], &1);
+    h.client.redeem_voucher_from(&alice, &ids[2]);
+
+    assert_eq!(
+        h.all(&alice, MAX_PAGE_SIZE),
+        std::vec![(ids[0], 10), (ids[3], 40)]
+    );
+    assert_eq!(h.all(&bob, MAX_PAGE_SIZE), std::vec![(ids[1], 20)]);
+
+    h.client.redeem_voucher_from(&bob, &ids[1]);
+    assert!(h.all(&bob, MAX_PAGE_SIZE).is_empty());
+}
+
+// ── EN-03 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn en_03_pages_cover_list_in_order() {
+    let h = H::new();
+    let user = Address::generate(&h.env);
+    let ids = h.mint(&user, &[1, 2, 3, 4, 5, 6, 7]);
+
+    let first = h.client.tokens_of_owner_page(&user, &0, &3);
+    let last = h.client.tokens_of_owner_page(&user, &2, &3);
+    assert_eq!(first, vec![&h.env, (ids[0], 1), (ids[1], 2), (ids[2], 3)]);
+    assert_eq!(last, vec![&h.env, (ids[6], 7)]);
+    assert_eq!(
+        h.client.tokens_of_owner_page(&user, &3, &3),
+        Vec::new(&h.env)
+    );
+    assert_eq!(
+        h.client
+            .tokens_of_owner_page(&user, &u32::MAX, &MAX_PAGE_SIZE),
+        Vec::new(&h.env)
+    );
+    assert_eq!(h.all(&user, 2).len(), 7);
+}
+
+#[test]
+fn en_03_empty_owner_has_no_pages() {
+    let h = H::new();
+    let nobody = Address::generate(&h.env);
+    assert_eq!(
+        h.client.tokens_of_owner_page(&nobody, &0, &MAX_PAGE_SIZE),
+        Vec::new(&h.env)
+    );
+}
+
+// ── EN-04 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn en_04_invalid_page_size_rejected() {
+    let h = H::new();
+    let user = Address::generate(&h.env);
+    h.mint(&user, &[1]);
+
+    assert!(h.client.try_tokens_of_owner_page(&user, &0, &0).is_err());
+    assert!(h
+        .client
+        .try_tokens_of_owner_page(&user, &0, &(MAX_PAGE_SIZE + 1))
+        .is_err());
+}
+
+#[test]
+#[should_panic(expected = "Index out of bounds")]
+fn en_04_index_out_of_bounds_rejected() {
+    let h = H::new();
+    let user = Address::generate(&h.env);
+    h.mint(&user, &[1, 2]);
+    h.client.token_of_owner_by_index(&user, &2);
+}
+
+// ── EN-05 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn en_05_partial_redeem_and_split_reflected() {
+    let h = H::new();
+    let user = Address::generate(&h.env);
+    let ids = h.mint(&user, &[100, 500]);
+
+    h.client.redeem_partial(&user, &ids[0], &40);
+    let parts = h
+        .client
+        .split_voucher(&user, &ids[1], &vec![&h.env, 200, 300]);
+
+    assert_eq!(
+        h.all(&user, MAX_PAGE_SIZE),
+        std::vec![
+            (ids[0], 60),
+            (parts.get(0).unwrap(), 200),
+            (parts.get(1).unwrap(), 300)
+        ]
+    );
+}
+
+// ── EN-06 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn en_06_list_length_matches_owned_token_count() {
+    let h = H::new();
+    let alice = Address::generate(&h.env);
+    let bob = Address::generate(&h.env);
+    let ids = h.mint(&alice, &[1, 2, 3]);
+    h.client.transfer(&alice, &bob, &ids[0], &1);
+    h.client.redeem_voucher_from(&alice, &ids[1]);
+
+    for owner in [&alice, &bob] {
+        assert_eq!(
+            h.all(owner, MAX_PAGE_SIZE).len() as u32,
+            h.client.owned_token_count(owner)
+        );
+    }
+}
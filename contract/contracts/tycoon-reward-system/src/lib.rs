@@ -1,8 +1,116 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, Symbol};
+use mint_limit::{MintBudget, MintLimit};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, vec, Address, BytesN, Env, IntoVal, Symbol,
+    Val, Vec,
+};
+
+pub mod enumeration;
+pub mod merkle;
+pub mod mint_limit;
+pub mod streak;
 
 const VOUCHER_ID_START: u128 = 1_000_000_000;
 
+/// Current state schema version. v2 keeps configuration in instance storage.
+pub const STATE_VERSION: u32 = 2;
+
+/// Ledgers per day at ~5 s per ledger.
+const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// Instance storage (configuration) is extended to `INSTANCE_BUMP_AMOUNT`
+/// ledgers whenever an admin, minter or user call finds it below the
+/// threshold.
+pub const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+/// Voucher balances are extended to `BALANCE_BUMP_AMOUNT` ledgers whenever
+/// they are written.
+pub const BALANCE_BUMP_AMOUNT: u32 = 60 * DAY_IN_LEDGERS;
+pub const BALANCE_LIFETIME_THRESHOLD: u32 = BALANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+/// Configuration keys moved from persistent to instance storage in v2.
+const CONFIG_KEYS: [DataKey; 10] = [
+    DataKey::Admin,
+    DataKey::TycToken,
+    DataKey::UsdcToken,
+    DataKey::VoucherCount,
+    DataKey::Paused,
+    DataKey::BackendMinter,
+    DataKey::MintLimit,
+    DataKey::StreakTable,
+    DataKey::StreakGrace,
+    DataKey::CampaignCount,
+];
+
+/// Maximum vouchers per `sweep_expired` call. Each swept voucher removes its
+/// value, expiry and holder entries and burns every holder's balance (which
+/// also updates the holder's owned count and token list), and the sweep
+/// updates the liability total once, so the cap keeps a sweep within the
+/// per-transaction write limit.
+pub const MAX_SWEEP_BATCH: u32 = 8;
+
+/// Maximum recipients per `mint_vouchers_batch` call. A voucher minted to a
+/// first-time holder writes 5 entries (value, balance, owned count, holders,
+/// owned tokens). 10 vouchers already exceed the 50-entry per-transaction
+/// write limit, so the cap is 8 to leave headroom.
+pub const MAX_MINT_BATCH: u32 = 8;
+
+/// Asset a voucher is redeemed in.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VoucherAsset {
+    /// The configured TYC token. Vouchers minted before assets were tagged
+    /// are TYC vouchers.
+    Tyc,
+    /// The configured USDC token.
+    Usdc,
+    /// Any other SEP-41 token the admin has allowlisted.
+    Token(Address),
+}
+
+/// Summary returned by `voucher_info`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoucherInfo {
+    pub asset: VoucherAsset,
+    /// Amount of `asset` paid on redemption.
+    pub value: u128,
+    /// Unix timestamp (seconds) from which the voucher can no longer be
+    /// redeemed; `None` for vouchers that never expire.
+    pub expires_at: Option<u64>,
+    pub holder_count: u32,
+}
+
+/// What a claim campaign pays out.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CampaignPayout {
+    /// A voucher worth the claimed amount, redeemable for TYC.
+    Voucher,
+    /// The claimed amount of TYC, transferred directly.
+    Tyc,
+}
+
+/// A Merkle-root claim campaign. See `create_campaign`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Campaign {
+    /// Root of the `(address, amount)` tree built with `merkle`.
+    pub root: BytesN<32>,
+    /// Number of leaves; claim indices must be below it.
+    pub leaf_count: u32,
+    pub payout: CampaignPayout,
+    /// TYC escrowed when the campaign was created.
+    pub total: u128,
+    /// TYC paid out (or reserved by vouchers) so far.
+    pub claimed: u128,
+    /// Unix timestamp (seconds) from which claims are closed and the
+    /// unclaimed remainder can be reclaimed.
+    pub deadline: u64,
+    pub reclaimed: bool,
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
@@ -10,6 +118,12 @@ pub enum DataKey {
     Balance(Address, u128),
     // TokenID -> Value
     VoucherValue(u128),
+    // TokenID -> Expiry timestamp (absent if the voucher never expires)
+    VoucherExpiry(u128),
+    // TokenID -> VoucherAsset (absent for TYC vouchers)
+    VoucherAsset(u128),
+    // TokenID -> Addresses holding a non-zero balance
+    TokenHolders(u128),
     // TokenID -> Perk Enum (u32)
     CollectiblePerk(u128),
     // TokenID -> Strength
@@ -26,7 +140,32 @@ pub enum DataKey {
     BackendMinter,
     // (Owner) -> Total distinct vouchers owned
     OwnedTokenCount(Address),
+    // (Owner) -> Token ids with a non-zero balance, in order received
+    OwnedTokens(Address),
+    // (Owner, Operator) -> approval expiry (None if it never expires)
+    OperatorApproval(Address, Address),
+    // Voucher value per streak length
+    StreakTable,
+    // Days a player may miss without losing their streak
+    StreakGrace,
+    // Player -> StreakState
+    Streak(Address),
     StateVersion,
+    // CampaignID -> Campaign
+    Campaign(u32),
+    CampaignCount,
+    // (CampaignID, word) -> bitmap of claimed leaf indices, 128 per word
+    CampaignClaimed(u32, u32),
+    // Token -> true if vouchers may be minted in it
+    AllowedAsset(Address),
+    // Token -> value of live vouchers and campaign escrow payable in it
+    Liabilities(Address),
+    // Backend minter rate limit and current-window usage
+    MintLimit,
+    // Contract whose `is_registered` gates daily streak claims
+    PlayerRegistry,
+    // Referral minter address (tycoon-game), minting alongside the backend
+    ReferralMinter,
 }
 
 #[contract]
@@ -38,78 +177,95 @@ impl TycoonRewardSystem {
 
     /// Initialize the contract (one-time setup).
     pub fn initialize(e: Env, admin: Address, tyc_token: Address, usdc_token: Address) {
-        if e.storage().persistent().has(&DataKey::Admin) {
+        // A v1 contract awaiting `migrate` still has its admin in persistent
+        // storage and must not be re-initialized.
+        if e.storage().instance().has(&DataKey::Admin)
+            || e.storage().persistent().has(&DataKey::Admin)
+        {
             panic!("Already initialized");
         }
         // Batch all initialization writes together
-        e.storage().persistent().set(&DataKey::Admin, &admin);
-        e.storage().persistent().set(&DataKey::TycToken, &tyc_token);
+        e.storage().instance().set(&DataKey::Admin, &admin);
+        e.storage().instance().set(&DataKey::TycToken, &tyc_token);
+        e.storage().instance().set(&DataKey::UsdcToken, &usdc_token);
         e.storage()
-            .persistent()
-            .set(&DataKey::UsdcToken, &usdc_token);
+            .instance()
+            .set(&DataKey::VoucherCount, &VOUCHER_ID_START);
+        e.storage().instance().set(&DataKey::Paused, &false);
         e.storage()
             .persistent()
-            .set(&DataKey::VoucherCount, &VOUCHER_ID_START);
-        e.storage().persistent().set(&DataKey::Paused, &false);
-        e.storage().persistent().set(&DataKey::StateVersion, &1u32);
+            .set(&DataKey::StateVersion, &STATE_VERSION);
+        Self::extend_instance_ttl(&e);
     }
 
-    /// Migrate the contract to a newer state version (admin only)
+    /// Migrate the contract to `STATE_VERSION` (admin only). Call right after
+    /// upgrading: until a v1 contract is migrated its configuration is still
+    /// in persistent storage and every other entrypoint panics with
+    /// `"Not initialized"`.
+    ///
+    /// - v0 → v1: records the version.
+    /// - v1 → v2: moves `CONFIG_KEYS` from persistent to instance storage.
+    ///
+    /// `StateVersion` itself stays in persistent storage so it can be read
+    /// before and after the move.
     pub fn migrate(e: Env) {
-        let admin: Address = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Admin)
-            .expect("Not initialized");
-        admin.require_auth();
-
-        let current_version: u32 = e
+        let mut current_version: u32 = e
             .storage()
             .persistent()
             .get(&DataKey::StateVersion)
             .unwrap_or(0);
 
+        if current_version < 2 {
+            let admin: Address = e
+                .storage()
+                .persistent()
+                .get(&DataKey::Admin)
+                .or_else(|| e.storage().instance().get(&DataKey::Admin))
+                .expect("Not initialized");
+            admin.require_auth();
+        } else {
+            Self::require_admin(&e);
+        }
+
         if current_version == 0 {
-            e.storage().persistent().set(&DataKey::StateVersion, &1u32);
+            current_version = 1;
         }
+        if current_version == 1 {
+            for key in CONFIG_KEYS.iter() {
+                if let Some(value) = e.storage().persistent().get::<DataKey, Val>(key) {
+                    e.storage().instance().set(key, &value);
+                    e.storage().persistent().remove(key);
+                }
+            }
+            current_version = 2;
+        }
+        e.storage()
+            .persistent()
+            .set(&DataKey::StateVersion, &current_version);
+        Self::extend_instance_ttl(&e);
     }
 
     /// Emergency pause contract (admin only)
     pub fn pause(e: Env) {
-        let admin: Address = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Admin)
-            .expect("Not initialized");
-        admin.require_auth();
-        e.storage().persistent().set(&DataKey::Paused, &true);
+        Self::require_admin(&e);
+        e.storage().instance().set(&DataKey::Paused, &true);
         #[allow(deprecated)]
         e.events().publish((symbol_short!("Paused"),), true);
     }
 
     /// Emergency unpause contract (admin only)
     pub fn unpause(e: Env) {
-        let admin: Address = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Admin)
-            .expect("Not initialized");
-        admin.require_auth();
-        e.storage().persistent().set(&DataKey::Paused, &false);
+        Self::require_admin(&e);
+        e.storage().instance().set(&DataKey::Paused, &false);
         #[allow(deprecated)]
         e.events().publish((symbol_short!("Unpaused"),), false);
     }
 
     /// Set the backend minter address (admin only)
     pub fn set_backend_minter(e: Env, new_minter: Address) {
-        let admin: Address = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Admin)
-            .expect("Not initialized");
-        admin.require_auth();
+        Self::require_admin(&e);
         e.storage()
-            .persistent()
+            .instance()
             .set(&DataKey::BackendMinter, &new_minter);
         #[allow(deprecated)]
         e.events()
@@ -118,66 +274,245 @@ impl TycoonRewardSystem {
 
     /// Clear the backend minter address (admin only)
     pub fn clear_backend_minter(e: Env) {
-        let admin: Address = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Admin)
-            .expect("Not initialized");
-        admin.require_auth();
-        e.storage().persistent().remove(&DataKey::BackendMinter);
+        Self::require_admin(&e);
+        e.storage().instance().remove(&DataKey::BackendMinter);
         #[allow(deprecated)]
         e.events().publish((symbol_short!("clr_min"),), ());
     }
 
+    /// Set the referral minter (admin only): normally the tycoon-game contract,
+    /// which mints referral vouchers. It mints with the same rights and rate
+    /// limit as the backend minter, which it does not replace.
+    pub fn set_referral_minter(e: Env, minter: Address) {
+        Self::require_admin(&e);
+        e.storage()
+            .instance()
+            .set(&DataKey::ReferralMinter, &minter);
+        #[allow(deprecated)]
+        e.events().publish((symbol_short!("set_ref"), minter), ());
+    }
+
+    /// Clear the referral minter (admin only)
+    pub fn clear_referral_minter(e: Env) {
+        Self::require_admin(&e);
+        e.storage().instance().remove(&DataKey::ReferralMinter);
+        #[allow(deprecated)]
+        e.events().publish((symbol_short!("clr_ref"),), ());
+    }
+
+    /// Limit the backend minter to `max_count` vouchers and `max_value` total
+    /// voucher value per window of `period_ledgers` ledgers (admin only).
+    /// Usage in the open window is kept. See `mint_limit`.
+    pub fn set_mint_limit(e: Env, max_count: u32, max_value: u128, period_ledgers: u32) {
+        Self::require_admin(&e);
+        mint_limit::set(&e, max_count, max_value, period_ledgers);
+        #[allow(deprecated)]
+        e.events().publish(
+            (symbol_short!("set_limit"),),
+            (max_count, max_value, period_ledgers),
+        );
+    }
+
+    /// Remove the backend minter rate limit (admin only)
+    pub fn clear_mint_limit(e: Env) {
+        Self::require_admin(&e);
+        mint_limit::clear(&e);
+        #[allow(deprecated)]
+        e.events().publish((symbol_short!("clr_limit"),), ());
+    }
+
     // ── Public (user-initiated) entrypoints ──────────────────────────────────
     // These functions can be called by any authenticated user
 
+    /// The backend minter rate limit and its current-window usage, if set.
+    pub fn get_mint_limit(e: Env) -> Option<MintLimit> {
+        mint_limit::read(&e)
+    }
+
+    /// What the backend minter may still mint in the current window, or
+    /// `None` if it is not rate limited.
+    pub fn remaining_mint_budget(e: Env) -> Option<MintBudget> {
+        mint_limit::read(&e).map(|limit| limit.remaining_at(e.ledger().sequence()))
+    }
+
     /// Get the current backend minter address. Returns None if not set.
     pub fn get_backend_minter(e: Env) -> Option<Address> {
         // Single read — avoids the has() + get() double-read pattern
-        e.storage().persistent().get(&DataKey::BackendMinter)
+        e.storage().instance().get(&DataKey::BackendMinter)
     }
 
+    /// Get the current referral minter address. Returns None if not set.
+    pub fn get_referral_minter(e: Env) -> Option<Address> {
+        e.storage().instance().get(&DataKey::ReferralMinter)
+    }
+
+    /// Mint a TYC voucher worth `tyc_value` to `to` and return its id.
+    ///
+    /// A minter's mint that would exceed the rate limit pauses the contract and
+    /// returns 0 without minting, so contract callers must treat 0 as a
+    /// refused mint. See `mint_limit`.
     pub fn mint_voucher(e: Env, caller: Address, to: Address, tyc_value: u128) -> u128 {
-        // Single read for admin — reused for both auth check and comparison
-        let admin: Address = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Admin)
-            .expect("Not initialized");
-        caller.require_auth();
+        if !Self::require_minter(&e, caller, 1, tyc_value) {
+            return 0;
+        }
+        Self::_mint_voucher(&e, to, VoucherAsset::Tyc, tyc_value, None)
+    }
 
-        // Single read for BackendMinter — replaces has() + get() double-read
-        let backend_minter: Option<Address> = e.storage().persistent().get(&DataKey::BackendMinter);
+    /// Mint a voucher redeemable for `amount` of `asset`. `VoucherAsset::Token`
+    /// must be allowlisted with `set_asset_allowed`. Rate limited like
+    /// `mint_voucher`.
+    pub fn mint_asset_voucher(
+        e: Env,
+        caller: Address,
+        to: Address,
+        asset: VoucherAsset,
+        amount: u128,
+    ) -> u128 {
+        if !Self::require_minter(&e, caller, 1, amount) {
+            return 0;
+        }
+        if let VoucherAsset::Token(token) = &asset {
+            if !Self::is_asset_allowed(e.clone(), token.clone()) {
+                panic!("Asset not allowed");
+            }
+        }
+        Self::_mint_voucher(&e, to, asset, amount, None)
+    }
 
-        let is_authorized = caller == admin || backend_minter == Some(caller);
+    /// Allow or disallow vouchers in `token` (admin only). TYC and USDC are
+    /// always allowed. Disallowing a token does not affect vouchers already
+    /// minted in it.
+    pub fn set_asset_allowed(e: Env, token: Address, allowed: bool) {
+        Self::require_admin(&e);
+        let key = DataKey::AllowedAsset(token.clone());
+        if allowed {
+            e.storage().persistent().set(&key, &true);
+        } else {
+            e.storage().persistent().remove(&key);
+        }
+        #[allow(deprecated)]
+        e.events()
+            .publish((symbol_short!("AssetAlw"), token), allowed);
+    }
 
-        if !is_authorized {
-            panic!("Unauthorized: only admin or backend minter can mint");
+    pub fn is_asset_allowed(e: Env, token: Address) -> bool {
+        e.storage().persistent().has(&DataKey::AllowedAsset(token))
+    }
+
+    /// Mint one voucher per `(recipient, tyc_value)` entry with a single
+    /// authorization. Ids are consecutive and returned in input order.
+    ///
+    /// A backend batch that would exceed the rate limit pauses the contract
+    /// and returns no ids without minting.
+    pub fn mint_vouchers_batch(
+        e: Env,
+        caller: Address,
+        recipients: Vec<(Address, u128)>,
+    ) -> Vec<u128> {
+        if recipients.is_empty() {
+            panic!("Batch is empty");
+        }
+        if recipients.len() > MAX_MINT_BATCH {
+            panic!("Batch too large");
         }
 
-        // Read-increment-write in one block; no intermediate clone needed
-        let token_id: u128 = e
-            .storage()
-            .persistent()
-            .get(&DataKey::VoucherCount)
-            .unwrap_or(VOUCHER_ID_START);
-        e.storage()
-            .persistent()
-            .set(&DataKey::VoucherCount, &(token_id + 1));
+        let mut total: u128 = 0;
+        for (_, tyc_value) in recipients.iter() {
+            total = total.checked_add(tyc_value).expect("Liability overflow");
+        }
+        if !Self::require_minter(&e, caller, recipients.len(), total) {
+            return Vec::new(&e);
+        }
 
-        e.storage()
+        let mut ids = Vec::new(&e);
+        for (to, tyc_value) in recipients.iter() {
+            ids.push_back(Self::_mint_voucher(
+                &e,
+                to,
+                VoucherAsset::Tyc,
+                tyc_value,
+                None,
+            ));
+        }
+        ids
+    }
+
+    /// Mint a voucher that can only be redeemed before `expires_at` (Unix
+    /// seconds). Once expired it can be cleared by anyone with `sweep_expired`.
+    /// Rate limited like `mint_voucher`.
+    pub fn mint_voucher_with_expiry(
+        e: Env,
+        caller: Address,
+        to: Address,
+        tyc_value: u128,
+        expires_at: u64,
+    ) -> u128 {
+        if expires_at <= e.ledger().timestamp() {
+            panic!("Expiry must be in the future");
+        }
+        if !Self::require_minter(&e, caller, 1, tyc_value) {
+            return 0;
+        }
+        Self::_mint_voucher(&e, to, VoucherAsset::Tyc, tyc_value, Some(expires_at))
+    }
+
+    /// Value, expiry and number of holders of `token_id`, or `None` if it is
+    /// not a live voucher.
+    pub fn voucher_info(e: Env, token_id: u128) -> Option<VoucherInfo> {
+        let value: u128 = e
+            .storage()
             .persistent()
-            .set(&DataKey::VoucherValue(token_id), &tyc_value);
+            .get(&DataKey::VoucherValue(token_id))?;
+        Some(VoucherInfo {
+            asset: Self::voucher_asset(&e, token_id),
+            value,
+            expires_at: e
+                .storage()
+                .persistent()
+                .get(&DataKey::VoucherExpiry(token_id)),
+            holder_count: Self::holders(&e, token_id).len(),
+        })
+    }
 
-        // _mint emits its own "Mint" event; no extra event needed here
-        Self::_mint(&e, to.clone(), token_id, 1);
+    /// Burn every expired voucher in `token_ids` from all of its holders and
+    /// drop its value, freeing the TYC it reserved. Permissionless; ids that
+    /// are unknown or not yet expired are skipped. Returns the number swept.
+    pub fn sweep_expired(e: Env, token_ids: Vec<u128>) -> u32 {
+        if token_ids.len() > MAX_SWEEP_BATCH {
+            panic!("Batch too large");
+        }
+        let now = e.ledger().timestamp();
+        let mut swept = 0;
+        for token_id in token_ids.iter() {
+            let expiry_key = DataKey::VoucherExpiry(token_id);
+            let expired = e
+                .storage()
+                .persistent()
+                .get::<DataKey, u64>(&expiry_key)
+                .is_some_and(|expires_at| now >= expires_at);
+            if !expired {
+                continue;
+            }
+            let value_key = DataKey::VoucherValue(token_id);
+            let value: u128 = match e.storage().persistent().get(&value_key) {
+                Some(value) => value,
+                None => continue,
+            };
 
-        #[allow(deprecated)]
-        e.events()
-            .publish((symbol_short!("V_Mint"), to, token_id), tyc_value);
+            for holder in Self::holders(&e, token_id).iter() {
+                let balance = Self::balance_of(&e, holder.clone(), token_id);
+                Self::_burn(&e, holder, token_id, balance);
+            }
+            let asset = Self::voucher_asset(&e, token_id);
+            Self::remove_voucher(&e, token_id, &asset);
+            Self::release_liability(&e, &Self::asset_token(&e, &asset), value);
 
-        token_id
+            #[allow(deprecated)]
+            e.events()
+                .publish((symbol_short!("V_Expire"), token_id), value);
+            swept += 1;
+        }
+        swept
     }
 
     #[deprecated(note = "Use redeem_voucher_from instead")]
@@ -187,78 +522,113 @@ impl TycoonRewardSystem {
 
     pub fn redeem_voucher_from(e: Env, redeemer: Address, token_id: u128) {
         redeemer.require_auth();
+        Self::require_not_paused(&e);
 
-        // Single read for Paused — unwrap_or avoids a separate has() check
-        if e.storage()
-            .persistent()
-            .get::<DataKey, bool>(&DataKey::Paused)
-            .unwrap_or(false)
-        {
-            panic!("Contract is paused");
+        let value = Self::live_value(&e, token_id);
+        Self::_redeem(&e, redeemer, token_id, value, value);
+    }
+
+    /// Redeem `amount` of a voucher's value and keep the rest on the same
+    /// voucher id. Redeeming the whole value burns the voucher like
+    /// `redeem_voucher_from`.
+    pub fn redeem_partial(e: Env, redeemer: Address, token_id: u128, amount: u128) {
+        redeemer.require_auth();
+        Self::require_not_paused(&e);
+
+        let value = Self::live_value(&e, token_id);
+        if amount == 0 {
+            panic!("Amount must be positive");
         }
+        if amount > value {
+            panic!("Amount exceeds voucher value");
+        }
+        Self::_redeem(&e, redeemer, token_id, value, amount);
+    }
 
-        let tyc_value: u128 = e
-            .storage()
-            .persistent()
-            .get(&DataKey::VoucherValue(token_id))
-            .expect("Invalid token_id");
+    /// Burn `token_id` and mint one voucher per entry of `amounts` to `owner`,
+    /// in the same asset and with the same expiry. `amounts` must sum to the
+    /// voucher's value, so outstanding value is unchanged. Returns the new ids.
+    pub fn split_voucher(e: Env, owner: Address, token_id: u128, amounts: Vec<u128>) -> Vec<u128> {
+        owner.require_auth();
+        Self::require_not_paused(&e);
 
-        // Burn first (validates balance), then transfer
-        Self::_burn(&e, redeemer.clone(), token_id, 1);
+        if amounts.len() < 2 {
+            panic!("Split needs at least two parts");
+        }
+        if amounts.len() > MAX_MINT_BATCH {
+            panic!("Batch too large");
+        }
+        let value = Self::live_value(&e, token_id);
+        let mut total: u128 = 0;
+        for amount in amounts.iter() {
+            if amount == 0 {
+                panic!("Amount must be positive");
+            }
+            total = total.checked_add(amount).expect("Split overflow");
+        }
+        if total != value {
+            panic!("Split amounts must sum to voucher value");
+        }
 
-        let tyc_token: Address = e
+        Self::_burn(&e, owner.clone(), token_id, 1);
+        let asset = Self::voucher_asset(&e, token_id);
+        let expires_at: Option<u64> = e
             .storage()
             .persistent()
-            .get(&DataKey::TycToken)
-            .expect("Not initialized");
-
-        soroban_sdk::token::Client::new(&e, &tyc_token).transfer(
-            &e.current_contract_address(),
-            &redeemer,
-            &(tyc_value as i128),
-        );
+            .get(&DataKey::VoucherExpiry(token_id));
+        Self::remove_voucher(&e, token_id, &asset);
+        Self::release_liability(&e, &Self::asset_token(&e, &asset), value);
 
-        // Remove voucher value entry after successful transfer
-        e.storage()
-            .persistent()
-            .remove(&DataKey::VoucherValue(token_id));
+        let mut ids = Vec::new(&e);
+        for amount in amounts.iter() {
+            ids.push_back(Self::_mint_voucher(
+                &e,
+                owner.clone(),
+                asset.clone(),
+                amount,
+                expires_at,
+            ));
+        }
 
         #[allow(deprecated)]
         e.events()
-            .publish((symbol_short!("Redeem"), redeemer, token_id), tyc_value);
+            .publish((symbol_short!("Split"), owner, token_id), ids.clone());
+        ids
     }
 
     /// Withdraw funds from the contract (admin only)
     pub fn withdraw_funds(e: Env, token: Address, to: Address, amount: u128) {
-        let admin: Address = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Admin)
-            .expect("Not initialized");
-        admin.require_auth();
+        Self::require_admin(&e);
 
         // Read both token addresses in two reads (unavoidable), but reuse locals
         let tyc_token: Address = e
             .storage()
-            .persistent()
+            .instance()
             .get(&DataKey::TycToken)
             .expect("Not initialized");
         let usdc_token: Address = e
             .storage()
-            .persistent()
+            .instance()
             .get(&DataKey::UsdcToken)
             .expect("Not initialized");
 
-        if token != tyc_token && token != usdc_token {
+        if token != tyc_token
+            && token != usdc_token
+            && !Self::is_asset_allowed(e.clone(), token.clone())
+        {
             panic!("Invalid token: not in allowlist");
         }
 
         let token_client = soroban_sdk::token::Client::new(&e, &token);
         let contract_address = e.current_contract_address();
 
-        if token_client.balance(&contract_address) < amount as i128 {
+        let balance = token_client.balance(&contract_address);
+        if balance < amount as i128 {
             panic!("Insufficient contract balance");
         }
+        if amount > (balance as u128).saturating_sub(Self::liabilities(&e, &token)) {
+            panic!("Withdrawal would leave vouchers underfunded");
+        }
 
         token_client.transfer(&contract_address, &to, &(amount as i128));
 
@@ -273,6 +643,20 @@ impl TycoonRewardSystem {
         Self::balance_of(&e, owner, token_id)
     }
 
+    /// Value of live vouchers plus unclaimed campaign escrow payable in
+    /// `token`.
+    pub fn outstanding_liabilities(e: Env, token: Address) -> u128 {
+        Self::liabilities(&e, &token)
+    }
+
+    /// Contract balance of `token` not reserved for liabilities; what
+    /// `withdraw_funds` may take. Zero if the contract is underfunded.
+    pub fn free_balance(e: Env, token: Address) -> u128 {
+        let balance = soroban_sdk::token::Client::new(&e, &token)
+            .balance(&e.current_contract_address()) as u128;
+        balance.saturating_sub(Self::liabilities(&e, &token))
+    }
+
     /// Get the number of distinct voucher tokens owned by an address
     pub fn owned_token_count(e: Env, owner: Address) -> u32 {
         e.storage()
@@ -281,30 +665,676 @@ impl TycoonRewardSystem {
             .unwrap_or(0)
     }
 
+    /// `(token_id, value)` of the voucher at `index` in `owner`'s list.
+    /// Panics if the index is out of bounds.
+    pub fn token_of_owner_by_index(e: Env, owner: Address, index: u32) -> (u128, u128) {
+        enumeration::token_of_owner_by_index(&e, &owner, index)
+            .unwrap_or_else(|| panic!("Index out of bounds"))
+    }
+
+    /// Page `page` (0-indexed) of `owner`'s vouchers as `(token_id, value)`,
+    /// in the order they were received. `page_size` must be between 1 and
+    /// `max_page_size()`.
+    pub fn tokens_of_owner_page(
+        e: Env,
+        owner: Address,
+        page: u32,
+        page_size: u32,
+    ) -> Vec<(u128, u128)> {
+        enumeration::tokens_of_owner_page(&e, &owner, page, page_size)
+    }
+
+    /// Largest `page_size` accepted by `tokens_of_owner_page`.
+    pub fn max_page_size(_e: Env) -> u32 {
+        enumeration::MAX_PAGE_SIZE
+    }
+
     /// Transfer vouchers from one address to another
     pub fn transfer(e: Env, from: Address, to: Address, token_id: u128, amount: u64) {
         from.require_auth();
+        Self::require_not_paused(&e);
+        Self::_transfer(&e, from, to, token_id, amount);
+    }
 
-        if e.storage()
+    /// Let `operator` move all of `owner`'s vouchers with `transfer_from`, or
+    /// revoke that. An approval may end at `expires_at` (Unix seconds).
+    pub fn set_approval_for_all(
+        e: Env,
+        owner: Address,
+        operator: Address,
+        approved: bool,
+        expires_at: Option<u64>,
+    ) {
+        owner.require_auth();
+        Self::require_not_paused(&e);
+
+        if owner == operator {
+            panic!("Cannot approve self");
+        }
+        let key = DataKey::OperatorApproval(owner.clone(), operator.clone());
+        if approved {
+            if let Some(expires_at) = expires_at {
+                if expires_at <= e.ledger().timestamp() {
+                    panic!("Expiry must be in the future");
+                }
+            }
+            e.storage().persistent().set(&key, &expires_at);
+        } else {
+            e.storage().persistent().remove(&key);
+        }
+
+        #[allow(deprecated)]
+        e.events().publish(
+            (symbol_short!("Approval"), owner, operator),
+            (approved, expires_at),
+        );
+    }
+
+    /// Whether `operator` may currently move `owner`'s vouchers.
+    pub fn is_approved_for_all(e: Env, owner: Address, operator: Address) -> bool {
+        match e
+            .storage()
             .persistent()
-            .get::<DataKey, bool>(&DataKey::Paused)
-            .unwrap_or(false)
+            .get::<DataKey, Option<u64>>(&DataKey::OperatorApproval(owner, operator))
         {
-            panic!("Contract is paused");
+            Some(Some(expires_at)) => e.ledger().timestamp() < expires_at,
+            Some(None) => true,
+            None => false,
         }
+    }
+
+    /// Transfer `from`'s vouchers on their behalf. `operator` must be `from`
+    /// or hold a live approval from `from`.
+    pub fn transfer_from(
+        e: Env,
+        operator: Address,
+        from: Address,
+        to: Address,
+        token_id: u128,
+        amount: u64,
+    ) {
+        operator.require_auth();
+        Self::require_not_paused(&e);
+
+        if operator != from && !Self::is_approved_for_all(e.clone(), from.clone(), operator) {
+            panic!("Not approved");
+        }
+        Self::_transfer(&e, from, to, token_id, amount);
+    }
+
+    // ── Claim campaigns ──────────────────────────────────────────────────────
 
+    /// Open a claim campaign over a Merkle tree of `(address, amount)` leaves
+    /// (admin only). `total` TYC is escrowed from the admin up front; claims
+    /// are accepted until `deadline` (Unix seconds), after which the admin can
+    /// reclaim whatever is left. Returns the campaign id.
+    pub fn create_campaign(
+        e: Env,
+        root: BytesN<32>,
+        leaf_count: u32,
+        payout: CampaignPayout,
+        total: u128,
+        deadline: u64,
+    ) -> u32 {
+        let admin = Self::require_admin(&e);
+
+        if leaf_count == 0 {
+            panic!("Campaign has no leaves");
+        }
+        if total == 0 {
+            panic!("Total must be positive");
+        }
+        if deadline <= e.ledger().timestamp() {
+            panic!("Deadline must be in the future");
+        }
+
+        let tyc_token: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::TycToken)
+            .expect("Not initialized");
+        soroban_sdk::token::Client::new(&e, &tyc_token).transfer(
+            &admin,
+            e.current_contract_address(),
+            &(total as i128),
+        );
+        Self::add_liability(&e, &tyc_token, total);
+
+        let campaign_id: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::CampaignCount)
+            .unwrap_or(0);
+        e.storage()
+            .instance()
+            .set(&DataKey::CampaignCount, &(campaign_id + 1));
+        e.storage().persistent().set(
+            &DataKey::Campaign(campaign_id),
+            &Campaign {
+                root: root.clone(),
+                leaf_count,
+                payout,
+                total,
+                claimed: 0,
+                deadline,
+                reclaimed: false,
+            },
+        );
+
+        #[allow(deprecated)]
+        e.events().publish(
+            (symbol_short!("Campaign"), campaign_id),
+            (root, total, deadline),
+        );
+        campaign_id
+    }
+
+    /// Claim `amount` from a campaign by proving that `(claimant, amount)` is
+    /// the leaf at `index`. Pays a voucher or TYC depending on the campaign and
+    /// returns the voucher id for voucher campaigns.
+    pub fn claim(
+        e: Env,
+        claimant: Address,
+        campaign_id: u32,
+        index: u32,
+        amount: u128,
+        proof: Vec<BytesN<32>>,
+    ) -> Option<u128> {
+        claimant.require_auth();
+        Self::require_not_paused(&e);
+
+        let campaign_key = DataKey::Campaign(campaign_id);
+        let mut campaign: Campaign = e
+            .storage()
+            .persistent()
+            .get(&campaign_key)
+            .expect("Campaign not found");
+        if e.ledger().timestamp() >= campaign.deadline {
+            panic!("Campaign ended");
+        }
+        if Self::is_claimed(e.clone(), campaign_id, index) {
+            panic!("Already claimed");
+        }
+        let leaf = merkle::leaf_hash(&e, &claimant, amount);
+        if index >= campaign.leaf_count || !merkle::verify(&e, &campaign.root, index, leaf, &proof)
+        {
+            panic!("Invalid proof");
+        }
+        let claimed = campaign
+            .claimed
+            .checked_add(amount)
+            .filter(|claimed| *claimed <= campaign.total)
+            .expect("Campaign budget exceeded");
+
+        let word_key = DataKey::CampaignClaimed(campaign_id, index / 128);
+        let word: u128 = e.storage().persistent().get(&word_key).unwrap_or(0);
+        e.storage()
+            .persistent()
+            .set(&word_key, &(word | (1 << (index % 128))));
+        campaign.claimed = claimed;
+        e.storage().persistent().set(&campaign_key, &campaign);
+
+        // The claim leaves the escrow; a voucher payout re-reserves it.
+        let tyc_token = Self::asset_token(&e, &VoucherAsset::Tyc);
+        Self::release_liability(&e, &tyc_token, amount);
+        let voucher_id = match campaign.payout {
+            CampaignPayout::Voucher => Some(Self::_mint_voucher(
+                &e,
+                claimant.clone(),
+                VoucherAsset::Tyc,
+                amount,
+                None,
+            )),
+            CampaignPayout::Tyc => {
+                soroban_sdk::token::Client::new(&e, &tyc_token).transfer(
+                    &e.current_contract_address(),
+                    &claimant,
+                    &(amount as i128),
+                );
+                None
+            }
+        };
+
+        #[allow(deprecated)]
+        e.events()
+            .publish((symbol_short!("Claim"), claimant, campaign_id), amount);
+        voucher_id
+    }
+
+    /// Return the unclaimed remainder of an ended campaign to `to` (admin
+    /// only). Can be called once per campaign. Returns the amount reclaimed.
+    pub fn reclaim_campaign(e: Env, campaign_id: u32, to: Address) -> u128 {
+        Self::require_admin(&e);
+
+        let campaign_key = DataKey::Campaign(campaign_id);
+        let mut campaign: Campaign = e
+            .storage()
+            .persistent()
+            .get(&campaign_key)
+            .expect("Campaign not found");
+        if e.ledger().timestamp() < campaign.deadline {
+            panic!("Campaign still active");
+        }
+        if campaign.reclaimed {
+            panic!("Campaign already reclaimed");
+        }
+        campaign.reclaimed = true;
+        e.storage().persistent().set(&campaign_key, &campaign);
+
+        let remainder = campaign.total - campaign.claimed;
+        if remainder > 0 {
+            let tyc_token = Self::asset_token(&e, &VoucherAsset::Tyc);
+            Self::release_liability(&e, &tyc_token, remainder);
+            soroban_sdk::token::Client::new(&e, &tyc_token).transfer(
+                &e.current_contract_address(),
+                &to,
+                &(remainder as i128),
+            );
+        }
+
+        #[allow(deprecated)]
+        e.events()
+            .publish((symbol_short!("Reclaim"), campaign_id, to), remainder);
+        remainder
+    }
+
+    pub fn campaign(e: Env, campaign_id: u32) -> Option<Campaign> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::Campaign(campaign_id))
+    }
+
+    /// Whether the leaf at `index` of `campaign_id` has been claimed.
+    pub fn is_claimed(e: Env, campaign_id: u32, index: u32) -> bool {
+        let word: u128 = e
+            .storage()
+            .persistent()
+            .get(&DataKey::CampaignClaimed(campaign_id, index / 128))
+            .unwrap_or(0);
+        word & (1 << (index % 128)) != 0
+    }
+
+    // ── Daily streaks ────────────────────────────────────────────────────────
+
+    /// Set the voucher value paid for each streak length (admin only): entry
+    /// `n - 1` for a streak of `n` days, the last entry beyond the table.
+    pub fn set_streak_table(e: Env, table: Vec<u128>) {
+        Self::require_admin(&e);
+
+        if table.is_empty() {
+            panic!("Streak table is empty");
+        }
+        if table.len() > streak::MAX_STREAK_TABLE {
+            panic!("Streak table too long");
+        }
+        e.storage().instance().set(&DataKey::StreakTable, &table);
+
+        #[allow(deprecated)]
+        e.events().publish((symbol_short!("StrkTable"),), table);
+    }
+
+    /// Set how many whole days a player may miss without losing their streak
+    /// (admin only).
+    pub fn set_streak_grace(e: Env, days: u32) {
+        Self::require_admin(&e);
+
+        e.storage().instance().set(&DataKey::StreakGrace, &days);
+
+        #[allow(deprecated)]
+        e.events().publish((symbol_short!("StrkGrace"),), days);
+    }
+
+    /// Set the contract whose `is_registered(player)` view gates
+    /// `claim_daily`, normally tycoon-game (admin only).
+    pub fn set_player_registry(e: Env, registry: Address) {
+        Self::require_admin(&e);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::PlayerRegistry, &registry);
+
+        #[allow(deprecated)]
+        e.events().publish((symbol_short!("Registry"),), registry);
+    }
+
+    pub fn get_player_registry(e: Env) -> Option<Address> {
+        e.storage().instance().get(&DataKey::PlayerRegistry)
+    }
+
+    pub fn get_streak_table(e: Env) -> Vec<u128> {
+        streak::table(&e)
+    }
+
+    pub fn get_streak_grace(e: Env) -> u32 {
+        streak::grace_days(&e)
+    }
+
+    /// Claim today's streak reward: mints `player` a TYC voucher worth the
+    /// streak table entry for their new streak. Once per UTC day. Returns the
+    /// voucher id.
+    ///
+    /// `player` must be registered with the player registry. Claims draw on
+    /// the mint limit budget and are refused once it is spent.
+    pub fn claim_daily(e: Env, player: Address) -> u128 {
+        player.require_auth();
+        Self::require_not_paused(&e);
+        Self::require_registered(&e, &player);
+
+        let (streak, value) = streak::record_claim(&e, &player);
+        if !mint_limit::consume(&e, 1, value) {
+            panic!("Mint limit reached");
+        }
+        let token_id = Self::_mint_voucher(&e, player.clone(), VoucherAsset::Tyc, value, None);
+
+        #[allow(deprecated)]
+        e.events()
+            .publish((symbol_short!("Streak"), player, token_id), (streak, value));
+        token_id
+    }
+
+    /// `player`'s streak if it is still alive, 0 otherwise.
+    pub fn current_streak(e: Env, player: Address) -> u32 {
+        streak::current_streak(&e, &player)
+    }
+
+    /// Unix timestamp from which `player` may call `claim_daily` again; the
+    /// current time if they may claim now.
+    pub fn next_claim_time(e: Env, player: Address) -> u64 {
+        streak::next_claim_time(&e, &player)
+    }
+}
+
+impl TycoonRewardSystem {
+    /// Require the admin's auth and return the admin.
+    fn require_admin(e: &Env) -> Address {
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Not initialized");
+        admin.require_auth();
+        Self::extend_instance_ttl(e);
+        admin
+    }
+
+    /// Panic unless the player registry reports `player` as registered.
+    fn require_registered(e: &Env, player: &Address) {
+        let registry: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::PlayerRegistry)
+            .expect("Player registry not set");
+        let registered: bool = e.invoke_contract(
+            &registry,
+            &Symbol::new(e, "is_registered"),
+            vec![e, player.into_val(e)],
+        );
+        if !registered {
+            panic!("Player not registered");
+        }
+    }
+
+    fn extend_instance_ttl(e: &Env) {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Extend `owner`'s balance of `token_id`.
+    fn extend_balance_ttl(e: &Env, owner: &Address, token_id: u128) {
+        e.storage().persistent().extend_ttl(
+            &DataKey::Balance(owner.clone(), token_id),
+            BALANCE_LIFETIME_THRESHOLD,
+            BALANCE_BUMP_AMOUNT,
+        );
+    }
+
+    /// Require `caller`'s auth and that it is the admin, the backend minter or
+    /// the referral minter, and charge a minter's mint of `count` vouchers
+    /// worth `value` against the rate limit. Returns `false` if the charge
+    /// tripped the breaker; the caller must then return without minting so
+    /// the pause is kept.
+    ///
+    /// Minters cannot mint while the contract is paused.
+    fn require_minter(e: &Env, caller: Address, count: u32, value: u128) -> bool {
+        // Single read for admin — reused for both auth check and comparison
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Not initialized");
+        caller.require_auth();
+        Self::extend_instance_ttl(e);
+
+        // Single read for BackendMinter — replaces has() + get() double-read
+        let backend_minter: Option<Address> = e.storage().instance().get(&DataKey::BackendMinter);
+
+        if caller == admin {
+            return true;
+        }
+        let referral_minter: Option<Address> = e.storage().instance().get(&DataKey::ReferralMinter);
+        if backend_minter.as_ref() != Some(&caller) && referral_minter != Some(caller) {
+            panic!("Unauthorized: only admin or backend minter can mint");
+        }
+
+        Self::require_not_paused(e);
+        if mint_limit::consume(e, count, value) {
+            return true;
+        }
+        e.storage().instance().set(&DataKey::Paused, &true);
+        #[allow(deprecated)]
+        e.events()
+            .publish((symbol_short!("Breaker"),), (count, value));
+        false
+    }
+
+    /// Shared body of every voucher mint. Callers check authorization and
+    /// charge the rate limit.
+    fn _mint_voucher(
+        e: &Env,
+        to: Address,
+        asset: VoucherAsset,
+        value: u128,
+        expires_at: Option<u64>,
+    ) -> u128 {
+        Self::add_liability(e, &Self::asset_token(e, &asset), value);
+
+        // Read-increment-write in one block; no intermediate clone needed
+        let token_id: u128 = e
+            .storage()
+            .instance()
+            .get(&DataKey::VoucherCount)
+            .unwrap_or(VOUCHER_ID_START);
+        e.storage()
+            .instance()
+            .set(&DataKey::VoucherCount, &(token_id + 1));
+
+        e.storage()
+            .persistent()
+            .set(&DataKey::VoucherValue(token_id), &value);
+        if let Some(expires_at) = expires_at {
+            e.storage()
+                .persistent()
+                .set(&DataKey::VoucherExpiry(token_id), &expires_at);
+        }
+        // TYC is implied by an absent tag, which keeps TYC mints at their
+        // original write count and old vouchers readable as TYC.
+        if asset != VoucherAsset::Tyc {
+            e.storage()
+                .persistent()
+                .set(&DataKey::VoucherAsset(token_id), &asset);
+            #[allow(deprecated)]
+            e.events()
+                .publish((symbol_short!("V_Asset"), token_id), asset);
+        }
+
+        // _mint emits its own "Mint" event; no extra event needed here
+        Self::_mint(e, to.clone(), token_id, 1);
+
+        #[allow(deprecated)]
+        e.events()
+            .publish((symbol_short!("V_Mint"), to, token_id), value);
+
+        token_id
+    }
+
+    fn _transfer(e: &Env, from: Address, to: Address, token_id: u128, amount: u64) {
         // _burn and _mint each emit their own internal events; the outer
         // "Transfer" event is the only caller-visible one needed here.
-        Self::_burn(&e, from.clone(), token_id, amount);
-        Self::_mint(&e, to.clone(), token_id, amount);
+        Self::_burn(e, from.clone(), token_id, amount);
+        Self::_mint(e, to.clone(), token_id, amount);
 
         #[allow(deprecated)]
         e.events()
             .publish((symbol_short!("Transfer"), from, to, token_id), amount);
     }
-}
 
-impl TycoonRewardSystem {
+    fn require_not_paused(e: &Env) {
+        // Single read for Paused — unwrap_or avoids a separate has() check
+        if e.storage()
+            .instance()
+            .get::<DataKey, bool>(&DataKey::Paused)
+            .unwrap_or(false)
+        {
+            panic!("Contract is paused");
+        }
+        Self::extend_instance_ttl(e);
+    }
+
+    /// Value of `token_id`, panicking if it does not exist or has expired.
+    fn live_value(e: &Env, token_id: u128) -> u128 {
+        let value: u128 = e
+            .storage()
+            .persistent()
+            .get(&DataKey::VoucherValue(token_id))
+            .expect("Invalid token_id");
+
+        let expiry_key = DataKey::VoucherExpiry(token_id);
+        if let Some(expires_at) = e.storage().persistent().get::<DataKey, u64>(&expiry_key) {
+            if e.ledger().timestamp() >= expires_at {
+                panic!("Voucher expired");
+            }
+        }
+        value
+    }
+
+    /// Pay `redeemer` `amount` of the voucher's `value`. The voucher is burned
+    /// when `amount` is its whole value; otherwise the redeemer must hold it
+    /// and its value is reduced.
+    fn _redeem(e: &Env, redeemer: Address, token_id: u128, value: u128, amount: u128) {
+        let remaining = value - amount;
+        if remaining == 0 {
+            // Burn first (validates balance), then transfer
+            Self::_burn(e, redeemer.clone(), token_id, 1);
+        } else if Self::balance_of(e, redeemer.clone(), token_id) == 0 {
+            panic!("Insufficient balance");
+        }
+
+        let asset = Self::voucher_asset(e, token_id);
+        let token = Self::asset_token(e, &asset);
+
+        soroban_sdk::token::Client::new(e, &token).transfer(
+            &e.current_contract_address(),
+            &redeemer,
+            &(amount as i128),
+        );
+
+        if remaining == 0 {
+            // Remove voucher entries after successful transfer
+            Self::remove_voucher(e, token_id, &asset);
+        } else {
+            e.storage()
+                .persistent()
+                .set(&DataKey::VoucherValue(token_id), &remaining);
+        }
+        Self::release_liability(e, &token, amount);
+
+        if remaining == 0 {
+            #[allow(deprecated)]
+            e.events()
+                .publish((symbol_short!("Redeem"), redeemer, token_id), value);
+        } else {
+            #[allow(deprecated)]
+            e.events().publish(
+                (symbol_short!("P_Redeem"), redeemer, token_id),
+                (amount, remaining),
+            );
+        }
+    }
+
+    /// Drop the value, expiry and asset tag of a voucher whose balances have
+    /// been burned.
+    fn remove_voucher(e: &Env, token_id: u128, asset: &VoucherAsset) {
+        e.storage()
+            .persistent()
+            .remove(&DataKey::VoucherValue(token_id));
+        e.storage()
+            .persistent()
+            .remove(&DataKey::VoucherExpiry(token_id));
+        // Removing an absent key still costs a write entry.
+        if *asset != VoucherAsset::Tyc {
+            e.storage()
+                .persistent()
+                .remove(&DataKey::VoucherAsset(token_id));
+        }
+    }
+
+    fn liabilities(e: &Env, token: &Address) -> u128 {
+        e.storage()
+            .persistent()
+            .get(&DataKey::Liabilities(token.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Reserve `amount` of `token`, panicking if the contract's balance
+    /// cannot cover every reservation.
+    fn add_liability(e: &Env, token: &Address, amount: u128) {
+        let total = Self::liabilities(e, token)
+            .checked_add(amount)
+            .expect("Liability overflow");
+        let balance =
+            soroban_sdk::token::Client::new(e, token).balance(&e.current_contract_address());
+        if total > balance as u128 {
+            panic!("Insufficient reserves");
+        }
+        e.storage()
+            .persistent()
+            .set(&DataKey::Liabilities(token.clone()), &total);
+    }
+
+    /// Drop a reservation of `amount` of `token`. Saturates because vouchers
+    /// issued before liabilities were tracked were never reserved.
+    fn release_liability(e: &Env, token: &Address, amount: u128) {
+        let total = Self::liabilities(e, token).saturating_sub(amount);
+        e.storage()
+            .persistent()
+            .set(&DataKey::Liabilities(token.clone()), &total);
+    }
+
+    fn voucher_asset(e: &Env, token_id: u128) -> VoucherAsset {
+        e.storage()
+            .persistent()
+            .get(&DataKey::VoucherAsset(token_id))
+            .unwrap_or(VoucherAsset::Tyc)
+    }
+
+    /// Token contract that pays out `asset`.
+    fn asset_token(e: &Env, asset: &VoucherAsset) -> Address {
+        match asset {
+            VoucherAsset::Tyc => e
+                .storage()
+                .instance()
+                .get(&DataKey::TycToken)
+                .expect("Not initialized"),
+            VoucherAsset::Usdc => e
+                .storage()
+                .instance()
+                .get(&DataKey::UsdcToken)
+                .expect("Not initialized"),
+            VoucherAsset::Token(token) => token.clone(),
+        }
+    }
+
     /// Mint `amount` of `token_id` to `to`.
     ///
     /// Optimisations vs. original:
@@ -323,12 +1353,20 @@ impl TycoonRewardSystem {
             .expect("Balance overflow");
 
         e.storage().persistent().set(&key, &new_balance);
+        Self::extend_balance_ttl(e, &to, token_id);
 
         // Only touch OwnedTokenCount on the zero → non-zero transition
         if current_balance == 0 {
             let count_key = DataKey::OwnedTokenCount(to.clone());
             let count: u32 = e.storage().persistent().get(&count_key).unwrap_or(0);
             e.storage().persistent().set(&count_key, &(count + 1));
+
+            let mut holders = Self::holders(e, token_id);
+            holders.push_back(to.clone());
+            e.storage()
+                .persistent()
+                .set(&DataKey::TokenHolders(token_id), &holders);
+            enumeration::add_token_to_owner(e, &to, token_id);
         }
 
         #[allow(deprecated)]
@@ -371,8 +1409,21 @@ impl TycoonRewardSystem {
                     e.storage().persistent().set(&count_key, &updated);
                 }
             }
+
+            let holders_key = DataKey::TokenHolders(token_id);
+            let mut holders = Self::holders(e, token_id);
+            if let Some(index) = holders.first_index_of(&from) {
+                holders.remove(index);
+            }
+            if holders.is_empty() {
+                e.storage().persistent().remove(&holders_key);
+            } else {
+                e.storage().persistent().set(&holders_key, &holders);
+            }
+            enumeration::remove_token_from_owner(e, &from, token_id);
         } else {
             e.storage().persistent().set(&key, &new_balance);
+            Self::extend_balance_ttl(e, &from, token_id);
         }
 
         #[allow(deprecated)]
@@ -380,6 +1431,15 @@ impl TycoonRewardSystem {
             .publish((symbol_short!("Burn"), from, token_id), amount);
     }
 
+    /// Addresses with a non-zero balance of `token_id`, in the order they
+    /// first received it.
+    fn holders(e: &Env, token_id: u128) -> Vec<Address> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::TokenHolders(token_id))
+            .unwrap_or_else(|| Vec::new(e))
+    }
+
     fn balance_of(e: &Env, owner: Address, token_id: u128) -> u64 {
         e.storage()
             .persistent()
@@ -405,12 +1465,39 @@ impl TycoonRewardSystem {
 #[cfg(test)]
 mod test;
 
+#[cfg(test)]
+mod test_support;
+
 #[cfg(test)]
 mod overflow_rounding_tests;
 
 mod admin_access_control_tests;
 #[cfg(test)]
+mod asset_voucher_tests;
+#[cfg(test)]
+mod batch_mint_tests;
+#[cfg(test)]
+mod claim_campaign_tests;
+#[cfg(test)]
+mod enumeration_tests;
+#[cfg(test)]
+mod mint_limit_tests;
+#[cfg(test)]
+mod operator_tests;
+#[cfg(test)]
 mod transfer_tests;
 
 #[cfg(test)]
 mod simulation_scenarios;
+
+#[cfg(test)]
+mod solvency_tests;
+
+#[cfg(test)]
+mod storage_layout_tests;
+
+#[cfg(test)]
+mod streak_tests;
+
+#[cfg(test)]
+mod voucher_expiry_tests;
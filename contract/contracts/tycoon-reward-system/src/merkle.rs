@@ -0,0 +1,110 @@
+//! sha256 Merkle trees for claim campaigns.
+//!
+//! The contract only ever calls [`verify`]; [`root`] and [`proof`] are the
+//! reference builder used by the tests and the backend, so roots are generated
+//! the same way everywhere.
+//!
+//! Encoding:
+//! - leaf = `sha256(0x00 || address XDR || amount as 16 big-endian bytes)`
+//! - node = `sha256(0x01 || left || right)`
+//! - a level with an odd number of nodes pairs its last node with itself.
+//!
+//! The prefixes keep a leaf from being passed off as an inner node. A proof
+//! lists sibling hashes from the leaf upwards; bit `i` of the leaf index says
+//! whether the node at level `i` is a right child. Because of the odd-level
+//! duplication a proof for the last leaf also verifies at `index + 1`, so
+//! callers must reject indices at or beyond the leaf count.
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec};
+
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+
+/// Hash of the `(account, amount)` leaf.
+pub fn leaf_hash(e: &Env, account: &Address, amount: u128) -> BytesN<32> {
+    let mut data = Bytes::from_array(e, &[LEAF_PREFIX]);
+    data.append(&account.clone().to_xdr(e));
+    data.extend_from_array(&amount.to_be_bytes());
+    e.crypto().sha256(&data).to_bytes()
+}
+
+fn node_hash(e: &Env, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32> {
+    let mut data = Bytes::from_array(e, &[NODE_PREFIX]);
+    data.extend_from_array(&left.to_array());
+    data.extend_from_array(&right.to_array());
+    e.crypto().sha256(&data).to_bytes()
+}
+
+/// True if `leaf` sits at `index` in the tree with `root`.
+pub fn verify(
+    e: &Env,
+    root: &BytesN<32>,
+    index: u32,
+    leaf: BytesN<32>,
+    proof: &Vec<BytesN<32>>,
+) -> bool {
+    if proof.len() > 32 {
+        return false;
+    }
+    let mut node = leaf;
+    let mut position = index;
+    for sibling in proof.iter() {
+        node = if position & 1 == 0 {
+            node_hash(e, &node, &sibling)
+        } else {
+            node_hash(e, &sibling, &node)
+        };
+        position >>= 1;
+    }
+    position == 0 && node == *root
+}
+
+/// The level above `level`.
+fn parent_level(e: &Env, level: &Vec<BytesN<32>>) -> Vec<BytesN<32>> {
+    let mut parents = Vec::new(e);
+    let mut i = 0;
+    while i < level.len() {
+        let left = level.get_unchecked(i);
+        let right = level.get(i + 1).unwrap_or_else(|| left.clone());
+        parents.push_back(node_hash(e, &left, &right));
+        i += 2;
+    }
+    parents
+}
+
+/// Root of the tree over `leaves` (already hashed with [`leaf_hash`]).
+///
+/// # Panics
+/// If `leaves` is empty.
+pub fn root(e: &Env, leaves: &Vec<BytesN<32>>) -> BytesN<32> {
+    if leaves.is_empty() {
+        panic!("No leaves");
+    }
+    let mut level = leaves.clone();
+    while level.len() > 1 {
+        level = parent_level(e, &level);
+    }
+    level.get_unchecked(0)
+}
+
+/// Proof for the leaf at `index`, suitable for [`verify`].
+///
+/// # Panics
+/// If `index` is out of range.
+pub fn proof(e: &Env, leaves: &Vec<BytesN<32>>, index: u32) -> Vec<BytesN<32>> {
+    if index >= leaves.len() {
+        panic!("Leaf index out of range");
+    }
+    let mut siblings = Vec::new(e);
+    let mut level = leaves.clone();
+    let mut position = index;
+    while level.len() > 1 {
+        let sibling = level
+            .get(position ^ 1)
+            .unwrap_or_else(|| level.get_unchecked(position));
+        siblings.push_back(sibling);
+        level = parent_level(e, &level);
+        position >>= 1;
+    }
+    siblings
+}
@@ -0,0 +1,129 @@
+//! Rate limit and circuit breaker for the backend minter.
+//!
+//! The admin may cap how many vouchers, and how much voucher value, the
+//! backend minter can mint per window of `period_ledgers` ledgers. As with
+//! the TYC token's minter quotas, a window opens on the first mint after the
+//! previous one has elapsed, and unused budget never carries over. Value is
+//! the raw voucher amount, summed across assets. The referral minter is
+//! charged against the same budget.
+//!
+//! A minter's mint that would exceed either cap trips the breaker instead of
+//! panicking: the contract pauses and the mint is skipped. Panicking would
+//! roll the pause back with the rest of the call, so contract callers such as
+//! tycoon-game's referral claims must not panic on a refused mint either.
+//! The admin is never limited.
+//!
+//! Daily streak claims draw on the same budget. A claim over the limit panics
+//! instead of tripping the breaker, so players cannot pause the contract.
+use crate::DataKey;
+use soroban_sdk::{contracttype, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintLimit {
+    /// Maximum vouchers per window.
+    pub max_count: u32,
+    /// Maximum total voucher value per window.
+    pub max_value: u128,
+    /// Window length in ledgers.
+    pub period_ledgers: u32,
+    /// Ledger the current window opened at.
+    pub window_start: u32,
+    /// Vouchers minted in the current window.
+    pub count: u32,
+    /// Voucher value minted in the current window.
+    pub value: u128,
+}
+
+/// What the backend minter may still mint in the current window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintBudget {
+    pub count: u32,
+    pub value: u128,
+}
+
+impl MintLimit {
+    /// True once `ledger` is past the current window.
+    fn window_elapsed(&self, ledger: u32) -> bool {
+        ledger >= self.window_start.saturating_add(self.period_ledgers)
+    }
+
+    /// Budget left at `ledger`, accounting for a window that has elapsed.
+    pub(crate) fn remaining_at(&self, ledger: u32) -> MintBudget {
+        if self.window_elapsed(ledger) {
+            MintBudget {
+                count: self.max_count,
+                value: self.max_value,
+            }
+        } else {
+            MintBudget {
+                count: self.max_count - self.count,
+                value: self.max_value - self.value,
+            }
+        }
+    }
+}
+
+pub(crate) fn read(e: &Env) -> Option<MintLimit> {
+    e.storage().instance().get(&DataKey::MintLimit)
+}
+
+/// Set or update the limit. Usage in the open window is kept so an update
+/// cannot be used to reset a spent budget.
+pub(crate) fn set(e: &Env, max_count: u32, max_value: u128, period_ledgers: u32) -> MintLimit {
+    if max_count == 0 || max_value == 0 {
+        panic!("Limit must be positive");
+    }
+    if period_ledgers == 0 {
+        panic!("Period must be positive");
+    }
+    let ledger = e.ledger().sequence();
+    let (window_start, count, value) = match read(e) {
+        Some(prev) if !prev.window_elapsed(ledger) => (prev.window_start, prev.count, prev.value),
+        _ => (ledger, 0, 0),
+    };
+    let limit = MintLimit {
+        max_count,
+        max_value,
+        period_ledgers,
+        window_start,
+        // A lowered cap leaves no budget rather than underflowing.
+        count: count.min(max_count),
+        value: value.min(max_value),
+    };
+    e.storage().instance().set(&DataKey::MintLimit, &limit);
+    limit
+}
+
+pub(crate) fn clear(e: &Env) {
+    e.storage().instance().remove(&DataKey::MintLimit);
+}
+
+/// Charge `count` vouchers worth `value` against the limit, opening a new
+/// window if the previous one has elapsed. Returns `false`, leaving usage
+/// unchanged, if the mint would exceed the limit. Always `true` when no
+/// limit is set.
+pub(crate) fn consume(e: &Env, count: u32, value: u128) -> bool {
+    let mut limit = match read(e) {
+        Some(limit) => limit,
+        None => return true,
+    };
+    let ledger = e.ledger().sequence();
+    if limit.window_elapsed(ledger) {
+        limit.window_start = ledger;
+        limit.count = 0;
+        limit.value = 0;
+    }
+    let new_count = limit.count.checked_add(count);
+    let new_value = limit.value.checked_add(value);
+    match (new_count, new_value) {
+        (Some(c), Some(v)) if c <= limit.max_count && v <= limit.max_value => {
+            limit.count = c;
+            limit.value = v;
+            e.storage().instance().set(&DataKey::MintLimit, &limit);
+            true
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,322 @@
+/// # Backend minter rate limit
+///
+/// Covers the `mint_limit` module, `set_mint_limit` / `clear_mint_limit`, the
+/// circuit breaker in `require_minter` and the budget views.
+///
+/// | ID    | What it pins |
+/// |-------|--------------|
+/// | ML-01 | Backend mints within the limit succeed and use up the budget |
+/// | ML-02 | Exceeding the count cap trips the breaker: paused, nothing minted |
+/// | ML-03 | Exceeding the value cap trips the breaker; batches are charged as a whole |
+/// | ML-04 | The admin is never limited, even while the breaker is tripped |
+/// | ML-05 | A new window restores the budget; updates keep window usage |
+/// | ML-06 | Limit setup is admin-only and validated; clearing lifts the limit |
+/// | ML-07 | All backend mint entrypoints are charged; no limit means no budget view |
+/// | ML-08 | `Breaker` event |
+/// | ML-09 | The referral minter mints alongside the backend minter and shares its budget |
+extern crate std;
+
+use crate::mint_limit::MintBudget;
+use crate::test_support::{deref_setup, Setup};
+use crate::{DataKey, VoucherAsset};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{symbol_short, vec, Address, IntoVal, Symbol};
+
+const PERIOD: u32 = 100;
+
+// ── Harness ───────────────────────────────────────────────────────────────────
+
+struct H<'a> {
+    base: Setup<'a>,
+    backend: Address,
+    user: Address,
+}
+deref_setup!(H);
+
+impl H<'_> {
+    /// Funded contract whose backend minter may mint 3 vouchers worth 1 000
+    /// per `PERIOD` ledgers.
+    fn new() -> Self {
+        let base = Setup::new();
+        base.fund_contract(&base.tyc, 1_000_000);
+        base.fund_contract(&base.usdc, 1_000_000);
+        let backend = Address::generate(&base.env);
+        base.client.set_backend_minter(&backend);
+        base.client.set_mint_limit(&3, &1_000, &PERIOD);
+        H {
+            user: Address::generate(&base.env),
+            base,
+            backend,
+        }
+    }
+
+    fn mint(&self, value: u128) -> u128 {
+        self.client.mint_voucher(&self.backend, &self.user, &value)
+    }
+
+    fn advance(&self, ledgers: u32) {
+        self.env
+            .ledger()
+            .with_mut(|li| li.sequence_number += ledgers);
+    }
+
+    fn paused(&self) -> bool {
+        self.env.as_contract(&self.client.address, || {
+            self.env.storage().instance().get(&DataKey::Paused).unwrap()
+        })
+    }
+}
+
+// ── ML-01 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn ml_01_mints_within_limit_use_budget() {
+    let h = H::new();
+    let id = h.mint(400);
+    assert_eq!(h.client.get_balance(&h.user, &id), 1);
+    assert_eq!(
+        h.client.remaining_mint_budget(),
+        Some(MintBudget {
+            count: 2,
+            value: 600
+        })
+    );
+
+    h.mint(300);
+    h.mint(300);
+    assert_eq!(
+        h.client.remaining_mint_budget(),
+        Some(MintBudget { count: 0, value: 0 })
+    );
+    assert!(!h.paused());
+}
+
+// ── ML-02 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn ml_02_count_cap_trips_breaker() {
+    let h = H::new();
+    for _ in 0..3 {
+        h.mint(1);
+    }
+    assert_eq!(h.mint(1), 0);
+
+    assert_eq!(h.client.owned_token_count(&h.user), 3);
+    assert_eq!(h.client.get_mint_limit().unwrap().count, 3);
+    assert!(h.paused());
+}
+
+#[test]
+#[should_panic(expected = "Contract is paused")]
+fn ml_02_backend_blocked_after_trip() {
+    let h = H::new();
+    assert_eq!(h.mint(1_001), 0);
+    h.mint(1);
+}
+
+// ── ML-03 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn ml_03_value_cap_trips_breaker() {
+    let h = H::new();
+    h.mint(900);
+    assert_eq!(h.mint(101), 0);
+    assert_eq!(h.client.owned_token_count(&h.user), 1);
+    assert_eq!(h.client.get_mint_limit().unwrap().value, 900);
+}
+
+#[test]
+fn ml_03_batch_charged_as_a_whole() {
+    let h = H::new();
+    let other = Address::generate(&h.env);
+    let ids = h.client.mint_vouchers_batch(
+        &h.backend,
+        &vec![
+            &h.env,
+            (h.user.clone(), 100),
+            (other.clone(), 100),
+            (other.clone(), 100),
+            (h.user.clone(), 100),
+        ],
+    );
+    assert!(ids.is_empty());
+    assert_eq!(h.client.owned_token_count(&h.user), 0);
+    assert_eq!(
+        h.client.remaining_mint_budget(),
+        Some(MintBudget {
+            count: 3,
+            value: 1_000
+        })
+    );
+}
+
+// ── ML-04 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn ml_04_admin_not_limited() {
+    let h = H::new();
+    assert_eq!(h.mint(5_000), 0);
+
+    let id = h.client.mint_voucher(&h.admin, &h.user, &5_000);
+    assert_eq!(h.client.voucher_info(&id).unwrap().value, 5_000);
+    assert_eq!(h.client.get_mint_limit().unwrap().value, 0);
+
+    h.client.unpause();
+    assert_eq!(h.client.get_balance(&h.user, &h.mint(10)), 1);
+}
+
+// ── ML-05 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn ml_05_new_window_restores_budget() {
+    let h = H::new();
+    h.mint(1_000);
+    h.advance(PERIOD - 1);
+    assert_eq!(
+        h.client.remaining_mint_budget(),
+        Some(MintBudget { count: 2, value: 0 })
+    );
+
+    h.advance(1);
+    assert_eq!(
+        h.client.remaining_mint_budget(),
+        Some(MintBudget {
+            count: 3,
+            value: 1_000
+        })
+    );
+    h.mint(1_000);
+    assert_eq!(h.client.get_mint_limit().unwrap().count, 1);
+}
+
+#[test]
+fn ml_05_update_keeps_window_usage() {
+    let h = H::new();
+    h.mint(600);
+    h.client.set_mint_limit(&5, &2_000, &PERIOD);
+    assert_eq!(
+        h.client.remaining_mint_budget(),
+        Some(MintBudget {
+            count: 4,
+            value: 1_400
+        })
+    );
+
+    h.client.set_mint_limit(&5, &500, &PERIOD);
+    assert_eq!(h.client.remaining_mint_budget().unwrap().value, 0);
+}
+
+// ── ML-06 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn ml_06_limit_validated() {
+    let h = H::new();
+    assert!(h.client.try_set_mint_limit(&0, &1, &1).is_err());
+    assert!(h.client.try_set_mint_limit(&1, &0, &1).is_err());
+    assert!(h.client.try_set_mint_limit(&1, &1, &0).is_err());
+    assert_eq!(h.client.get_mint_limit().unwrap().max_count, 3);
+}
+
+#[test]
+#[should_panic]
+fn ml_06_limit_is_admin_only() {
+    let h = H::new();
+    h.env.mock_auths(&[]);
+    h.client.set_mint_limit(&100, &100, &1);
+}
+
+#[test]
+fn ml_06_clear_lifts_limit() {
+    let h = H::new();
+    h.client.clear_mint_limit();
+    assert_eq!(h.client.get_mint_limit(), None);
+    for _ in 0..5 {
+        h.mint(1_000);
+    }
+    assert_eq!(h.client.owned_token_count(&h.user), 5);
+}
+
+// ── ML-07 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn ml_07_every_backend_entrypoint_is_charged() {
+    let h = H::new();
+    h.client
+        .mint_asset_voucher(&h.backend, &h.user, &VoucherAsset::Usdc, &100);
+    h.client
+        .mint_voucher_with_expiry(&h.backend, &h.user, &100, &1_000);
+    h.client
+        .mint_vouchers_batch(&h.backend, &vec![&h.env, (h.user.clone(), 100)]);
+    assert_eq!(
+        h.client.remaining_mint_budget(),
+        Some(MintBudget {
+            count: 0,
+            value: 700
+        })
+    );
+    assert_eq!(
+        h.client
+            .mint_asset_voucher(&h.backend, &h.user, &VoucherAsset::Usdc, &1),
+        0
+    );
+}
+
+#[test]
+fn ml_07_no_limit_no_budget() {
+    let h = H::new();
+    h.client.clear_mint_limit();
+    assert_eq!(h.client.remaining_mint_budget(), None);
+}
+
+// ── ML-08 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn ml_08_breaker_event() {
+    let h = H::new();
+    h.mint(2_000);
+    let (_, topics, data) = h.env.events().all().last().unwrap();
+    let name: Symbol = topics.get(0).unwrap().into_val(&h.env);
+    let data: (u32, u128) = data.into_val(&h.env);
+    assert_eq!(name, symbol_short!("Breaker"));
+    assert_eq!(data, (1, 2_000));
+}
+
+// ── ML-09 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn ml_09_referral_minter_shares_budget() {
+    let h = H::new();
+    let game = Address::generate(&h.env);
+    h.client.set_referral_minter(&game);
+    assert_eq!(h.client.get_referral_minter(), Some(game.clone()));
+
+    let id = h.client.mint_voucher(&game, &h.user, &400);
+    assert_eq!(h.client.get_balance(&h.user, &id), 1);
+    h.mint(400);
+    assert_eq!(
+        h.client.remaining_mint_budget(),
+        Some(MintBudget {
+            count: 1,
+            value: 200
+        })
+    );
+    assert_eq!(h.client.mint_voucher(&game, &h.user, &400), 0);
+    assert!(h.paused());
+}
+
+#[test]
+fn ml_09_referral_minter_is_admin_set_and_clearable() {
+    let h = H::new();
+    let game = Address::generate(&h.env);
+    assert!(h.client.try_mint_voucher(&game, &h.user, &1).is_err());
+
+    h.client.set_referral_minter(&game);
+    h.client.clear_referral_minter();
+    assert_eq!(h.client.get_referral_minter(), None);
+    assert!(h.client.try_mint_voucher(&game, &h.user, &1).is_err());
+    // The backend minter is unaffected.
+    h.mint(1);
+
+    h.env.mock_auths(&[]);
+    assert!(h.client.try_set_referral_minter(&game).is_err());
+}
@@ -0,0 +1,245 @@
+/// # Operator approvals
+///
+/// Covers `set_approval_for_all`, `is_approved_for_all` and `transfer_from`.
+///
+/// | ID    | What it pins |
+/// |-------|--------------|
+/// | OP-01 | An approved operator moves any of the owner's vouchers |
+/// | OP-02 | Unapproved and revoked operators are rejected |
+/// | OP-03 | Approvals lapse at their expiry; past expiries are rejected |
+/// | OP-04 | The owner may call `transfer_from` for themselves |
+/// | OP-05 | Approvals are per owner and need the owner's auth |
+/// | OP-06 | Approving and `transfer_from` are blocked while paused |
+/// | OP-07 | `Approval` and `Transfer` events |
+extern crate std;
+
+use crate::test_support::{deref_setup, Setup};
+use soroban_sdk::testutils::{
+    Address as _, AuthorizedFunction, AuthorizedInvocation, Events, Ledger,
+};
+use soroban_sdk::{symbol_short, Address, IntoVal, Symbol};
+
+const NOW: u64 = 1_700_000_000;
+const DAY: u64 = 86_400;
+
+// ── Harness ───────────────────────────────────────────────────────────────────
+
+struct H<'a> {
+    base: Setup<'a>,
+    owner: Address,
+    operator: Address,
+}
+deref_setup!(H);
+
+impl H<'_> {
+    /// Funded contract, an owner and an operator. Ledger clock at `NOW`.
+    fn new() -> Self {
+        let base = Setup::new();
+        base.env.ledger().set_timestamp(NOW);
+        base.fund_contract(&base.tyc, 1_000);
+        H {
+            owner: Address::generate(&base.env),
+            operator: Address::generate(&base.env),
+            base,
+        }
+    }
+
+    fn mint(&self) -> u128 {
+        self.client.mint_voucher(&self.admin, &self.owner, &100)
+    }
+}
+
+// ── OP-01 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn op_01_approved_operator_transfers() {
+    let h = H::new();
+    let first = h.mint();
+    let second = h.mint();
+    let buyer = Address::generate(&h.env);
+
+    h.client
+        .set_approval_for_all(&h.owner, &h.operator, &true, &None);
+    assert!(h.client.is_approved_for_all(&h.owner, &h.operator));
+
+    h.client
+        .transfer_from(&h.operator, &h.owner, &buyer, &first, &1);
+    h.client
+        .transfer_from(&h.operator, &h.owner, &buyer, &second, &1);
+
+    assert_eq!(h.client.get_balance(&h.owner, &first), 0);
+    assert_eq!(h.client.get_balance(&buyer, &first), 1);
+    assert_eq!(h.client.get_balance(&buyer, &second), 1);
+    assert_eq!(h.client.owned_token_count(&buyer), 2);
+}
+
+// ── OP-02 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic(expected = "Not approved")]
+fn op_02_unapproved_operator_rejected() {
+    let h = H::new();
+    let id = h.mint();
+    h.client
+        .transfer_from(&h.operator, &h.owner, &h.operator, &id, &1);
+}
+
+#[test]
+fn op_02_revoked_operator_rejected() {
+    let h = H::new();
+    let id = h.mint();
+    h.client
+        .set_approval_for_all(&h.owner, &h.operator, &true, &None);
+    h.client
+        .set_approval_for_all(&h.owner, &h.operator, &false, &None);
+
+    assert!(!h.client.is_approved_for_all(&h.owner, &h.operator));
+    assert!(h
+        .client
+        .try_transfer_from(&h.operator, &h.owner, &h.operator, &id, &1)
+        .is_err());
+    assert_eq!(h.client.get_balance(&h.owner, &id), 1);
+}
+
+// ── OP-03 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn op_03_approval_lapses_at_expiry() {
+    let h = H::new();
+    let id = h.mint();
+    h.client
+        .set_approval_for_all(&h.owner, &h.operator, &true, &Some(NOW + DAY));
+
+    h.env.ledger().set_timestamp(NOW + DAY - 1);
+    assert!(h.client.is_approved_for_all(&h.owner, &h.operator));
+
+    h.env.ledger().set_timestamp(NOW + DAY);
+    assert!(!h.client.is_approved_for_all(&h.owner, &h.operator));
+    assert!(h
+        .client
+        .try_transfer_from(&h.operator, &h.owner, &h.operator, &id, &1)
+        .is_err());
+}
+
+#[test]
+#[should_panic(expected = "Expiry must be in the future")]
+fn op_03_past_expiry_rejected() {
+    let h = H::new();
+    h.client
+        .set_approval_for_all(&h.owner, &h.operator, &true, &Some(NOW));
+}
+
+// ── OP-04 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn op_04_owner_can_transfer_from_self() {
+    let h = H::new();
+    let id = h.mint();
+    let friend = Address::generate(&h.env);
+    h.client.transfer_from(&h.owner, &h.owner, &friend, &id, &1);
+    assert_eq!(h.client.get_balance(&friend, &id), 1);
+}
+
+// ── OP-05 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn op_05_approval_is_per_owner() {
+    let h = H::new();
+    let other = Address::generate(&h.env);
+    let others_id = h.client.mint_voucher(&h.admin, &other, &100);
+    h.client
+        .set_approval_for_all(&h.owner, &h.operator, &true, &None);
+
+    assert!(!h.client.is_approved_for_all(&other, &h.operator));
+    assert!(h
+        .client
+        .try_transfer_from(&h.operator, &other, &h.operator, &others_id, &1)
+        .is_err());
+}
+
+#[test]
+fn op_05_approval_requires_owner_auth() {
+    let h = H::new();
+    h.client
+        .set_approval_for_all(&h.owner, &h.operator, &true, &None);
+
+    assert_eq!(
+        h.env.auths(),
+        std::vec![(
+            h.owner.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    h.client.address.clone(),
+                    Symbol::new(&h.env, "set_approval_for_all"),
+                    (
+                        h.owner.clone(),
+                        h.operator.clone(),
+                        true,
+                        Option::<u64>::None
+                    )
+                        .into_val(&h.env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Cannot approve self")]
+fn op_05_self_approval_rejected() {
+    let h = H::new();
+    h.client
+        .set_approval_for_all(&h.owner, &h.owner, &true, &None);
+}
+
+// ── OP-06 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn op_06_blocked_while_paused() {
+    let h = H::new();
+    let id = h.mint();
+    h.client
+        .set_approval_for_all(&h.owner, &h.operator, &true, &None);
+    h.client.pause();
+
+    assert!(h
+        .client
+        .try_set_approval_for_all(&h.owner, &Address::generate(&h.env), &true, &None)
+        .is_err());
+    assert!(h
+        .client
+        .try_transfer_from(&h.operator, &h.owner, &h.operator, &id, &1)
+        .is_err());
+
+    h.client.unpause();
+    h.client
+        .transfer_from(&h.operator, &h.owner, &h.operator, &id, &1);
+    assert_eq!(h.client.get_balance(&h.operator, &id), 1);
+}
+
+// ── OP-07 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn op_07_events() {
+    let h = H::new();
+    let id = h.mint();
+
+    h.client
+        .set_approval_for_all(&h.owner, &h.operator, &true, &Some(NOW + DAY));
+    let (_, topics, data) = h.env.events().all().last().unwrap();
+    let name: Symbol = topics.get(0).unwrap().into_val(&h.env);
+    let data: (bool, Option<u64>) = data.into_val(&h.env);
+    assert_eq!(name, symbol_short!("Approval"));
+    assert_eq!(data, (true, Some(NOW + DAY)));
+
+    let buyer = Address::generate(&h.env);
+    h.client
+        .transfer_from(&h.operator, &h.owner, &buyer, &id, &1);
+    let (_, topics, _) = h.env.events().all().last().unwrap();
+    let name: Symbol = topics.get(0).unwrap().into_val(&h.env);
+    let from: Address = topics.get(1).unwrap().into_val(&h.env);
+    let to: Address = topics.get(2).unwrap().into_val(&h.env);
+    assert_eq!(name, symbol_short!("Transfer"));
+    assert_eq!((from, to), (h.owner.clone(), buyer));
+}
@@ -22,6 +22,7 @@
 ///   - Large value vouchers (near `u128::MAX / 2` to stay within `i128` cast)
 ///   - Multiple vouchers per user — cumulative redemption correctness
 ///   - `owned_token_count` stays consistent across all edge cases
+///   - Partial redemption and splitting never create or lose value
 ///
 /// ## Tier Definitions (aligned with backend accounting)
 ///
@@ -38,8 +39,8 @@
 extern crate std;
 
 use crate::{TycoonRewardSystem, TycoonRewardSystemClient};
-use soroban_sdk::testutils::Address as TestAddress;
-use soroban_sdk::{token, Address, Env};
+use soroban_sdk::testutils::{Address as TestAddress, Events, Ledger};
+use soroban_sdk::{symbol_short, token, vec, Address, Env, IntoVal, Symbol, Vec};
 
 // ── Tier constants (backend-aligned) ─────────────────────────────────────────
 
@@ -237,6 +238,7 @@ fn test_overflow_large_voucher_value_exact_transfer() {
 fn test_overflow_voucher_count_increments_monotonically() {
     let h = Harness::new();
     let user = Address::generate(&h.env);
+    h.fund_contract((3 * TIER_BRONZE) as i128);
 
     let first_id = h.client.mint_voucher(&h.admin, &user, &TIER_BRONZE);
     let second_id = h.client.mint_voucher(&h.admin, &user, &TIER_BRONZE);
@@ -511,24 +513,17 @@ fn test_accrual_independent_user_redemptions() {
     }
 }
 
-/// Accrual: redeeming when contract has insufficient TYC panics.
-/// This guards against under-funded contract states.
+/// Accrual: minting a voucher the contract cannot cover panics, so a
+/// redeem can never find the contract under-funded.
 #[test]
-fn test_accrual_redeem_fails_when_contract_underfunded() {
+#[should_panic(expected = "Insufficient reserves")]
+fn test_accrual_mint_fails_when_contract_underfunded() {
     let h = Harness::new();
     let user = Address::generate(&h.env);
 
     // Fund with less than the voucher value
     h.fund_contract((TIER_GOLD - 1) as i128);
-    let token_id = h.client.mint_voucher(&h.admin, &user, &TIER_GOLD);
-
-    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        h.client.redeem_voucher_from(&user, &token_id);
-    }));
-    assert!(
-        res.is_err(),
-        "redeem should fail when contract is underfunded"
-    );
+    h.client.mint_voucher(&h.admin, &user, &TIER_GOLD);
 }
 
 /// Accrual: minting a voucher with value=0 is stored and redeems 0 TYC.
@@ -547,6 +542,249 @@ fn test_accrual_zero_value_voucher_redeems_zero() {
     assert_eq!(h.client.get_balance(&user, &token_id), 0);
 }
 
+// ── Partial redemption & splitting ───────────────────────────────────────────
+
+/// Partial: redeeming part of a voucher pays exactly that amount and leaves
+/// the remainder on the same id, still reserved.
+#[test]
+fn test_partial_redeem_pays_amount_and_keeps_remainder() {
+    let h = Harness::new();
+    let user = Address::generate(&h.env);
+    h.fund_contract(TIER_GOLD as i128);
+    let tid = h.client.mint_voucher(&h.admin, &user, &TIER_GOLD);
+
+    h.client.redeem_partial(&user, &tid, &TIER_BRONZE);
+
+    assert_eq!(h.tyc_balance_of(&user), TIER_BRONZE as i128);
+    assert_eq!(h.client.get_balance(&user, &tid), 1);
+    assert_eq!(
+        h.client.voucher_info(&tid).unwrap().value,
+        TIER_GOLD - TIER_BRONZE
+    );
+    assert_eq!(
+        h.client.outstanding_liabilities(&h.tyc_token_id),
+        TIER_GOLD - TIER_BRONZE
+    );
+}
+
+/// Partial: a sequence of partial redemptions down to the last raw unit pays
+/// out the full value and burns the voucher on the final call.
+#[test]
+fn test_partial_redeem_sequence_sums_to_value() {
+    let h = Harness::new();
+    let user = Address::generate(&h.env);
+    h.fund_contract(TIER_SILVER as i128);
+    let tid = h.client.mint_voucher(&h.admin, &user, &TIER_SILVER);
+
+    h.client.redeem_partial(&user, &tid, &TIER_MINIMUM);
+    h.client.redeem_partial(&user, &tid, &TIER_BRONZE);
+    h.client
+        .redeem_partial(&user, &tid, &(TIER_SILVER - TIER_BRONZE - TIER_MINIMUM));
+
+    assert_eq!(h.tyc_balance_of(&user), TIER_SILVER as i128);
+    assert_eq!(h.tyc_balance_of(&h.contract_id), 0);
+    assert_eq!(h.client.get_balance(&user, &tid), 0);
+    assert_eq!(h.client.owned_token_count(&user), 0);
+    assert_eq!(h.client.voucher_info(&tid), None);
+    assert_eq!(h.client.outstanding_liabilities(&h.tyc_token_id), 0);
+}
+
+/// Partial: emits `P_Redeem` with the amount paid and the value left.
+#[test]
+fn test_partial_redeem_emits_event() {
+    let h = Harness::new();
+    let user = Address::generate(&h.env);
+    h.fund_contract(TIER_GOLD as i128);
+    let tid = h.client.mint_voucher(&h.admin, &user, &TIER_GOLD);
+
+    h.client.redeem_partial(&user, &tid, &TIER_SILVER);
+
+    let (_, topics, data) = h.env.events().all().last().unwrap();
+    let name: Symbol = topics.get(0).unwrap().into_val(&h.env);
+    let data: (u128, u128) = data.into_val(&h.env);
+    assert_eq!(name, symbol_short!("P_Redeem"));
+    assert_eq!(data, (TIER_SILVER, TIER_GOLD - TIER_SILVER));
+}
+
+/// Partial: amounts above the remaining value are rejected.
+#[test]
+#[should_panic(expected = "Amount exceeds voucher value")]
+fn test_partial_redeem_exceeding_value_rejected() {
+    let h = Harness::new();
+    let user = Address::generate(&h.env);
+    h.fund_contract(TIER_GOLD as i128);
+    let tid = h.client.mint_voucher(&h.admin, &user, &TIER_GOLD);
+
+    h.client.redeem_partial(&user, &tid, &TIER_SILVER);
+    h.client.redeem_partial(&user, &tid, &(TIER_SILVER + 1));
+}
+
+/// Partial: a zero amount is rejected rather than treated as a no-op.
+#[test]
+#[should_panic(expected = "Amount must be positive")]
+fn test_partial_redeem_zero_rejected() {
+    let h = Harness::new();
+    let user = Address::generate(&h.env);
+    h.fund_contract(TIER_GOLD as i128);
+    let tid = h.client.mint_voucher(&h.admin, &user, &TIER_GOLD);
+    h.client.redeem_partial(&user, &tid, &0);
+}
+
+/// Partial: only a holder of the voucher can redeem part of it.
+#[test]
+#[should_panic(expected = "Insufficient balance")]
+fn test_partial_redeem_by_non_holder_rejected() {
+    let h = Harness::new();
+    let user = Address::generate(&h.env);
+    h.fund_contract(TIER_GOLD as i128);
+    let tid = h.client.mint_voucher(&h.admin, &user, &TIER_GOLD);
+    h.client
+        .redeem_partial(&Address::generate(&h.env), &tid, &TIER_BRONZE);
+}
+
+/// Split: new vouchers carry the requested values, the original is gone and
+/// outstanding value is unchanged.
+#[test]
+fn test_split_values_sum_to_original() {
+    let h = Harness::new();
+    let user = Address::generate(&h.env);
+    h.fund_contract(TIER_PLATINUM as i128);
+    let tid = h.client.mint_voucher(&h.admin, &user, &TIER_PLATINUM);
+
+    let parts = vec![
+        &h.env,
+        TIER_BRONZE,
+        TIER_GOLD,
+        TIER_PLATINUM - TIER_BRONZE - TIER_GOLD,
+    ];
+    let ids = h.client.split_voucher(&user, &tid, &parts);
+
+    assert_eq!(ids.len(), 3);
+    assert_eq!(h.client.get_balance(&user, &tid), 0);
+    assert_eq!(h.client.voucher_info(&tid), None);
+    assert_eq!(h.client.owned_token_count(&user), 3);
+    let mut total = 0;
+    for (id, part) in ids.iter().zip(parts.iter()) {
+        assert!(id > tid);
+        assert_eq!(h.client.get_balance(&user, &id), 1);
+        assert_eq!(h.client.voucher_info(&id).unwrap().value, part);
+        total += part;
+    }
+    assert_eq!(total, TIER_PLATINUM);
+    assert_eq!(
+        h.client.outstanding_liabilities(&h.tyc_token_id),
+        TIER_PLATINUM
+    );
+}
+
+/// Split: gifting one part and redeeming both pays out the original value.
+#[test]
+fn test_split_then_gift_redeems_full_value() {
+    let h = Harness::new();
+    let user = Address::generate(&h.env);
+    let friend = Address::generate(&h.env);
+    h.fund_contract(TIER_GOLD as i128);
+    let tid = h.client.mint_voucher(&h.admin, &user, &TIER_GOLD);
+
+    let ids = h
+        .client
+        .split_voucher(&user, &tid, &vec![&h.env, TIER_SILVER - 1, TIER_SILVER + 1]);
+    h.client.transfer(&user, &friend, &ids.get(1).unwrap(), &1);
+    h.client.redeem_voucher_from(&user, &ids.get(0).unwrap());
+    h.client.redeem_voucher_from(&friend, &ids.get(1).unwrap());
+
+    assert_eq!(h.tyc_balance_of(&user), (TIER_SILVER - 1) as i128);
+    assert_eq!(h.tyc_balance_of(&friend), (TIER_SILVER + 1) as i128);
+    assert_eq!(h.tyc_balance_of(&h.contract_id), 0);
+    assert_eq!(h.client.outstanding_liabilities(&h.tyc_token_id), 0);
+}
+
+/// Split: parts inherit the original expiry.
+#[test]
+fn test_split_preserves_expiry() {
+    let h = Harness::new();
+    let user = Address::generate(&h.env);
+    h.env.ledger().set_timestamp(1_000);
+    h.fund_contract(TIER_GOLD as i128);
+    let tid = h
+        .client
+        .mint_voucher_with_expiry(&h.admin, &user, &TIER_GOLD, &2_000);
+
+    let ids = h
+        .client
+        .split_voucher(&user, &tid, &vec![&h.env, TIER_SILVER, TIER_SILVER]);
+    for id in ids.iter() {
+        assert_eq!(h.client.voucher_info(&id).unwrap().expires_at, Some(2_000));
+    }
+}
+
+/// Split: emits `Split` with the new ids.
+#[test]
+fn test_split_emits_event() {
+    let h = Harness::new();
+    let user = Address::generate(&h.env);
+    h.fund_contract(TIER_GOLD as i128);
+    let tid = h.client.mint_voucher(&h.admin, &user, &TIER_GOLD);
+
+    let ids = h
+        .client
+        .split_voucher(&user, &tid, &vec![&h.env, TIER_SILVER, TIER_SILVER]);
+
+    let (_, topics, data) = h.env.events().all().last().unwrap();
+    let name: Symbol = topics.get(0).unwrap().into_val(&h.env);
+    let data: Vec<u128> = data.into_val(&h.env);
+    assert_eq!(name, symbol_short!("Split"));
+    assert_eq!(data, ids);
+}
+
+/// Split: amounts that do not sum exactly to the value are rejected, in
+/// either direction.
+#[test]
+fn test_split_amounts_must_sum_to_value() {
+    let h = Harness::new();
+    let user = Address::generate(&h.env);
+    h.fund_contract(TIER_GOLD as i128);
+    let tid = h.client.mint_voucher(&h.admin, &user, &TIER_GOLD);
+
+    let short = vec![&h.env, TIER_SILVER, TIER_SILVER - 1];
+    let long = vec![&h.env, TIER_SILVER, TIER_SILVER + 1];
+    assert!(h.client.try_split_voucher(&user, &tid, &short).is_err());
+    assert!(h.client.try_split_voucher(&user, &tid, &long).is_err());
+    assert_eq!(h.client.get_balance(&user, &tid), 1);
+    assert_eq!(h.client.voucher_info(&tid).unwrap().value, TIER_GOLD);
+}
+
+/// Split: single-part, zero-part and overflowing splits are rejected.
+#[test]
+fn test_split_degenerate_amounts_rejected() {
+    let h = Harness::new();
+    let user = Address::generate(&h.env);
+    h.fund_contract(TIER_GOLD as i128);
+    let tid = h.client.mint_voucher(&h.admin, &user, &TIER_GOLD);
+
+    let single = vec![&h.env, TIER_GOLD];
+    let zero = vec![&h.env, TIER_GOLD, 0];
+    let overflow = vec![&h.env, u128::MAX, TIER_GOLD + 1];
+    assert!(h.client.try_split_voucher(&user, &tid, &single).is_err());
+    assert!(h.client.try_split_voucher(&user, &tid, &zero).is_err());
+    assert!(h.client.try_split_voucher(&user, &tid, &overflow).is_err());
+}
+
+/// Split: only a holder can split a voucher.
+#[test]
+#[should_panic(expected = "Insufficient balance")]
+fn test_split_by_non_holder_rejected() {
+    let h = Harness::new();
+    let user = Address::generate(&h.env);
+    h.fund_contract(TIER_GOLD as i128);
+    let tid = h.client.mint_voucher(&h.admin, &user, &TIER_GOLD);
+    h.client.split_voucher(
+        &Address::generate(&h.env),
+        &tid,
+        &vec![&h.env, TIER_SILVER, TIER_SILVER],
+    );
+}
+
 // ── Snapshot tests ────────────────────────────────────────────────────────────
 //
 // These snapshot-style tests pin the exact numeric outcomes for each tier so
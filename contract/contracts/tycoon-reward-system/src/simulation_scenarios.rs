@@ -15,7 +15,7 @@
 /// | S-06 | Treasury withdrawal after season        | Admin drains residual TYC and USDC to treasury wallet        |
 /// | S-07 | Concurrent multi-voucher accrual        | Single player accumulates 5 vouchers, redeems sequentially   |
 /// | S-08 | Double-redeem guard                     | Redeeming the same voucher twice must panic on second call   |
-/// | S-09 | Underfunded contract guard              | Redeem panics when contract TYC balance < voucher value      |
+/// | S-09 | Underfunded contract guard              | Mint panics when contract TYC balance < voucher value        |
 /// | S-10 | owned_token_count lifecycle             | Count tracks mint → transfer → redeem across two players     |
 extern crate std;
 
@@ -405,10 +405,11 @@ fn sim_s08_double_redeem_panics() {
 
 // ── S-09: Underfunded contract guard ─────────────────────────────────────────
 
-/// If the contract holds less TYC than a voucher's value, the redeem must
-/// panic and leave the voucher intact (no partial transfer).
+/// If the contract holds less TYC than a voucher's value, the mint must
+/// panic so no voucher is ever issued that a redeem could not honor.
 #[test]
-fn sim_s09_underfunded_contract_panics_on_redeem() {
+#[should_panic(expected = "Insufficient reserves")]
+fn sim_s09_underfunded_contract_panics_on_mint() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -431,21 +432,7 @@ fn sim_s09_underfunded_contract_panics_on_redeem() {
     // Fund with only 1 raw unit — far less than TIER_BRONZE
     StellarAssetClient::new(&env, &tyc_id).mint(&contract_id, &1);
 
-    let tid = client.mint_voucher(&backend, &player, &TIER_BRONZE);
-    assert_eq!(client.get_balance(&player, &tid), 1);
-
-    // Redeem must panic because contract balance < voucher value
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-        client.redeem_voucher_from(&player, &tid);
-    }));
-    assert!(
-        result.is_err(),
-        "redeem must panic when contract is underfunded"
-    );
-
-    // Voucher must still be intact (burn happens before transfer in CEI, but
-    // the token transfer panic unwinds the whole invocation in the sandbox)
-    // — balance check is best-effort; the key invariant is the panic above.
+    client.mint_voucher(&backend, &player, &TIER_BRONZE);
 }
 
 // ── S-10: owned_token_count lifecycle ────────────────────────────────────────
@@ -0,0 +1,153 @@
+/// # Voucher solvency
+///
+/// Covers outstanding-liability tracking, the reserve check on mint and the
+/// `withdraw_funds` underfunding guard.
+///
+/// | ID    | What it pins |
+/// |-------|--------------|
+/// | SV-01 | Minting reserves the voucher value; redeeming releases it |
+/// | SV-02 | Single and batch mints beyond the contract balance are refused |
+/// | SV-03 | `withdraw_funds` may take `free_balance` but no more |
+/// | SV-04 | `sweep_expired` releases the reservation of swept vouchers |
+/// | SV-05 | Liabilities are tracked per asset |
+extern crate std;
+
+use crate::test_support::{deref_setup, Setup};
+use crate::VoucherAsset;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address};
+
+const FUNDING: u128 = 1_000;
+const NOW: u64 = 1_700_000_000;
+
+// ── Harness ───────────────────────────────────────────────────────────────────
+
+struct H<'a> {
+    base: Setup<'a>,
+}
+deref_setup!(H);
+
+impl H<'_> {
+    /// Contract funded with `FUNDING` of TYC and USDC. Ledger clock at `NOW`.
+    fn new() -> Self {
+        let base = Setup::new();
+        base.env.ledger().set_timestamp(NOW);
+        base.fund_contract(&base.tyc, FUNDING as i128);
+        base.fund_contract(&base.usdc, FUNDING as i128);
+        H { base }
+    }
+}
+
+// ── SV-01 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn sv_01_mint_reserves_and_redeem_releases() {
+    let h = H::new();
+    let user = Address::generate(&h.env);
+    assert_eq!(h.client.outstanding_liabilities(&h.tyc.address), 0);
+    assert_eq!(h.client.free_balance(&h.tyc.address), FUNDING);
+
+    let first = h.client.mint_voucher(&h.admin, &user, &300);
+    h.client.mint_voucher(&h.admin, &user, &200);
+    assert_eq!(h.client.outstanding_liabilities(&h.tyc.address), 500);
+    assert_eq!(h.client.free_balance(&h.tyc.address), FUNDING - 500);
+
+    h.client.redeem_voucher_from(&user, &first);
+    assert_eq!(h.client.outstanding_liabilities(&h.tyc.address), 200);
+    // The payout left the contract, so the free balance is unchanged.
+    assert_eq!(h.client.free_balance(&h.tyc.address), FUNDING - 500);
+}
+
+// ── SV-02 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn sv_02_mint_up_to_balance_succeeds() {
+    let h = H::new();
+    h.client
+        .mint_voucher(&h.admin, &Address::generate(&h.env), &FUNDING);
+    assert_eq!(h.client.free_balance(&h.tyc.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient reserves")]
+fn sv_02_mint_beyond_balance_rejected() {
+    let h = H::new();
+    let user = Address::generate(&h.env);
+    h.client.mint_voucher(&h.admin, &user, &(FUNDING - 1));
+    h.client.mint_voucher(&h.admin, &user, &2);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient reserves")]
+fn sv_02_batch_beyond_balance_rejected() {
+    let h = H::new();
+    let recipients = vec![
+        &h.env,
+        (Address::generate(&h.env), FUNDING / 2),
+        (Address::generate(&h.env), FUNDING / 2 + 1),
+    ];
+    h.client.mint_vouchers_batch(&h.admin, &recipients);
+}
+
+// ── SV-03 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn sv_03_withdraw_free_balance_succeeds() {
+    let h = H::new();
+    let treasury = Address::generate(&h.env);
+    h.client
+        .mint_voucher(&h.admin, &Address::generate(&h.env), &400);
+
+    h.client
+        .withdraw_funds(&h.tyc.address, &treasury, &(FUNDING - 400));
+    assert_eq!(h.tyc.balance(&treasury), (FUNDING - 400) as i128);
+    assert_eq!(h.client.free_balance(&h.tyc.address), 0);
+    assert_eq!(h.client.outstanding_liabilities(&h.tyc.address), 400);
+}
+
+#[test]
+#[should_panic(expected = "Withdrawal would leave vouchers underfunded")]
+fn sv_03_withdraw_reserved_funds_rejected() {
+    let h = H::new();
+    h.client
+        .mint_voucher(&h.admin, &Address::generate(&h.env), &400);
+    h.client
+        .withdraw_funds(&h.tyc.address, &Address::generate(&h.env), &(FUNDING - 399));
+}
+
+// ── SV-04 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn sv_04_sweep_releases_reservation() {
+    let h = H::new();
+    let user = Address::generate(&h.env);
+    let expiring = h
+        .client
+        .mint_voucher_with_expiry(&h.admin, &user, &300, &(NOW + 10));
+    h.client.mint_voucher(&h.admin, &user, &100);
+
+    h.env.ledger().set_timestamp(NOW + 10);
+    h.client.sweep_expired(&vec![&h.env, expiring]);
+    assert_eq!(h.client.outstanding_liabilities(&h.tyc.address), 100);
+    assert_eq!(h.client.free_balance(&h.tyc.address), FUNDING - 100);
+}
+
+// ── SV-05 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn sv_05_liabilities_are_per_asset() {
+    let h = H::new();
+    let user = Address::generate(&h.env);
+    let usdc_voucher = h
+        .client
+        .mint_asset_voucher(&h.admin, &user, &VoucherAsset::Usdc, &FUNDING);
+    h.client.mint_voucher(&h.admin, &user, &10);
+
+    assert_eq!(h.client.outstanding_liabilities(&h.usdc.address), FUNDING);
+    assert_eq!(h.client.outstanding_liabilities(&h.tyc.address), 10);
+    assert_eq!(h.client.free_balance(&h.usdc.address), 0);
+
+    h.client.redeem_voucher_from(&user, &usdc_voucher);
+    assert_eq!(h.client.outstanding_liabilities(&h.usdc.address), 0);
+    assert_eq!(h.client.outstanding_liabilities(&h.tyc.address), 10);
+}
@@ -0,0 +1,247 @@
+/// # Storage layout and TTLs
+///
+/// Covers the v2 layout (configuration in instance storage), the v1 → v2
+/// step of `migrate` and TTL extension of the instance and of balances.
+///
+/// | ID    | What it pins |
+/// |-------|--------------|
+/// | SL-01 | `initialize` writes configuration to instance storage at `STATE_VERSION` |
+/// | SL-02 | `migrate` moves a v1 contract's configuration to instance storage |
+/// | SL-03 | A v1 contract cannot be re-initialized; `migrate` is idempotent at v2 |
+/// | SL-04 | Balance writes extend the balance TTL; calls extend the instance TTL |
+/// | SL-05 | `mint_voucher`, `transfer` and `redeem_voucher_from` budget ceilings |
+extern crate std;
+
+use crate::test_support::{deref_setup, Setup};
+use crate::{CampaignPayout, DataKey, BALANCE_BUMP_AMOUNT, INSTANCE_BUMP_AMOUNT, STATE_VERSION};
+use soroban_sdk::testutils::storage::{Instance as _, Persistent as _};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, BytesN, Val};
+
+const CONFIG_KEYS: [DataKey; 10] = [
+    DataKey::Admin,
+    DataKey::TycToken,
+    DataKey::UsdcToken,
+    DataKey::VoucherCount,
+    DataKey::Paused,
+    DataKey::BackendMinter,
+    DataKey::MintLimit,
+    DataKey::StreakTable,
+    DataKey::StreakGrace,
+    DataKey::CampaignCount,
+];
+
+// ── Harness ───────────────────────────────────────────────────────────────────
+
+struct H<'a> {
+    base: Setup<'a>,
+    backend: Address,
+    user: Address,
+}
+deref_setup!(H);
+
+impl H<'_> {
+    /// Funded contract with a backend minter, a mint limit, a streak table and
+    /// grace, and one campaign, so every configuration key is written.
+    fn new() -> Self {
+        let base = Setup::new();
+        base.fund_contract(&base.tyc, 1_000_000);
+        let backend = Address::generate(&base.env);
+        base.client.set_backend_minter(&backend);
+        base.client.set_mint_limit(&10, &1_000, &1_000);
+        base.client.set_streak_table(&vec![&base.env, 10]);
+        base.client.set_streak_grace(&1);
+        let h = H {
+            user: Address::generate(&base.env),
+            base,
+            backend,
+        };
+        h.create_campaign();
+        h
+    }
+
+    /// Fund and create a one-leaf TYC campaign, returning its id.
+    fn create_campaign(&self) -> u32 {
+        self.fund(&self.tyc, &self.admin, 100);
+        self.client.create_campaign(
+            &BytesN::from_array(&self.env, &[7; 32]),
+            &1,
+            &CampaignPayout::Tyc,
+            &100,
+            &1_000,
+        )
+    }
+
+    /// Rewrite the contract's storage into the v1 layout.
+    fn downgrade_to_v1(&self) {
+        self.env.as_contract(&self.client.address, || {
+            let storage = self.env.storage();
+            for key in CONFIG_KEYS.iter() {
+                if let Some(value) = storage.instance().get::<DataKey, Val>(key) {
+                    storage.persistent().set(key, &value);
+                    storage.instance().remove(key);
+                }
+            }
+            storage.persistent().set(&DataKey::StateVersion, &1u32);
+        });
+    }
+
+    fn in_instance(&self, key: &DataKey) -> bool {
+        self.env.as_contract(&self.client.address, || {
+            self.env.storage().instance().has(key)
+        })
+    }
+
+    fn in_persistent(&self, key: &DataKey) -> bool {
+        self.env.as_contract(&self.client.address, || {
+            self.env.storage().persistent().has(key)
+        })
+    }
+
+    fn version(&self) -> u32 {
+        self.env.as_contract(&self.client.address, || {
+            self.env
+                .storage()
+                .persistent()
+                .get(&DataKey::StateVersion)
+                .unwrap()
+        })
+    }
+}
+
+// ── SL-01 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn sl_01_config_in_instance_storage() {
+    let h = H::new();
+    for key in CONFIG_KEYS.iter() {
+        assert!(h.in_instance(key));
+        assert!(!h.in_persistent(key));
+    }
+    assert_eq!(h.version(), STATE_VERSION);
+}
+
+// ── SL-02 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn sl_02_migrate_moves_v1_config() {
+    let h = H::new();
+    let first = h.client.mint_voucher(&h.admin, &h.user, &100);
+    h.downgrade_to_v1();
+    assert!(h.in_persistent(&DataKey::Admin));
+    assert!(h.client.try_pause().is_err());
+
+    h.client.migrate();
+
+    for key in CONFIG_KEYS.iter() {
+        assert!(h.in_instance(key));
+        assert!(!h.in_persistent(key));
+    }
+    assert_eq!(h.version(), 2);
+    assert_eq!(h.client.get_backend_minter(), Some(h.backend.clone()));
+    assert_eq!(h.client.get_streak_table(), vec![&h.env, 10]);
+    assert_eq!(h.client.get_streak_grace(), 1);
+    let second = h.client.mint_voucher(&h.backend, &h.user, &100);
+    assert_eq!(second, first + 1);
+    // The limit kept its usage across the move.
+    assert_eq!(h.client.get_mint_limit().unwrap().count, 1);
+    h.client.redeem_voucher_from(&h.user, &first);
+    assert_eq!(h.create_campaign(), 1);
+}
+
+#[test]
+#[should_panic]
+fn sl_02_migrate_requires_v1_admin() {
+    let h = H::new();
+    h.downgrade_to_v1();
+    h.env.mock_auths(&[]);
+    h.client.migrate();
+}
+
+// ── SL-03 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic(expected = "Already initialized")]
+fn sl_03_v1_contract_cannot_be_reinitialized() {
+    let h = H::new();
+    h.downgrade_to_v1();
+    let other = Address::generate(&h.env);
+    h.client.initialize(&other, &other, &other);
+}
+
+#[test]
+fn sl_03_migrate_idempotent_at_v2() {
+    let h = H::new();
+    h.client.migrate();
+    h.client.migrate();
+    assert_eq!(h.version(), STATE_VERSION);
+    assert!(h.in_instance(&DataKey::Admin));
+}
+
+// ── SL-04 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn sl_04_balance_and_instance_ttls_extended() {
+    let h = H::new();
+    let id = h.client.mint_voucher(&h.backend, &h.user, &100);
+    let key = DataKey::Balance(h.user.clone(), id);
+    let ttl = || {
+        h.env.as_contract(&h.client.address, || {
+            h.env.storage().persistent().get_ttl(&key)
+        })
+    };
+    assert_eq!(ttl(), BALANCE_BUMP_AMOUNT);
+    assert_eq!(
+        h.env
+            .as_contract(&h.client.address, || h.env.storage().instance().get_ttl()),
+        INSTANCE_BUMP_AMOUNT
+    );
+
+    // A month later the balance is below the threshold; writing it extends
+    // it again.
+    h.env
+        .ledger()
+        .with_mut(|li| li.sequence_number += INSTANCE_BUMP_AMOUNT);
+    assert!(ttl() < BALANCE_BUMP_AMOUNT);
+    h.client.transfer(&h.user, &h.user, &id, &1);
+    assert_eq!(ttl(), BALANCE_BUMP_AMOUNT);
+}
+
+// ── SL-05 ─────────────────────────────────────────────────────────────────────
+
+/// Sandbox budget of the calls below on the v1 layout → the v2 layout:
+///
+/// | Call                  | CPU instructions    | Memory bytes    | Read entries |
+/// |-----------------------|---------------------|-----------------|--------------|
+/// | `mint_voucher`        | 355 390 → 371 178   | 61 121 → 64 469 | 17 → 13      |
+/// | `transfer`            | 274 818 → 299 678   | 55 914 → 58 830 | 11 → 11      |
+/// | `redeem_voucher_from` | 453 614 → 451 116   | 87 495 → 85 793 | 17 → 16      |
+///
+/// The harness also sets a streak table and grace and creates a campaign.
+/// Since those keys moved into the instance, every call reads the larger
+/// instance, and the v2 figures rise to 423 593, 341 874 and 498 261
+/// instructions. The mint limit is cleared so mints take the same path.
+#[test]
+fn sl_05_budget_ceilings() {
+    let h = H::new();
+    h.client.clear_mint_limit();
+    let buyer = Address::generate(&h.env);
+    h.client.mint_voucher(&h.backend, &h.user, &100);
+
+    let id = h.client.mint_voucher(&h.backend, &h.user, &100);
+    let mint = h.env.cost_estimate().resources();
+    h.client.transfer(&h.user, &buyer, &id, &1);
+    let transfer = h.env.cost_estimate().resources();
+    h.client.redeem_voucher_from(&buyer, &id);
+    let redeem = h.env.cost_estimate().resources();
+
+    for (resources, ceiling) in [(mint, 450_000), (transfer, 370_000), (redeem, 530_000)] {
+        assert!(
+            resources.instructions <= ceiling,
+            "{} > {}",
+            resources.instructions,
+            ceiling
+        );
+        assert!(resources.write_entries <= 10, "{}", resources.write_entries);
+    }
+}
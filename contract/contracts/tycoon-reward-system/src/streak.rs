@@ -0,0 +1,96 @@
+//! Daily login streaks.
+//!
+//! Days are UTC days since the Unix epoch (`timestamp / SECONDS_PER_DAY`). A
+//! player may claim once per day. Claiming on the day after their last claim,
+//! or after missing at most `StreakGrace` days, extends the streak; a longer
+//! gap starts it again at 1. The voucher value for a streak of `n` days is
+//! entry `n - 1` of the admin's streak table, or its last entry once the
+//! streak outgrows the table.
+use crate::DataKey;
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Longest streak table the admin may set.
+pub const MAX_STREAK_TABLE: u32 = 31;
+
+/// A player's streak as of their last claim.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreakState {
+    /// UTC day of the last claim.
+    pub last_day: u64,
+    /// Consecutive days claimed, including `last_day`.
+    pub streak: u32,
+}
+
+fn today(e: &Env) -> u64 {
+    e.ledger().timestamp() / SECONDS_PER_DAY
+}
+
+pub(crate) fn state(e: &Env, player: &Address) -> Option<StreakState> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Streak(player.clone()))
+}
+
+pub(crate) fn grace_days(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::StreakGrace)
+        .unwrap_or(0)
+}
+
+pub(crate) fn table(e: &Env) -> Vec<u128> {
+    e.storage()
+        .instance()
+        .get(&DataKey::StreakTable)
+        .unwrap_or_else(|| Vec::new(e))
+}
+
+/// Whether a streak last claimed on `last_day` survives until `day`.
+fn alive(e: &Env, last_day: u64, day: u64) -> bool {
+    day - last_day <= 1 + grace_days(e) as u64
+}
+
+/// Record today's claim for `player` and return the new streak and the
+/// voucher value it earns. Panics if the player already claimed today.
+pub(crate) fn record_claim(e: &Env, player: &Address) -> (u32, u128) {
+    let table = table(e);
+    if table.is_empty() {
+        panic!("Streak table not set");
+    }
+
+    let day = today(e);
+    let streak = match state(e, player) {
+        Some(prev) if prev.last_day == day => panic!("Already claimed today"),
+        Some(prev) if alive(e, prev.last_day, day) => prev.streak + 1,
+        _ => 1,
+    };
+    e.storage().persistent().set(
+        &DataKey::Streak(player.clone()),
+        &StreakState {
+            last_day: day,
+            streak,
+        },
+    );
+
+    let value = table.get(streak.min(table.len()) - 1).unwrap();
+    (streak, value)
+}
+
+/// The player's streak if claiming now would extend it, else 0.
+pub(crate) fn current_streak(e: &Env, player: &Address) -> u32 {
+    match state(e, player) {
+        Some(prev) if alive(e, prev.last_day, today(e)) => prev.streak,
+        _ => 0,
+    }
+}
+
+/// Unix timestamp from which `player` may claim next.
+pub(crate) fn next_claim_time(e: &Env, player: &Address) -> u64 {
+    match state(e, player) {
+        Some(prev) if prev.last_day == today(e) => (prev.last_day + 1) * SECONDS_PER_DAY,
+        _ => e.ledger().timestamp(),
+    }
+}
@@ -0,0 +1,282 @@
+/// # Daily login streaks
+///
+/// Covers the `streak` module, `claim_daily` and the streak views.
+///
+/// | ID    | What it pins |
+/// |-------|--------------|
+/// | ST-01 | The first claim starts a streak of 1 and mints `table[0]` |
+/// | ST-02 | Consecutive days extend the streak; the last entry caps the value |
+/// | ST-03 | One claim per UTC day, even across a few seconds to midnight |
+/// | ST-04 | A missed day resets the streak |
+/// | ST-05 | Grace days keep the streak alive; one more resets it |
+/// | ST-06 | `current_streak` and `next_claim_time` |
+/// | ST-07 | Table and grace are admin-only and validated; claims need a table |
+/// | ST-08 | Claims are blocked while paused and need reserves |
+/// | ST-09 | Only registered players may claim |
+/// | ST-10 | Claims draw on the mint limit budget and are refused once it is spent |
+extern crate std;
+
+use crate::mint_limit::MintBudget;
+use crate::streak::{MAX_STREAK_TABLE, SECONDS_PER_DAY};
+use crate::test_support::{deref_setup, Setup};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, Vec};
+
+/// Midnight UTC, day 19 675.
+const DAY0: u64 = 19_675 * SECONDS_PER_DAY;
+
+// ── Harness ───────────────────────────────────────────────────────────────────
+
+struct H<'a> {
+    base: Setup<'a>,
+    player: Address,
+}
+deref_setup!(H);
+
+impl H<'_> {
+    /// Funded contract with a `[10, 20, 30]` streak table and no grace, and a
+    /// registered player. Ledger clock at noon on `DAY0`.
+    fn new() -> Self {
+        let base = Setup::new();
+        base.env.ledger().set_timestamp(DAY0 + SECONDS_PER_DAY / 2);
+        base.fund_contract(&base.tyc, 1_000);
+        base.client.set_streak_table(&vec![&base.env, 10, 20, 30]);
+        let player = Address::generate(&base.env);
+        base.registry().register(&player);
+        H { base, player }
+    }
+
+    /// Move the clock to `offset` seconds into day `DAY0 + day`.
+    fn at(&self, day: u64, offset: u64) {
+        self.env
+            .ledger()
+            .set_timestamp(DAY0 + day * SECONDS_PER_DAY + offset);
+    }
+
+    /// Claim and return the minted voucher's value.
+    fn claim(&self) -> u128 {
+        let id = self.client.claim_daily(&self.player);
+        self.client.voucher_info(&id).unwrap().value
+    }
+}
+
+// ── ST-01 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn st_01_first_claim_starts_streak() {
+    let h = H::new();
+    let id = h.client.claim_daily(&h.player);
+
+    assert_eq!(h.client.get_balance(&h.player, &id), 1);
+    assert_eq!(h.client.voucher_info(&id).unwrap().value, 10);
+    assert_eq!(h.client.current_streak(&h.player), 1);
+}
+
+// ── ST-02 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn st_02_consecutive_days_follow_table() {
+    let h = H::new();
+    let mut values = std::vec::Vec::new();
+    for day in 0..5 {
+        h.at(day, 3_600);
+        values.push(h.claim());
+    }
+    assert_eq!(values, std::vec![10, 20, 30, 30, 30]);
+    assert_eq!(h.client.current_streak(&h.player), 5);
+}
+
+// ── ST-03 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic(expected = "Already claimed today")]
+fn st_03_second_claim_same_day_rejected() {
+    let h = H::new();
+    h.at(0, 0);
+    h.claim();
+    h.at(0, SECONDS_PER_DAY - 1);
+    h.claim();
+}
+
+#[test]
+fn st_03_claims_either_side_of_midnight_extend() {
+    let h = H::new();
+    h.at(0, SECONDS_PER_DAY - 1);
+    h.claim();
+    h.at(1, 0);
+    assert_eq!(h.claim(), 20);
+}
+
+// ── ST-04 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn st_04_missed_day_resets() {
+    let h = H::new();
+    h.claim();
+    h.at(1, 0);
+    h.claim();
+
+    h.at(3, 0);
+    assert_eq!(h.client.current_streak(&h.player), 0);
+    assert_eq!(h.claim(), 10);
+    assert_eq!(h.client.current_streak(&h.player), 1);
+}
+
+// ── ST-05 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn st_05_grace_days_keep_streak() {
+    let h = H::new();
+    h.client.set_streak_grace(&2);
+    assert_eq!(h.client.get_streak_grace(), 2);
+    h.claim();
+
+    // Days 1 and 2 missed, within grace.
+    h.at(3, 0);
+    assert_eq!(h.client.current_streak(&h.player), 1);
+    assert_eq!(h.claim(), 20);
+
+    // Days 4, 5 and 6 missed, one more than grace.
+    h.at(7, 0);
+    assert_eq!(h.claim(), 10);
+}
+
+// ── ST-06 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn st_06_next_claim_time() {
+    let h = H::new();
+    let now = DAY0 + SECONDS_PER_DAY / 2;
+    assert_eq!(h.client.next_claim_time(&h.player), now);
+    assert_eq!(h.client.current_streak(&h.player), 0);
+
+    h.claim();
+    assert_eq!(h.client.next_claim_time(&h.player), DAY0 + SECONDS_PER_DAY);
+
+    h.at(1, 5);
+    assert_eq!(
+        h.client.next_claim_time(&h.player),
+        DAY0 + SECONDS_PER_DAY + 5
+    );
+}
+
+// ── ST-07 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn st_07_table_validation() {
+    let h = H::new();
+    let mut long = Vec::new(&h.env);
+    for i in 0..=MAX_STREAK_TABLE {
+        long.push_back(i as u128);
+    }
+    assert!(h.client.try_set_streak_table(&Vec::new(&h.env)).is_err());
+    assert!(h.client.try_set_streak_table(&long).is_err());
+    assert_eq!(h.client.get_streak_table(), vec![&h.env, 10, 20, 30]);
+}
+
+#[test]
+#[should_panic]
+fn st_07_table_is_admin_only() {
+    let h = H::new();
+    h.env.mock_auths(&[]);
+    h.client.set_streak_table(&vec![&h.env, 1]);
+}
+
+#[test]
+#[should_panic(expected = "Streak table not set")]
+fn st_07_claim_without_table_rejected() {
+    let s = Setup::new();
+    let player = Address::generate(&s.env);
+    s.registry().register(&player);
+    s.client.claim_daily(&player);
+}
+
+// ── ST-08 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic(expected = "Contract is paused")]
+fn st_08_claim_blocked_while_paused() {
+    let h = H::new();
+    h.client.pause();
+    h.claim();
+}
+
+#[test]
+fn st_08_claim_needs_reserves() {
+    let h = H::new();
+    h.client
+        .withdraw_funds(&h.tyc.address, &Address::generate(&h.env), &995);
+    assert!(h.client.try_claim_daily(&h.player).is_err());
+    // The failed claim left no streak behind.
+    assert_eq!(h.client.current_streak(&h.player), 0);
+    assert_eq!(h.tyc.balance(&h.client.address), 5);
+}
+
+// ── ST-09 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic(expected = "Player not registered")]
+fn st_09_unregistered_player_rejected() {
+    let h = H::new();
+    h.client.claim_daily(&Address::generate(&h.env));
+}
+
+#[test]
+#[should_panic(expected = "Player registry not set")]
+fn st_09_claims_need_registry() {
+    let s = Setup::new();
+    s.fund_contract(&s.tyc, 1_000);
+    s.client.set_streak_table(&vec![&s.env, 10]);
+    s.client.claim_daily(&Address::generate(&s.env));
+}
+
+#[test]
+fn st_09_registry_is_admin_set() {
+    let h = H::new();
+    assert!(h.client.get_player_registry().is_some());
+
+    h.env.mock_auths(&[]);
+    assert!(h
+        .client
+        .try_set_player_registry(&Address::generate(&h.env))
+        .is_err());
+}
+
+// ── ST-10 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn st_10_claims_use_mint_budget() {
+    let h = H::new();
+    h.client.set_mint_limit(&2, &1_000, &1_000);
+
+    h.claim();
+    assert_eq!(
+        h.client.remaining_mint_budget(),
+        Some(MintBudget {
+            count: 1,
+            value: 990
+        })
+    );
+}
+
+#[test]
+fn st_10_spent_budget_refuses_claim_without_pausing() {
+    let h = H::new();
+    h.client.set_mint_limit(&1, &1_000, &1_000);
+    h.claim();
+
+    h.at(1, 0);
+    assert!(h.client.try_claim_daily(&h.player).is_err());
+    assert_eq!(h.client.current_streak(&h.player), 1);
+
+    // The contract stays live; the admin can still mint.
+    h.client.mint_voucher(&h.admin, &h.player, &10);
+}
+
+#[test]
+#[should_panic(expected = "Mint limit reached")]
+fn st_10_claim_over_value_limit_rejected() {
+    let h = H::new();
+    h.client.set_mint_limit(&10, &5, &1_000);
+    h.claim();
+}
@@ -1,5 +1,5 @@
 extern crate std;
-use crate::{DataKey, TycoonRewardSystem, TycoonRewardSystemClient};
+use crate::{DataKey, TycoonRewardSystem, TycoonRewardSystemClient, STATE_VERSION};
 use soroban_sdk::testutils::{Address as TestAddress, Events};
 use soroban_sdk::{token, Address, Env};
 
@@ -111,12 +111,12 @@ fn test_pause_and_unpause_admin_only() {
     // Admin can pause
     client.pause();
     let paused: bool = env.as_contract(&contract_id, || {
-        env.storage().persistent().get(&DataKey::Paused).unwrap()
+        env.storage().instance().get(&DataKey::Paused).unwrap()
     });
     assert!(paused);
     client.unpause();
     let paused: bool = env.as_contract(&contract_id, || {
-        env.storage().persistent().get(&DataKey::Paused).unwrap()
+        env.storage().instance().get(&DataKey::Paused).unwrap()
     });
     assert!(!paused);
     // Non-admin cannot pause
@@ -660,6 +660,9 @@ fn test_owned_token_count() {
     assert_eq!(client.owned_token_count(&user1), 0);
     assert_eq!(client.owned_token_count(&user2), 0);
 
+    // Fund contract with TYC to back the vouchers
+    token::StellarAssetClient::new(&env, &tyc_token_id).mint(&contract_id.clone(), &10000);
+
     // Mint voucher for user1
     let tyc_value = 500u128;
     let token_id_1 = client.mint_voucher(&admin, &user1, &tyc_value);
@@ -688,9 +691,6 @@ fn test_owned_token_count() {
     assert_eq!(client.owned_token_count(&user1), 0);
     assert_eq!(client.owned_token_count(&user2), 2);
 
-    // Fund contract with TYC before redeem
-    token::StellarAssetClient::new(&env, &tyc_token_id).mint(&contract_id.clone(), &10000);
-
     // User2 redeems token_id_1 -> burns token_id_1
     client.redeem_voucher_from(&user2, &token_id_1);
 
@@ -794,7 +794,7 @@ fn test_redeem_blocked_when_paused() {
 // ===== MIGRATE TESTS (SW-001) =====
 
 #[test]
-fn test_migrate_is_idempotent_at_version_1() {
+fn test_migrate_is_idempotent_at_current_version() {
     let env = Env::default();
     env.mock_all_auths();
     let admin = Address::generate(&env);
@@ -808,10 +808,10 @@ fn test_migrate_is_idempotent_at_version_1() {
     let client = TycoonRewardSystemClient::new(&env, &contract_id);
     client.initialize(&admin, &tyc_id, &usdc_id);
 
-    // migrate at v1 is a no-op — must not panic
+    // migrate at the current version is a no-op — must not panic
     client.migrate();
 
-    // State version should still be 1
+    // State version should be unchanged
     let version: u32 = env.as_contract(&contract_id, || {
         env.storage()
             .persistent()
@@ -819,8 +819,8 @@ fn test_migrate_is_idempotent_at_version_1() {
             .unwrap_or(0)
     });
     assert_eq!(
-        version, 1,
-        "migrate must not change version when already at v1"
+        version, STATE_VERSION,
+        "migrate must not change version when already current"
     );
 }
 
@@ -877,6 +877,7 @@ fn test_transfer_blocked_when_paused() {
     let client = TycoonRewardSystemClient::new(&env, &contract_id);
 
     client.initialize(&admin, &tyc_token_id, &usdc_token_id);
+    token::StellarAssetClient::new(&env, &tyc_token_id).mint(&contract_id, &500);
 
     let token_id = client.mint_voucher(&admin, &user1, &500);
 
@@ -1046,6 +1047,7 @@ fn test_voucher_ids_are_unique() {
     let client = TycoonRewardSystemClient::new(&env, &contract_id);
 
     client.initialize(&admin, &tyc_token_id, &usdc_token_id);
+    token::StellarAssetClient::new(&env, &tyc_token_id).mint(&contract_id, &600);
 
     let id1 = client.mint_voucher(&admin, &user, &100);
     let id2 = client.mint_voucher(&admin, &user, &200);
@@ -1096,6 +1098,7 @@ fn test_redeem_nonexistent_token_panics() {
     let contract_id = env.register(TycoonRewardSystem, ());
     let client = TycoonRewardSystemClient::new(&env, &contract_id);
     client.initialize(&admin, &tyc_id, &usdc_id);
+    token::StellarAssetClient::new(&env, &tyc_id).mint(&contract_id, &100);
 
     // Mint a voucher to user_a
     let token_id = client.mint_voucher(&admin, &user_a, &100u128);
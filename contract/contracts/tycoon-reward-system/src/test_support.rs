@@ -0,0 +1,93 @@
+//! Shared harness for the reward-system test modules.
+//!
+//! `Setup` is an initialized contract with fresh TYC and USDC Stellar asset
+//! contracts and every auth mocked. Test modules wrap it in their own `H`,
+//! adding only the funding, fields and helpers they need; `H` derefs to
+//! `Setup` so `h.env`, `h.client`, `h.admin`, `h.tyc` and `h.usdc` work
+//! everywhere.
+use crate::{TycoonRewardSystem, TycoonRewardSystemClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contractimpl, token, Address, Env};
+
+/// Stand-in for tycoon-game's `is_registered` view.
+#[contract]
+pub(crate) struct MockRegistry;
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn register(e: Env, player: Address) {
+        e.storage().persistent().set(&player, &true);
+    }
+
+    pub fn is_registered(e: Env, player: Address) -> bool {
+        e.storage().persistent().has(&player)
+    }
+}
+
+pub(crate) struct Setup<'a> {
+    pub env: Env,
+    pub client: TycoonRewardSystemClient<'a>,
+    pub admin: Address,
+    pub tyc: token::Client<'a>,
+    pub usdc: token::Client<'a>,
+}
+
+impl Setup<'_> {
+    /// Initialized, unfunded contract.
+    pub fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
+        let contract_id = env.register(TycoonRewardSystem, ());
+        let client = TycoonRewardSystemClient::new(&env, &contract_id);
+        let tyc = Self::token(&env);
+        let usdc = Self::token(&env);
+        client.initialize(&admin, &tyc.address, &usdc.address);
+        Setup {
+            env,
+            client,
+            admin,
+            tyc,
+            usdc,
+        }
+    }
+
+    /// A fresh Stellar asset contract.
+    pub fn token<'b>(env: &Env) -> token::Client<'b> {
+        let id = env
+            .register_stellar_asset_contract_v2(Address::generate(env))
+            .address();
+        token::Client::new(env, &id)
+    }
+
+    /// Mint `amount` of `token` to `to`.
+    pub fn fund(&self, token: &token::Client, to: &Address, amount: i128) {
+        token::StellarAssetClient::new(&self.env, &token.address).mint(to, &amount);
+    }
+
+    /// Point the contract at a fresh `MockRegistry` and return its client.
+    pub fn registry(&self) -> MockRegistryClient<'_> {
+        let registry = MockRegistryClient::new(&self.env, &self.env.register(MockRegistry, ()));
+        self.client.set_player_registry(&registry.address);
+        registry
+    }
+
+    /// Mint `amount` of `token` to the reward contract.
+    pub fn fund_contract(&self, token: &token::Client, amount: i128) {
+        self.fund(token, &self.client.address, amount);
+    }
+}
+
+/// Implement `Deref<Target = Setup>` for a test module's `H { base, .. }`.
+macro_rules! deref_setup {
+    ($h:ident) => {
+        impl<'a> core::ops::Deref for $h<'a> {
+            type Target = crate::test_support::Setup<'a>;
+
+            fn deref(&self) -> &Self::Target {
+                &self.base
+            }
+        }
+    };
+}
+pub(crate) use deref_setup;
@@ -17,6 +17,7 @@ extern crate std;
 
 use crate::{TycoonRewardSystem, TycoonRewardSystemClient};
 use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::StellarAssetClient;
 use soroban_sdk::{Address, Env};
 
 // ── Harness ───────────────────────────────────────────────────────────────────
@@ -41,6 +42,7 @@ impl H<'_> {
         let contract_id = env.register(TycoonRewardSystem, ());
         let client = TycoonRewardSystemClient::new(&env, &contract_id);
         client.initialize(&admin, &tyc_id, &usdc_id);
+        StellarAssetClient::new(&env, &tyc_id).mint(&contract_id, &1_000);
         H {
             env,
             client,
@@ -0,0 +1,224 @@
+/// # Voucher expiry and sweeping
+///
+/// Covers `mint_voucher_with_expiry`, the expiry check in `redeem_voucher_from`,
+/// the permissionless `sweep_expired` and the `voucher_info` view.
+///
+/// | ID    | What it pins |
+/// |-------|--------------|
+/// | VE-01 | `voucher_info` reports value, expiry and holder count |
+/// | VE-02 | An expiring voucher redeems normally before its expiry |
+/// | VE-03 | Redeeming at or after the expiry is rejected |
+/// | VE-04 | An expiry that is not in the future is rejected at mint |
+/// | VE-05 | `sweep_expired` burns expired vouchers from every holder |
+/// | VE-06 | Unexpired, non-expiring and unknown ids are skipped |
+/// | VE-07 | Swept TYC stays in the contract and can be withdrawn |
+/// | VE-08 | Oversized sweeps are rejected; a full sweep fits the write limit |
+extern crate std;
+
+use crate::test_support::{deref_setup, Setup};
+use crate::{VoucherAsset, VoucherInfo, MAX_SWEEP_BATCH};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, Vec};
+
+const VALUE: u128 = 100_000_000_000_000_000_000; // 100 TYC
+const NOW: u64 = 1_700_000_000;
+const DAY: u64 = 86_400;
+
+// ── Harness ───────────────────────────────────────────────────────────────────
+
+struct H<'a> {
+    base: Setup<'a>,
+}
+deref_setup!(H);
+
+impl H<'_> {
+    /// Contract funded with 10 000 TYC, ledger clock at `NOW`.
+    fn new() -> Self {
+        let base = Setup::new();
+        base.env.ledger().set_timestamp(NOW);
+        base.fund_contract(&base.tyc, 100 * VALUE as i128);
+        H { base }
+    }
+
+    fn mint_expiring(&self, to: &Address, expires_at: u64) -> u128 {
+        self.client
+            .mint_voucher_with_expiry(&self.admin, to, &VALUE, &expires_at)
+    }
+}
+
+// ── VE-01 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn ve_01_voucher_info_reports_value_expiry_and_holders() {
+    let h = H::new();
+    let user = Address::generate(&h.env);
+
+    let expiring = h.mint_expiring(&user, NOW + DAY);
+    let permanent = h.client.mint_voucher(&h.admin, &user, &VALUE);
+
+    assert_eq!(
+        h.client.voucher_info(&expiring),
+        Some(VoucherInfo {
+            asset: VoucherAsset::Tyc,
+            value: VALUE,
+            expires_at: Some(NOW + DAY),
+            holder_count: 1,
+        })
+    );
+    assert_eq!(h.client.voucher_info(&permanent).unwrap().expires_at, None);
+    assert_eq!(h.client.voucher_info(&42), None);
+}
+
+#[test]
+fn ve_01_holder_count_follows_transfers() {
+    let h = H::new();
+    let alice = Address::generate(&h.env);
+    let bob = Address::generate(&h.env);
+    let token_id = h.mint_expiring(&alice, NOW + DAY);
+
+    h.client.transfer(&alice, &bob, &token_id, &1);
+    assert_eq!(h.client.voucher_info(&token_id).unwrap().holder_count, 1);
+
+    // A second unit held by alice makes two holders.
+    h.client.test_mint(&alice, &token_id, &1);
+    assert_eq!(h.client.voucher_info(&token_id).unwrap().holder_count, 2);
+}
+
+// ── VE-02 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn ve_02_redeem_before_expiry_succeeds() {
+    let h = H::new();
+    let user = Address::generate(&h.env);
+    let token_id = h.mint_expiring(&user, NOW + DAY);
+
+    h.env.ledger().set_timestamp(NOW + DAY - 1);
+    h.client.redeem_voucher_from(&user, &token_id);
+
+    assert_eq!(h.tyc.balance(&user), VALUE as i128);
+    assert_eq!(h.client.voucher_info(&token_id), None);
+}
+
+// ── VE-03 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic(expected = "Voucher expired")]
+fn ve_03_redeem_at_expiry_rejected() {
+    let h = H::new();
+    let user = Address::generate(&h.env);
+    let token_id = h.mint_expiring(&user, NOW + DAY);
+
+    h.env.ledger().set_timestamp(NOW + DAY);
+    h.client.redeem_voucher_from(&user, &token_id);
+}
+
+// ── VE-04 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic(expected = "Expiry must be in the future")]
+fn ve_04_past_expiry_rejected() {
+    let h = H::new();
+    h.mint_expiring(&Address::generate(&h.env), NOW);
+}
+
+// ── VE-05 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn ve_05_sweep_burns_from_every_holder() {
+    let h = H::new();
+    let alice = Address::generate(&h.env);
+    let bob = Address::generate(&h.env);
+    let token_id = h.mint_expiring(&alice, NOW + DAY);
+    h.client.test_mint(&bob, &token_id, &2);
+
+    h.env.ledger().set_timestamp(NOW + DAY);
+    let swept = h.client.sweep_expired(&vec![&h.env, token_id]);
+
+    assert_eq!(swept, 1);
+    assert_eq!(h.client.get_balance(&alice, &token_id), 0);
+    assert_eq!(h.client.get_balance(&bob, &token_id), 0);
+    assert_eq!(h.client.owned_token_count(&alice), 0);
+    assert_eq!(h.client.owned_token_count(&bob), 0);
+    assert_eq!(h.client.voucher_info(&token_id), None);
+}
+
+#[test]
+#[should_panic(expected = "Invalid token_id")]
+fn ve_05_swept_voucher_cannot_be_redeemed() {
+    let h = H::new();
+    let user = Address::generate(&h.env);
+    let token_id = h.mint_expiring(&user, NOW + DAY);
+
+    h.env.ledger().set_timestamp(NOW + 2 * DAY);
+    h.client.sweep_expired(&vec![&h.env, token_id]);
+    h.client.redeem_voucher_from(&user, &token_id);
+}
+
+// ── VE-06 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn ve_06_sweep_skips_live_and_unknown_vouchers() {
+    let h = H::new();
+    let user = Address::generate(&h.env);
+    let expired = h.mint_expiring(&user, NOW + DAY);
+    let live = h.mint_expiring(&user, NOW + 3 * DAY);
+    let permanent = h.client.mint_voucher(&h.admin, &user, &VALUE);
+
+    h.env.ledger().set_timestamp(NOW + 2 * DAY);
+    let swept = h
+        .client
+        .sweep_expired(&vec![&h.env, expired, live, permanent, 7, expired]);
+
+    assert_eq!(swept, 1);
+    assert_eq!(h.client.get_balance(&user, &live), 1);
+    assert_eq!(h.client.get_balance(&user, &permanent), 1);
+    assert_eq!(h.client.owned_token_count(&user), 2);
+}
+
+// ── VE-07 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn ve_07_swept_value_can_be_withdrawn() {
+    let h = H::new();
+    let user = Address::generate(&h.env);
+    let treasury = Address::generate(&h.env);
+    let token_id = h.mint_expiring(&user, NOW + DAY);
+    let contract_balance = h.tyc.balance(&h.client.address);
+
+    h.env.ledger().set_timestamp(NOW + DAY);
+    h.client.sweep_expired(&vec![&h.env, token_id]);
+
+    // Sweeping moves no TYC; the admin decides what to do with it.
+    assert_eq!(h.tyc.balance(&h.client.address), contract_balance);
+    h.client.withdraw_funds(&h.tyc.address, &treasury, &VALUE);
+    assert_eq!(h.tyc.balance(&treasury), VALUE as i128);
+}
+
+// ── VE-08 ─────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic(expected = "Batch too large")]
+fn ve_08_oversized_sweep_rejected() {
+    let h = H::new();
+    let mut ids = Vec::new(&h.env);
+    for id in 0..=MAX_SWEEP_BATCH {
+        ids.push_back(id as u128);
+    }
+    h.client.sweep_expired(&ids);
+}
+
+/// Worst case: `MAX_SWEEP_BATCH` expired vouchers, each with its own holder.
+#[test]
+fn ve_08_full_sweep_within_write_limit() {
+    let h = H::new();
+    let mut ids = Vec::new(&h.env);
+    for _ in 0..MAX_SWEEP_BATCH {
+        ids.push_back(h.mint_expiring(&Address::generate(&h.env), NOW + DAY));
+    }
+
+    h.env.ledger().set_timestamp(NOW + DAY);
+    assert_eq!(h.client.sweep_expired(&ids), MAX_SWEEP_BATCH);
+
+    let resources = h.env.cost_estimate().resources();
+    assert!(resources.write_entries <= 50, "{}", resources.write_entries);
+}
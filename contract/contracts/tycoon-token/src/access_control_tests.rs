@@ -10,7 +10,7 @@ extern crate std;
 use crate::TycoonToken;
 use soroban_sdk::{
     testutils::{Address as _, MockAuth, MockAuthInvoke},
-    vec, Address, Env, IntoVal,
+    vec, Address, Env, IntoVal, String,
 };
 
 const SUPPLY: i128 = 1_000_000_000_000_000_000_000_000_000;
@@ -27,7 +27,13 @@ fn admin_can_mint() {
     let client = crate::TycoonTokenClient::new(&e, &id);
     let admin = Address::generate(&e);
     let user = Address::generate(&e);
-    client.initialize(&admin, &SUPPLY);
+    client.initialize(
+        &admin,
+        &SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     let amount: i128 = 1_000_000_000_000_000_000_000;
     client.mint(&user, &amount);
@@ -44,7 +50,13 @@ fn non_admin_cannot_mint() {
     let client = crate::TycoonTokenClient::new(&e, &id);
     let admin = Address::generate(&e);
     let attacker = Address::generate(&e);
-    client.initialize(&admin, &SUPPLY);
+    client.initialize(
+        &admin,
+        &SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     // Provide auth only for attacker — admin.require_auth() inside mint will fail.
     e.mock_auths(&[MockAuth {
@@ -67,7 +79,13 @@ fn non_admin_mint_does_not_change_supply() {
     let client = crate::TycoonTokenClient::new(&e, &id);
     let admin = Address::generate(&e);
     let attacker = Address::generate(&e);
-    client.initialize(&admin, &SUPPLY);
+    client.initialize(
+        &admin,
+        &SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     let supply_before = client.total_supply();
 
@@ -100,7 +118,13 @@ fn admin_can_set_admin() {
     let client = crate::TycoonTokenClient::new(&e, &id);
     let admin = Address::generate(&e);
     let new_admin = Address::generate(&e);
-    client.initialize(&admin, &SUPPLY);
+    client.initialize(
+        &admin,
+        &SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     client.set_admin(&new_admin);
     assert_eq!(client.admin(), new_admin);
@@ -115,7 +139,13 @@ fn non_admin_cannot_set_admin() {
     let client = crate::TycoonTokenClient::new(&e, &id);
     let admin = Address::generate(&e);
     let attacker = Address::generate(&e);
-    client.initialize(&admin, &SUPPLY);
+    client.initialize(
+        &admin,
+        &SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     e.mock_auths(&[MockAuth {
         address: &attacker,
@@ -137,7 +167,13 @@ fn non_admin_set_admin_does_not_change_admin() {
     let client = crate::TycoonTokenClient::new(&e, &id);
     let admin = Address::generate(&e);
     let attacker = Address::generate(&e);
-    client.initialize(&admin, &SUPPLY);
+    client.initialize(
+        &admin,
+        &SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
         e.mock_auths(&[MockAuth {
@@ -168,7 +204,13 @@ fn token_holder_can_transfer() {
     let client = crate::TycoonTokenClient::new(&e, &id);
     let admin = Address::generate(&e);
     let user = Address::generate(&e);
-    client.initialize(&admin, &SUPPLY);
+    client.initialize(
+        &admin,
+        &SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     let amount: i128 = 100_000_000_000_000_000_000_000_000;
     client.transfer(&admin, &user, &amount);
@@ -185,7 +227,13 @@ fn third_party_cannot_transfer_without_allowance() {
     let client = crate::TycoonTokenClient::new(&e, &id);
     let admin = Address::generate(&e);
     let attacker = Address::generate(&e);
-    client.initialize(&admin, &SUPPLY);
+    client.initialize(
+        &admin,
+        &SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     // Attacker provides their own auth but tries to move admin's tokens.
     e.mock_auths(&[MockAuth {
@@ -216,7 +264,13 @@ fn read_only_entrypoints_need_no_auth() {
     let id = e.register(TycoonToken, ());
     let client = crate::TycoonTokenClient::new(&e, &id);
     let admin = Address::generate(&e);
-    client.initialize(&admin, &SUPPLY);
+    client.initialize(
+        &admin,
+        &SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     // Clear all auth mocks — read-only calls must succeed without any auth.
     e.mock_auths(&[]);
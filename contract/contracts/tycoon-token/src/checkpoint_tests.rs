@@ -0,0 +1,245 @@
+/// # Tycoon Token (TYC) — Balance Checkpoint Tests
+///
+/// Covers `balance_at` / `total_supply_at` and the checkpoint bookkeeping
+/// behind them.
+///
+/// | ID    | Behaviour |
+/// |-------|-----------|
+/// | CP-01 | `balance_at` returns the balance as of the end of each past ledger |
+/// | CP-02 | Ledgers before an account's first change report zero |
+/// | CP-03 | Several changes in one ledger collapse into a single checkpoint |
+/// | CP-04 | `total_supply_at` tracks mint and burn history |
+/// | CP-05 | `transfer_from` and `burn_from` are checkpointed |
+/// | CP-06 | Querying a future ledger is rejected |
+/// | CP-07 | Balances that predate checkpointing are carried over on first change |
+/// | CP-08 | Extra write cost on `transfer` stays within the documented budget |
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+
+// ── helpers ──────────────────────────────────────────────────────────────────
+
+const INITIAL_SUPPLY: i128 = 1_000_000_000_000_000_000_000_000_000; // 1e9 * 10^18
+const ONE_TYC: i128 = 1_000_000_000_000_000_000;
+
+/// Spin up a fresh environment at ledger 10 and initialize the contract.
+/// Returns `(env, client, admin)`.
+fn setup() -> (Env, TycoonTokenClient<'static>, Address) {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_sequence_number(10);
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
+    (e, client, admin)
+}
+
+fn checkpoint_count(e: &Env, client: &TycoonTokenClient, account: &Address) -> u32 {
+    e.as_contract(&client.address, || {
+        e.storage()
+            .persistent()
+            .get(&DataKey::BalanceCheckpointCount(account.clone()))
+            .unwrap_or(0)
+    })
+}
+
+// ── CP-01 ─────────────────────────────────────────────────────────────────────
+
+/// CP-01: each past ledger reports the balance as it stood at the end of that ledger.
+#[test]
+fn test_cp_01_balance_at_tracks_history() {
+    let (e, client, admin) = setup();
+    let user = Address::generate(&e);
+
+    e.ledger().set_sequence_number(20);
+    client.transfer(&admin, &user, &(100 * ONE_TYC));
+    e.ledger().set_sequence_number(30);
+    client.transfer(&admin, &user, &(50 * ONE_TYC));
+    e.ledger().set_sequence_number(40);
+    client.transfer(&user, &admin, &(120 * ONE_TYC));
+    e.ledger().set_sequence_number(50);
+
+    assert_eq!(client.balance_at(&user, &20), 100 * ONE_TYC);
+    assert_eq!(client.balance_at(&user, &25), 100 * ONE_TYC);
+    assert_eq!(client.balance_at(&user, &30), 150 * ONE_TYC);
+    assert_eq!(client.balance_at(&user, &39), 150 * ONE_TYC);
+    assert_eq!(client.balance_at(&user, &40), 30 * ONE_TYC);
+    assert_eq!(client.balance_at(&user, &50), 30 * ONE_TYC);
+
+    assert_eq!(client.balance_at(&admin, &10), INITIAL_SUPPLY);
+    assert_eq!(
+        client.balance_at(&admin, &35),
+        INITIAL_SUPPLY - 150 * ONE_TYC
+    );
+    assert_eq!(client.balance_at(&admin, &50), client.balance(&admin));
+}
+
+// ── CP-02 ─────────────────────────────────────────────────────────────────────
+
+/// CP-02: an account reports zero for every ledger before it first received tokens.
+#[test]
+fn test_cp_02_before_first_change_is_zero() {
+    let (e, client, admin) = setup();
+    let user = Address::generate(&e);
+
+    e.ledger().set_sequence_number(100);
+    client.transfer(&admin, &user, &ONE_TYC);
+
+    assert_eq!(client.balance_at(&user, &0), 0);
+    assert_eq!(client.balance_at(&user, &99), 0);
+    assert_eq!(client.balance_at(&user, &100), ONE_TYC);
+
+    let stranger = Address::generate(&e);
+    assert_eq!(client.balance_at(&stranger, &50), 0);
+}
+
+// ── CP-03 ─────────────────────────────────────────────────────────────────────
+
+/// CP-03: repeated changes within one ledger overwrite rather than append.
+#[test]
+fn test_cp_03_same_ledger_changes_share_checkpoint() {
+    let (e, client, admin) = setup();
+    let user = Address::generate(&e);
+
+    e.ledger().set_sequence_number(20);
+    client.transfer(&admin, &user, &ONE_TYC);
+    client.transfer(&admin, &user, &ONE_TYC);
+    client.transfer(&admin, &user, &ONE_TYC);
+    assert_eq!(checkpoint_count(&e, &client, &user), 1);
+    assert_eq!(client.balance_at(&user, &20), 3 * ONE_TYC);
+
+    e.ledger().set_sequence_number(21);
+    client.transfer(&user, &admin, &ONE_TYC);
+    assert_eq!(checkpoint_count(&e, &client, &user), 2);
+    assert_eq!(client.balance_at(&user, &20), 3 * ONE_TYC);
+    assert_eq!(client.balance_at(&user, &21), 2 * ONE_TYC);
+}
+
+// ── CP-04 ─────────────────────────────────────────────────────────────────────
+
+/// CP-04: total supply history follows mints and burns.
+#[test]
+fn test_cp_04_total_supply_at_tracks_mint_and_burn() {
+    let (e, client, admin) = setup();
+    let user = Address::generate(&e);
+
+    e.ledger().set_sequence_number(20);
+    client.mint(&user, &(500 * ONE_TYC));
+    e.ledger().set_sequence_number(30);
+    client.burn(&user, &(200 * ONE_TYC));
+    e.ledger().set_sequence_number(40);
+    client.burn(&admin, &(100 * ONE_TYC));
+
+    assert_eq!(client.total_supply_at(&9), 0);
+    assert_eq!(client.total_supply_at(&10), INITIAL_SUPPLY);
+    assert_eq!(client.total_supply_at(&20), INITIAL_SUPPLY + 500 * ONE_TYC);
+    assert_eq!(client.total_supply_at(&35), INITIAL_SUPPLY + 300 * ONE_TYC);
+    assert_eq!(client.total_supply_at(&40), INITIAL_SUPPLY + 200 * ONE_TYC);
+    assert_eq!(client.total_supply_at(&40), client.total_supply());
+}
+
+// ── CP-05 ─────────────────────────────────────────────────────────────────────
+
+/// CP-05: allowance-based transfers and burns write checkpoints too.
+#[test]
+fn test_cp_05_allowance_paths_are_checkpointed() {
+    let (e, client, admin) = setup();
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    client.approve(&admin, &spender, &(100 * ONE_TYC), &0);
+
+    e.ledger().set_sequence_number(20);
+    client.transfer_from(&spender, &admin, &recipient, &(60 * ONE_TYC));
+    e.ledger().set_sequence_number(30);
+    client.burn_from(&spender, &admin, &(40 * ONE_TYC));
+
+    assert_eq!(client.balance_at(&recipient, &19), 0);
+    assert_eq!(client.balance_at(&recipient, &20), 60 * ONE_TYC);
+    assert_eq!(
+        client.balance_at(&admin, &20),
+        INITIAL_SUPPLY - 60 * ONE_TYC
+    );
+    assert_eq!(
+        client.balance_at(&admin, &30),
+        INITIAL_SUPPLY - 100 * ONE_TYC
+    );
+    assert_eq!(client.total_supply_at(&29), INITIAL_SUPPLY);
+    assert_eq!(client.total_supply_at(&30), INITIAL_SUPPLY - 40 * ONE_TYC);
+}
+
+// ── CP-06 ─────────────────────────────────────────────────────────────────────
+
+/// CP-06a: `balance_at` rejects ledgers that have not closed yet.
+#[test]
+#[should_panic(expected = "Ledger is in the future")]
+fn test_cp_06_balance_at_future_ledger_panics() {
+    let (_e, client, admin) = setup();
+    client.balance_at(&admin, &11);
+}
+
+/// CP-06b: `total_supply_at` rejects ledgers that have not closed yet.
+#[test]
+#[should_panic(expected = "Ledger is in the future")]
+fn test_cp_06_total_supply_at_future_ledger_panics() {
+    let (_e, client, _admin) = setup();
+    client.total_supply_at(&11);
+}
+
+// ── CP-07 ─────────────────────────────────────────────────────────────────────
+
+/// CP-07: a balance written before checkpoints existed is preserved as a
+/// ledger-0 checkpoint the first time the account changes.
+#[test]
+fn test_cp_07_legacy_balance_is_carried_over() {
+    let (e, client, admin) = setup();
+    let holder = Address::generate(&e);
+
+    // Simulate a pre-upgrade balance: raw storage, no checkpoints.
+    e.as_contract(&client.address, || {
+        e.storage()
+            .persistent()
+            .set(&DataKey::Balance(holder.clone()), &(70 * ONE_TYC));
+    });
+    assert_eq!(client.balance_at(&holder, &5), 70 * ONE_TYC);
+
+    e.ledger().set_sequence_number(50);
+    client.transfer(&holder, &admin, &(20 * ONE_TYC));
+
+    assert_eq!(checkpoint_count(&e, &client, &holder), 2);
+    assert_eq!(client.balance_at(&holder, &49), 70 * ONE_TYC);
+    assert_eq!(client.balance_at(&holder, &50), 50 * ONE_TYC);
+}
+
+// ── CP-08 ─────────────────────────────────────────────────────────────────────
+
+/// CP-08: sandbox budget for a steady-state `transfer` (both accounts already
+/// hold checkpoints, new ledger). Baseline before checkpoints was 3 write
+/// entries / 360 write bytes; see `GAS_SNAPSHOT_DIFF.md` for the full table.
+#[test]
+fn test_cp_08_transfer_checkpoint_write_budget() {
+    let (e, client, admin) = setup();
+    let user = Address::generate(&e);
+
+    e.ledger().set_sequence_number(20);
+    client.transfer(&admin, &user, &ONE_TYC);
+
+    // New ledger: each side appends a checkpoint and bumps its count.
+    e.ledger().set_sequence_number(21);
+    client.transfer(&admin, &user, &ONE_TYC);
+    let appended = e.cost_estimate().resources();
+    assert_eq!(appended.write_entries, 7);
+    assert!(appended.write_bytes <= 1_200);
+
+    // Same ledger: each side overwrites its last checkpoint in place.
+    client.transfer(&admin, &user, &ONE_TYC);
+    let overwritten = e.cost_estimate().resources();
+    assert_eq!(overwritten.write_entries, 5);
+    assert!(overwritten.write_bytes <= appended.write_bytes);
+}
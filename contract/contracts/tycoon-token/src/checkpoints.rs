@@ -0,0 +1,181 @@
+//! Historical balance and supply checkpoints.
+//!
+//! Every write to `DataKey::Balance` or `DataKey::TotalSupply` also appends a
+//! `Checkpoint { ledger, value }` entry so that `balance_at` / `total_supply_at`
+//! can answer "what was the value at the end of ledger N" with a binary search
+//! over O(log n) persistent reads.
+//!
+//! Checkpoints are stored one per key (`BalanceCheckpoint(account, index)`),
+//! never as a single growing `Vec`, so the cost of a write stays constant no
+//! matter how long an account's history gets. Two changes in the same ledger
+//! overwrite the last checkpoint instead of appending a new one.
+//!
+//! Accounts (and the supply) that already held a value before checkpointing
+//! was introduced get a ledger-0 checkpoint carrying that value the first time
+//! they change, so historical queries stay correct across the upgrade.
+use crate::DataKey;
+use soroban_sdk::{contracttype, Address, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Checkpoint {
+    pub ledger: u32,
+    pub value: i128,
+}
+
+/// Record `new_balance` for `account` at the current ledger.
+///
+/// Must be called *before* `DataKey::Balance(account)` is overwritten so the
+/// pre-checkpoint balance can be carried over on the account's first write.
+pub(crate) fn push_balance(e: &Env, account: &Address, new_balance: i128) {
+    let count_key = DataKey::BalanceCheckpointCount(account.clone());
+    let count: u32 = e.storage().persistent().get(&count_key).unwrap_or(0);
+    let count = if count == 0 {
+        let prior: i128 = e
+            .storage()
+            .persistent()
+            .get(&DataKey::Balance(account.clone()))
+            .unwrap_or(0);
+        seed(e, DataKey::BalanceCheckpoint(account.clone(), 0), prior)
+    } else {
+        count
+    };
+
+    let next = push(
+        e,
+        count,
+        |i| DataKey::BalanceCheckpoint(account.clone(), i),
+        new_balance,
+    );
+    if next != count {
+        e.storage().persistent().set(&count_key, &next);
+    }
+}
+
+/// Record `new_supply` at the current ledger.
+///
+/// Must be called *before* `DataKey::TotalSupply` is overwritten.
+pub(crate) fn push_supply(e: &Env, new_supply: i128) {
+    let count: u32 = e
+        .storage()
+        .instance()
+        .get(&DataKey::SupplyCheckpointCount)
+        .unwrap_or(0);
+    let count = if count == 0 {
+        let prior: i128 = e
+            .storage()
+            .instance()
+            .get(&DataKey::TotalSupply)
+            .unwrap_or(0);
+        seed(e, DataKey::SupplyCheckpoint(0), prior)
+    } else {
+        count
+    };
+
+    let next = push(e, count, DataKey::SupplyCheckpoint, new_supply);
+    if next != count {
+        e.storage()
+            .instance()
+            .set(&DataKey::SupplyCheckpointCount, &next);
+    }
+}
+
+/// Balance of `account` at the end of `ledger`.
+///
+/// An account with no checkpoints has not changed since checkpointing began,
+/// so its current balance is returned.
+pub(crate) fn balance_at(e: &Env, account: &Address, ledger: u32) -> i128 {
+    let count: u32 = e
+        .storage()
+        .persistent()
+        .get(&DataKey::BalanceCheckpointCount(account.clone()))
+        .unwrap_or(0);
+    if count == 0 {
+        return e
+            .storage()
+            .persistent()
+            .get(&DataKey::Balance(account.clone()))
+            .unwrap_or(0);
+    }
+    lookup(
+        e,
+        count,
+        |i| DataKey::BalanceCheckpoint(account.clone(), i),
+        ledger,
+    )
+}
+
+/// Total supply at the end of `ledger`.
+pub(crate) fn supply_at(e: &Env, ledger: u32) -> i128 {
+    let count: u32 = e
+        .storage()
+        .instance()
+        .get(&DataKey::SupplyCheckpointCount)
+        .unwrap_or(0);
+    if count == 0 {
+        return e
+            .storage()
+            .instance()
+            .get(&DataKey::TotalSupply)
+            .unwrap_or(0);
+    }
+    lookup(e, count, DataKey::SupplyCheckpoint, ledger)
+}
+
+// ── Internal helpers ─────────────────────────────────────────────────────────
+
+/// Write the ledger-0 checkpoint for a value that predates checkpointing.
+/// Returns the new checkpoint count (0 if there was nothing to carry over).
+fn seed(e: &Env, key: DataKey, prior: i128) -> u32 {
+    if prior == 0 {
+        return 0;
+    }
+    e.storage().persistent().set(
+        &key,
+        &Checkpoint {
+            ledger: 0,
+            value: prior,
+        },
+    );
+    1
+}
+
+/// Append (or overwrite, for a same-ledger change) the latest checkpoint.
+/// Returns the checkpoint count after the write.
+fn push<F: Fn(u32) -> DataKey>(e: &Env, count: u32, key_at: F, value: i128) -> u32 {
+    let ledger = e.ledger().sequence();
+    if count > 0 {
+        let last: Checkpoint = e.storage().persistent().get(&key_at(count - 1)).unwrap();
+        if last.ledger == ledger {
+            e.storage()
+                .persistent()
+                .set(&key_at(count - 1), &Checkpoint { ledger, value });
+            return count;
+        }
+    }
+    e.storage()
+        .persistent()
+        .set(&key_at(count), &Checkpoint { ledger, value });
+    count + 1
+}
+
+/// Binary search for the last checkpoint at or before `ledger`.
+/// Returns 0 when every checkpoint is later than `ledger`.
+fn lookup<F: Fn(u32) -> DataKey>(e: &Env, count: u32, key_at: F, ledger: u32) -> i128 {
+    let mut low: u32 = 0;
+    let mut high: u32 = count;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let cp: Checkpoint = e.storage().persistent().get(&key_at(mid)).unwrap();
+        if cp.ledger > ledger {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    if low == 0 {
+        return 0;
+    }
+    let cp: Checkpoint = e.storage().persistent().get(&key_at(low - 1)).unwrap();
+    cp.value
+}
@@ -7,7 +7,7 @@
 extern crate std;
 
 use crate::TycoonToken;
-use soroban_sdk::{testutils::Address as _, Env};
+use soroban_sdk::{testutils::Address as _, Env, String};
 
 const SUPPLY: i128 = 1_000_000_000_000_000_000_000_000_000;
 
@@ -24,7 +24,13 @@ fn legacy_mint_always_panics() {
     let client = crate::TycoonTokenClient::new(&e, &contract_id);
     let admin = soroban_sdk::Address::generate(&e);
     let user = soroban_sdk::Address::generate(&e);
-    client.initialize(&admin, &SUPPLY);
+    client.initialize(
+        &admin,
+        &SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
     client.legacy_mint(&user, &1_000);
 }
 
@@ -36,7 +42,13 @@ fn legacy_mint_does_not_change_supply() {
     let client = crate::TycoonTokenClient::new(&e, &contract_id);
     let admin = soroban_sdk::Address::generate(&e);
     let user = soroban_sdk::Address::generate(&e);
-    client.initialize(&admin, &SUPPLY);
+    client.initialize(
+        &admin,
+        &SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     let supply_before = client.total_supply();
     let balance_before = client.balance(&user);
@@ -58,7 +70,13 @@ fn canonical_mint_still_works_after_legacy_mint_attempt() {
     let client = crate::TycoonTokenClient::new(&e, &contract_id);
     let admin = soroban_sdk::Address::generate(&e);
     let user = soroban_sdk::Address::generate(&e);
-    client.initialize(&admin, &SUPPLY);
+    client.initialize(
+        &admin,
+        &SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
         client.legacy_mint(&user, &1_000);
@@ -82,7 +100,13 @@ fn legacy_burn_always_panics() {
     let contract_id = e.register(TycoonToken, ());
     let client = crate::TycoonTokenClient::new(&e, &contract_id);
     let admin = soroban_sdk::Address::generate(&e);
-    client.initialize(&admin, &SUPPLY);
+    client.initialize(
+        &admin,
+        &SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
     client.legacy_burn(&admin, &1_000);
 }
 
@@ -93,7 +117,13 @@ fn legacy_burn_does_not_change_supply() {
     let contract_id = e.register(TycoonToken, ());
     let client = crate::TycoonTokenClient::new(&e, &contract_id);
     let admin = soroban_sdk::Address::generate(&e);
-    client.initialize(&admin, &SUPPLY);
+    client.initialize(
+        &admin,
+        &SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     let supply_before = client.total_supply();
     let balance_before = client.balance(&admin);
@@ -114,7 +144,13 @@ fn canonical_burn_still_works_after_legacy_burn_attempt() {
     let contract_id = e.register(TycoonToken, ());
     let client = crate::TycoonTokenClient::new(&e, &contract_id);
     let admin = soroban_sdk::Address::generate(&e);
-    client.initialize(&admin, &SUPPLY);
+    client.initialize(
+        &admin,
+        &SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
         client.legacy_burn(&admin, &1_000);
@@ -139,7 +175,13 @@ fn legacy_transfer_always_panics() {
     let client = crate::TycoonTokenClient::new(&e, &contract_id);
     let admin = soroban_sdk::Address::generate(&e);
     let user = soroban_sdk::Address::generate(&e);
-    client.initialize(&admin, &SUPPLY);
+    client.initialize(
+        &admin,
+        &SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
     client.legacy_transfer(&admin, &user, &1_000);
 }
 
@@ -151,7 +193,13 @@ fn legacy_transfer_does_not_move_tokens() {
     let client = crate::TycoonTokenClient::new(&e, &contract_id);
     let admin = soroban_sdk::Address::generate(&e);
     let user = soroban_sdk::Address::generate(&e);
-    client.initialize(&admin, &SUPPLY);
+    client.initialize(
+        &admin,
+        &SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     let admin_balance_before = client.balance(&admin);
     let user_balance_before = client.balance(&user);
@@ -173,7 +221,13 @@ fn canonical_transfer_still_works_after_legacy_transfer_attempt() {
     let client = crate::TycoonTokenClient::new(&e, &contract_id);
     let admin = soroban_sdk::Address::generate(&e);
     let user = soroban_sdk::Address::generate(&e);
-    client.initialize(&admin, &SUPPLY);
+    client.initialize(
+        &admin,
+        &SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
         client.legacy_transfer(&admin, &user, &1_000);
@@ -198,7 +252,13 @@ fn multiple_legacy_mint_attempts_all_fail() {
     let client = crate::TycoonTokenClient::new(&e, &contract_id);
     let admin = soroban_sdk::Address::generate(&e);
     let user = soroban_sdk::Address::generate(&e);
-    client.initialize(&admin, &SUPPLY);
+    client.initialize(
+        &admin,
+        &SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     let supply_before = client.total_supply();
     let balance_before = client.balance(&user);
@@ -223,7 +283,13 @@ fn legacy_burn_with_zero_amount_panics() {
     let contract_id = e.register(TycoonToken, ());
     let client = crate::TycoonTokenClient::new(&e, &contract_id);
     let admin = soroban_sdk::Address::generate(&e);
-    client.initialize(&admin, &SUPPLY);
+    client.initialize(
+        &admin,
+        &SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
     client.legacy_burn(&admin, &0);
 }
 
@@ -236,7 +302,13 @@ fn legacy_transfer_large_amount_does_not_move_tokens() {
     let client = crate::TycoonTokenClient::new(&e, &contract_id);
     let admin = soroban_sdk::Address::generate(&e);
     let user = soroban_sdk::Address::generate(&e);
-    client.initialize(&admin, &SUPPLY);
+    client.initialize(
+        &admin,
+        &SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     let large_amount: i128 = 999_000_000_000_000_000_000_000_000;
     let admin_balance_before = client.balance(&admin);
@@ -261,7 +333,13 @@ fn interleaved_legacy_and_canonical_operations() {
     let admin = soroban_sdk::Address::generate(&e);
     let user_a = soroban_sdk::Address::generate(&e);
     let user_b = soroban_sdk::Address::generate(&e);
-    client.initialize(&admin, &SUPPLY);
+    client.initialize(
+        &admin,
+        &SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     // Canonical mint
     let mint_amount: i128 = 100_000_000_000_000_000_000;
@@ -311,7 +389,13 @@ fn legacy_operations_do_not_affect_allowances() {
     let client = crate::TycoonTokenClient::new(&e, &contract_id);
     let admin = soroban_sdk::Address::generate(&e);
     let spender = soroban_sdk::Address::generate(&e);
-    client.initialize(&admin, &SUPPLY);
+    client.initialize(
+        &admin,
+        &SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     // Set an allowance
     let allowance: i128 = 1_000_000_000_000_000_000;
@@ -36,7 +36,13 @@ fn setup() -> (Env, TycoonTokenClient<'static>, Address) {
     let id = e.register(TycoonToken, ());
     let client = TycoonTokenClient::new(&e, &id);
     let admin = Address::generate(&e);
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
     (e, client, admin)
 }
 
@@ -157,7 +163,13 @@ fn test_set_admin_unauthorized() {
 
     // Initialize with mocked auth for this one call only.
     e.mock_all_auths();
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     // Attempt set_admin without admin auth — should panic.
     // (mock_all_auths is still active here, but the contract checks
@@ -168,7 +180,13 @@ fn test_set_admin_unauthorized() {
     let client2 = TycoonTokenClient::new(&e2, &id2);
     let admin2 = Address::generate(&e2);
     e2.mock_all_auths();
-    client2.initialize(&admin2, &INITIAL_SUPPLY);
+    client2.initialize(
+        &admin2,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e2, "Tycoon"),
+        &String::from_str(&e2, "TYC"),
+        &18,
+    );
 
     // Call set_admin mocking only attacker's auth — admin2.require_auth() will fail.
     e2.mock_auths(&[soroban_sdk::testutils::MockAuth {
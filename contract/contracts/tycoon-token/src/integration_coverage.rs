@@ -19,7 +19,7 @@ mod tests {
     use crate::{TycoonToken, TycoonTokenClient};
     use soroban_sdk::{
         testutils::{Address as _, Ledger, LedgerInfo},
-        Address, Env,
+        Address, Env, String,
     };
 
     const SUPPLY: i128 = 1_000_000_000_000_000_000_000_000_000;
@@ -30,7 +30,13 @@ mod tests {
         let id = e.register(TycoonToken, ());
         let client = TycoonTokenClient::new(&e, &id);
         let admin = Address::generate(&e);
-        client.initialize(&admin, &SUPPLY);
+        client.initialize(
+            &admin,
+            &SUPPLY,
+            &String::from_str(&e, "Tycoon"),
+            &String::from_str(&e, "TYC"),
+            &18,
+        );
         (e, client, admin)
     }
 
@@ -41,7 +41,13 @@ fn setup() -> (Env, TycoonTokenClient<'static>, Address) {
     let contract_id = e.register(TycoonToken, ());
     let client = TycoonTokenClient::new(&e, &contract_id);
     let admin = Address::generate(&e);
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
     (e, client, admin)
 }
 
@@ -386,7 +392,13 @@ fn test_inv_15_non_admin_cannot_mint() {
 
     // Initialize with real auth mocked only for this call
     e.mock_all_auths();
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     // Now drop mock_all_auths and attempt mint as attacker — should panic
     let e2 = Env::default();
@@ -394,7 +406,13 @@ fn test_inv_15_non_admin_cannot_mint() {
     let client2 = TycoonTokenClient::new(&e2, &contract_id2);
     let admin2 = Address::generate(&e2);
     e2.mock_all_auths();
-    client2.initialize(&admin2, &INITIAL_SUPPLY);
+    client2.initialize(
+        &admin2,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e2, "Tycoon"),
+        &String::from_str(&e2, "TYC"),
+        &18,
+    );
 
     // Attempt mint without mocking auth for admin — attacker has no admin rights
     // This should panic because admin.require_auth() will fail
@@ -441,7 +459,13 @@ fn test_inv_16b_initialize_emits_mint_event() {
     let client = TycoonTokenClient::new(&e, &contract_id);
     let admin = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     let events = e.events().all();
     assert!(
@@ -1,5 +1,35 @@
 #![no_std]
-use soroban_sdk::{contract, contractevent, contractimpl, contracttype, Address, Env, String};
+mod checkpoints;
+mod minters;
+mod tax;
+
+pub use checkpoints::Checkpoint;
+pub use minters::MinterQuota;
+use soroban_sdk::{
+    address_payload::AddressPayload, contract, contractevent, contractimpl, contracttype,
+    symbol_short, vec, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Vec,
+};
+pub use tax::{TransferTax, MAX_TRANSFER_TAX_BPS};
+
+/// Maximum recipients per `batch_transfer` / `airdrop_mint` call.
+///
+/// A recipient credited in a new ledger costs up to 3 write entries (balance,
+/// checkpoint, checkpoint count). With a transfer tax the treasury adds 3 more
+/// and the supply checkpoint 2, so 13 recipients plus the sender's 3 entries,
+/// instance storage and the auth nonce stay within the 50-entry
+/// per-transaction write limit.
+pub const MAX_BATCH_SIZE: u32 = 13;
+
+/// Current storage schema version written by `initialize` / `migrate`.
+///
+/// - v1: original deployment (hard-coded name, symbol and decimals)
+/// - v2: `TokenMetadata` stored in instance storage
+pub const STATE_VERSION: u32 = 2;
+
+/// Upper bounds for `TokenMetadata` validation.
+pub const MAX_NAME_LEN: u32 = 32;
+pub const MAX_SYMBOL_LEN: u32 = 12;
+pub const MAX_DECIMALS: u32 = 18;
 
 // SW-CON-TOKEN-001: allowance entry stores amount + expiration together so
 // transfer_from / burn_from can enforce the ledger-based expiry.
@@ -10,6 +40,33 @@ pub struct AllowanceValue {
     pub expiration_ledger: u32,
 }
 
+/// Message signed by the owner for `permit`.
+///
+/// The ed25519 signature covers `sha256(PermitPayload.to_xdr())`. `domain`,
+/// `network_id` and `contract` bind the signature to this token deployment so
+/// it cannot be replayed on another network or another token contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PermitPayload {
+    pub domain: Symbol,
+    pub network_id: BytesN<32>,
+    pub contract: Address,
+    pub owner: Address,
+    pub spender: Address,
+    pub amount: i128,
+    pub expiration_ledger: u32,
+    pub nonce: u64,
+}
+
+/// Name, symbol and decimals, set once at `initialize`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenMetadata {
+    pub decimals: u32,
+    pub name: String,
+    pub symbol: String,
+}
+
 #[contractevent(data_format = "single-value")]
 pub struct MintEvent {
     #[topic]
@@ -43,6 +100,56 @@ pub struct ApproveEvent {
     pub expiration_ledger: u32,
 }
 
+/// Transfer tax paid by `from`: `burned` left the supply and `to_treasury`
+/// was credited to `treasury`.
+#[contractevent]
+pub struct TransferTaxEvent {
+    #[topic]
+    pub from: Address,
+    #[topic]
+    pub treasury: Address,
+    pub burned: i128,
+    pub to_treasury: i128,
+}
+
+#[contractevent]
+pub struct TransferTaxSetEvent {
+    pub burn_bps: u32,
+    pub treasury_bps: u32,
+    pub treasury: Address,
+}
+
+#[contractevent(data_format = "single-value")]
+pub struct TaxExemptEvent {
+    #[topic]
+    pub account: Address,
+    pub exempt: bool,
+}
+
+#[contractevent]
+pub struct MinterSetEvent {
+    #[topic]
+    pub minter: Address,
+    pub quota: i128,
+    pub period_ledgers: u32,
+}
+
+#[contractevent]
+pub struct MinterRemovedEvent {
+    #[topic]
+    pub minter: Address,
+}
+
+/// Emitted when a registered minter mints; `remaining` is the quota left in
+/// the current window.
+#[contractevent]
+pub struct MintQuotaUsedEvent {
+    #[topic]
+    pub minter: Address,
+    pub amount: i128,
+    pub remaining: i128,
+}
+
 #[contractevent]
 pub struct SetAdminEvent {
     #[topic]
@@ -59,6 +166,26 @@ pub enum DataKey {
     Allowance(Address, Address),
     TotalSupply,
     Initialized,
+    /// (account, index) -> Checkpoint; see `checkpoints`.
+    BalanceCheckpoint(Address, u32),
+    /// account -> number of balance checkpoints written.
+    BalanceCheckpointCount(Address),
+    /// index -> Checkpoint for `TotalSupply`.
+    SupplyCheckpoint(u32),
+    /// Number of supply checkpoints written (instance storage).
+    SupplyCheckpointCount,
+    /// owner -> next expected `permit` nonce.
+    Nonce(Address),
+    /// `TokenMetadata` (instance storage).
+    Metadata,
+    /// u32 version of the state schema; see `STATE_VERSION`.
+    StateVersion,
+    /// `TransferTax` (instance storage); absent when no tax is charged.
+    TransferTax,
+    /// account -> true if transfers to or from it are not taxed.
+    TaxExempt(Address),
+    /// minter -> `MinterQuota`; see `minters`.
+    Minter(Address),
 }
 
 // ---------------------------------------------------------------------------
@@ -76,26 +203,178 @@ fn require_admin(e: &Env) -> Address {
     admin
 }
 
+/// Metadata of deployments that predate `TokenMetadata` (state version 1).
+fn legacy_metadata(e: &Env) -> TokenMetadata {
+    TokenMetadata {
+        decimals: 18,
+        name: String::from_str(e, "Tycoon"),
+        symbol: String::from_str(e, "TYC"),
+    }
+}
+
+/// Stored metadata, falling back to the v1 values until `migrate` has run.
+fn read_metadata(e: &Env) -> TokenMetadata {
+    e.storage()
+        .instance()
+        .get(&DataKey::Metadata)
+        .unwrap_or_else(|| legacy_metadata(e))
+}
+
+/// Reject empty batches and batches over `MAX_BATCH_SIZE`.
+fn check_batch_size(recipients: &Vec<(Address, i128)>) {
+    if recipients.is_empty() {
+        panic!("Batch is empty");
+    }
+    if recipients.len() > MAX_BATCH_SIZE {
+        panic!("Batch too large");
+    }
+}
+
+/// Write `amount` as the balance of `id`, recording a checkpoint first.
+///
+/// All balance mutations go through here so `balance_at` never misses a change.
+fn write_balance(e: &Env, id: &Address, amount: i128) {
+    checkpoints::push_balance(e, id, amount);
+    e.storage()
+        .persistent()
+        .set(&DataKey::Balance(id.clone()), &amount);
+}
+
+/// Write `amount` as the total supply, recording a checkpoint first.
+fn write_supply(e: &Env, amount: i128) {
+    checkpoints::push_supply(e, amount);
+    e.storage().instance().set(&DataKey::TotalSupply, &amount);
+}
+
+fn read_balance(e: &Env, id: &Address) -> i128 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Balance(id.clone()))
+        .unwrap_or(0)
+}
+
+/// Add `amount` to the balance of `id`.
+fn credit_balance(e: &Env, id: &Address, amount: i128) {
+    let balance = read_balance(e, id);
+    write_balance(
+        e,
+        id,
+        balance.checked_add(amount).expect("Balance overflow"),
+    );
+}
+
+/// Credit `to` with newly minted `amount` and raise the supply to match.
+fn mint_to(e: &Env, to: &Address, amount: i128) {
+    credit_balance(e, to, amount);
+    let supply: i128 = e.storage().instance().get(&DataKey::TotalSupply).unwrap();
+    write_supply(e, supply.checked_add(amount).expect("Supply overflow"));
+    MintEvent {
+        to: to.clone(),
+        amount,
+    }
+    .publish(e);
+}
+
+/// Debit `amount` from `from` and credit `to` with it minus any transfer tax.
+/// Returns the net amount `to` received.
+fn move_balance(e: &Env, from: &Address, to: &Address, amount: i128) -> i128 {
+    let from_balance = read_balance(e, from);
+    if from_balance < amount {
+        panic!("Insufficient balance");
+    }
+    write_balance(e, from, from_balance - amount);
+
+    let config = tax::read_config(e);
+    let assessed = tax::assess(e, &config, from, to, amount);
+    credit_balance(e, to, assessed.net);
+    TransferEvent {
+        from: from.clone(),
+        to: to.clone(),
+        amount: assessed.net,
+    }
+    .publish(e);
+
+    if let Some(config) = config {
+        settle_tax(e, from, &config, assessed.burned, assessed.to_treasury);
+    }
+    assessed.net
+}
+
+/// Burn `burned` and credit `to_treasury` to the treasury for tax already
+/// debited from `from`. No-op when both are zero.
+fn settle_tax(e: &Env, from: &Address, config: &TransferTax, burned: i128, to_treasury: i128) {
+    if burned == 0 && to_treasury == 0 {
+        return;
+    }
+    if to_treasury > 0 {
+        credit_balance(e, &config.treasury, to_treasury);
+    }
+    if burned > 0 {
+        let supply: i128 = e.storage().instance().get(&DataKey::TotalSupply).unwrap();
+        write_supply(e, supply.checked_sub(burned).expect("Supply underflow"));
+    }
+    TransferTaxEvent {
+        from: from.clone(),
+        treasury: config.treasury.clone(),
+        burned,
+        to_treasury,
+    }
+    .publish(e);
+}
+
 #[contract]
 pub struct TycoonToken;
 
 #[contractimpl]
 impl TycoonToken {
-    pub fn initialize(e: Env, admin: Address, initial_supply: i128) {
+    /// Initialize the token, minting `initial_supply` to `admin`.
+    ///
+    /// `name`, `symbol` and `decimals` are fixed for the lifetime of the
+    /// deployment, so the same WASM can back TYC, test tokens and mocks.
+    ///
+    /// # Errors
+    /// - Panics with `"Already initialized"` if called more than once.
+    /// - Panics with `"Initial supply cannot be negative"` if `initial_supply < 0`.
+    /// - Panics with `"Name must be 1-32 characters"`, `"Symbol must be 1-12 characters"`
+    ///   or `"Decimals must be at most 18"` for invalid metadata.
+    pub fn initialize(
+        e: Env,
+        admin: Address,
+        initial_supply: i128,
+        name: String,
+        symbol: String,
+        decimals: u32,
+    ) {
         if e.storage().instance().has(&DataKey::Initialized) {
             panic!("Already initialized");
         }
         if initial_supply < 0 {
             panic!("Initial supply cannot be negative");
         }
+        if !(1..=MAX_NAME_LEN).contains(&name.len()) {
+            panic!("Name must be 1-32 characters");
+        }
+        if !(1..=MAX_SYMBOL_LEN).contains(&symbol.len()) {
+            panic!("Symbol must be 1-12 characters");
+        }
+        if decimals > MAX_DECIMALS {
+            panic!("Decimals must be at most 18");
+        }
         e.storage().instance().set(&DataKey::Initialized, &true);
         e.storage().instance().set(&DataKey::Admin, &admin);
+        e.storage().instance().set(
+            &DataKey::Metadata,
+            &TokenMetadata {
+                decimals,
+                name,
+                symbol,
+            },
+        );
         e.storage()
             .instance()
-            .set(&DataKey::TotalSupply, &initial_supply);
-        e.storage()
-            .persistent()
-            .set(&DataKey::Balance(admin.clone()), &initial_supply);
+            .set(&DataKey::StateVersion, &STATE_VERSION);
+        write_supply(&e, initial_supply);
+        write_balance(&e, &admin, initial_supply);
         MintEvent {
             to: admin,
             amount: initial_supply,
@@ -103,30 +382,104 @@ impl TycoonToken {
         .publish(&e);
     }
 
+    /// Mint `amount` to `to` (admin only). The admin has no quota.
+    ///
+    /// # Errors
+    /// - Panics with `"Amount must be positive"` if `amount <= 0`.
     pub fn mint(e: Env, to: Address, amount: i128) {
         require_admin(&e);
 
         if amount <= 0 {
             panic!("Amount must be positive");
         }
+        mint_to(&e, &to, amount);
+    }
 
-        let balance: i128 = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Balance(to.clone()))
-            .unwrap_or(0);
-        let new_balance = balance.checked_add(amount).expect("Balance overflow");
-        e.storage()
-            .persistent()
-            .set(&DataKey::Balance(to.clone()), &new_balance);
+    /// Mint `amount` to `to` as the registered minter `minter`, charged
+    /// against its quota for the current window.
+    ///
+    /// # Errors
+    /// - Panics with `"Amount must be positive"` if `amount <= 0`.
+    /// - Panics with `"Not an authorized minter"` if `minter` is not registered.
+    /// - Panics with `"Mint quota exceeded"` if the mint would exceed the
+    ///   minter's quota for the window.
+    pub fn mint_as(e: Env, minter: Address, to: Address, amount: i128) {
+        minter.require_auth();
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+
+        let remaining = minters::consume(&e, &minter, amount);
+        MintQuotaUsedEvent {
+            minter,
+            amount,
+            remaining,
+        }
+        .publish(&e);
+        mint_to(&e, &to, amount);
+    }
+
+    /// Mint to up to `MAX_BATCH_SIZE` recipients in one call (admin only).
+    ///
+    /// Emits one `MintEvent` per recipient. Any invalid entry reverts the
+    /// whole batch.
+    ///
+    /// # Errors
+    /// - Panics with `"Batch is empty"` / `"Batch too large"` on a bad recipient count.
+    /// - Panics with `"Amount must be positive"` if any amount is `<= 0`.
+    pub fn airdrop_mint(e: Env, recipients: Vec<(Address, i128)>) {
+        require_admin(&e);
+        check_batch_size(&recipients);
+
+        let mut total: i128 = 0;
+        for (_, amount) in recipients.iter() {
+            if amount <= 0 {
+                panic!("Amount must be positive");
+            }
+            total = total.checked_add(amount).expect("Supply overflow");
+        }
 
         let supply: i128 = e.storage().instance().get(&DataKey::TotalSupply).unwrap();
-        e.storage().instance().set(
-            &DataKey::TotalSupply,
-            &supply.checked_add(amount).expect("Supply overflow"),
-        );
+        write_supply(&e, supply.checked_add(total).expect("Supply overflow"));
 
-        MintEvent { to, amount }.publish(&e);
+        for (to, amount) in recipients.iter() {
+            credit_balance(&e, &to, amount);
+            MintEvent { to, amount }.publish(&e);
+        }
+    }
+
+    /// Migrate the contract to `STATE_VERSION` (admin only).
+    ///
+    /// v1 → v2 stores the previously hard-coded "Tycoon" / "TYC" / 18 as
+    /// `TokenMetadata`. Safe to call multiple times; a current version is a no-op.
+    pub fn migrate(e: Env) {
+        require_admin(&e);
+
+        let current_version: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::StateVersion)
+            .unwrap_or(1);
+
+        if current_version < 2 {
+            if !e.storage().instance().has(&DataKey::Metadata) {
+                e.storage()
+                    .instance()
+                    .set(&DataKey::Metadata, &legacy_metadata(&e));
+            }
+            e.storage()
+                .instance()
+                .set(&DataKey::StateVersion, &STATE_VERSION);
+        }
+    }
+
+    /// Current storage schema version (1 for deployments that predate versioning).
+    pub fn state_version(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::StateVersion)
+            .unwrap_or(1)
     }
 
     pub fn set_admin(e: Env, new_admin: Address) {
@@ -139,6 +492,88 @@ impl TycoonToken {
         .publish(&e);
     }
 
+    /// Authorize `minter` to mint up to `quota` per `period_ledgers` ledgers,
+    /// or update an existing minter's limits (admin only). Amounts already
+    /// minted in the open window still count against the new quota.
+    ///
+    /// # Errors
+    /// - Panics with `"Quota must be positive"` / `"Period must be positive"`.
+    pub fn set_minter(e: Env, minter: Address, quota: i128, period_ledgers: u32) {
+        require_admin(&e);
+        minters::set(&e, &minter, quota, period_ledgers);
+        MinterSetEvent {
+            minter,
+            quota,
+            period_ledgers,
+        }
+        .publish(&e);
+    }
+
+    /// Revoke `minter` (admin only).
+    ///
+    /// # Errors
+    /// - Panics with `"Not a minter"` if `minter` is not registered.
+    pub fn remove_minter(e: Env, minter: Address) {
+        require_admin(&e);
+        minters::remove(&e, &minter);
+        MinterRemovedEvent { minter }.publish(&e);
+    }
+
+    /// Quota configuration and current-window usage of `minter`, if registered.
+    pub fn minter_quota(e: Env, minter: Address) -> Option<MinterQuota> {
+        minters::read(&e, &minter)
+    }
+
+    /// Amount `minter` can still mint right now; 0 for unregistered addresses.
+    pub fn remaining_quota(e: Env, minter: Address) -> i128 {
+        minters::read(&e, &minter)
+            .map(|entry| entry.remaining_at(e.ledger().sequence()))
+            .unwrap_or(0)
+    }
+
+    /// Configure the transfer tax (admin only).
+    ///
+    /// `burn_bps` of each taxed transfer is burned and `treasury_bps` is
+    /// credited to `treasury`. Setting both to 0 disables the tax.
+    ///
+    /// # Errors
+    /// - Panics with `"Transfer tax exceeds cap"` if
+    ///   `burn_bps + treasury_bps > MAX_TRANSFER_TAX_BPS`.
+    pub fn set_transfer_tax(e: Env, burn_bps: u32, treasury_bps: u32, treasury: Address) {
+        require_admin(&e);
+        let config = TransferTax {
+            burn_bps,
+            treasury_bps,
+            treasury: treasury.clone(),
+        };
+        tax::write_config(&e, &config);
+        TransferTaxSetEvent {
+            burn_bps,
+            treasury_bps,
+            treasury,
+        }
+        .publish(&e);
+    }
+
+    /// Current transfer tax, or `None` if transfers are untaxed.
+    pub fn transfer_tax(e: Env) -> Option<TransferTax> {
+        tax::read_config(&e)
+    }
+
+    /// Add or remove `account` from the transfer-tax exemption list (admin only).
+    ///
+    /// Transfers are untaxed when either side is exempt; use this for the
+    /// game, reward and shop contracts.
+    pub fn set_tax_exempt(e: Env, account: Address, exempt: bool) {
+        require_admin(&e);
+        tax::set_exempt(&e, &account, exempt);
+        TaxExemptEvent { account, exempt }.publish(&e);
+    }
+
+    pub fn is_tax_exempt(e: Env, account: Address) -> bool {
+        tax::is_exempt(&e, &account)
+    }
+
     pub fn admin(e: Env) -> Address {
         e.storage().instance().get(&DataKey::Admin).unwrap()
     }
@@ -149,6 +584,31 @@ impl TycoonToken {
             .get(&DataKey::TotalSupply)
             .unwrap_or(0)
     }
+
+    /// Balance of `account` at the end of ledger `ledger`.
+    ///
+    /// Binary-searches the account's checkpoints. Queries for the current
+    /// ledger reflect every change made so far in it.
+    ///
+    /// # Errors
+    /// - Panics with `"Ledger is in the future"` if `ledger` is after the current ledger.
+    pub fn balance_at(e: Env, account: Address, ledger: u32) -> i128 {
+        if ledger > e.ledger().sequence() {
+            panic!("Ledger is in the future");
+        }
+        checkpoints::balance_at(&e, &account, ledger)
+    }
+
+    /// Total supply at the end of ledger `ledger`.
+    ///
+    /// # Errors
+    /// - Panics with `"Ledger is in the future"` if `ledger` is after the current ledger.
+    pub fn total_supply_at(e: Env, ledger: u32) -> i128 {
+        if ledger > e.ledger().sequence() {
+            panic!("Ledger is in the future");
+        }
+        checkpoints::supply_at(&e, ledger)
+    }
 }
 
 #[contractimpl]
@@ -191,6 +651,89 @@ impl TycoonToken {
         .publish(&e);
     }
 
+    /// Set an allowance from a signed message instead of the owner's auth.
+    ///
+    /// Lets a relayer pay the fees for players who hold TYC but no XLM. The
+    /// owner is the account whose master key is `owner_pubkey`; the signature
+    /// must be over `sha256(PermitPayload.to_xdr())` with the owner's current
+    /// `nonces` value. On success the nonce is consumed and the allowance is
+    /// set exactly as `approve` would, emitting the same `ApproveEvent`.
+    ///
+    /// Only master-key signatures are accepted: an account that has removed or
+    /// down-weighted its master key should use `approve` instead.
+    ///
+    /// # Errors
+    /// - Panics with `"Amount cannot be negative"` if `amount < 0`.
+    /// - Panics with `"Permit expired"` if `expiration_ledger` is non-zero and
+    ///   before the current ledger. `0` means the allowance never expires, as
+    ///   in `approve`.
+    /// - Panics with `"Invalid nonce"` if `nonce` is not the owner's next nonce.
+    /// - Traps if `signature` does not verify.
+    pub fn permit(
+        e: Env,
+        owner_pubkey: BytesN<32>,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+        nonce: u64,
+        signature: BytesN<64>,
+    ) {
+        if amount < 0 {
+            panic!("Amount cannot be negative");
+        }
+        if expiration_ledger != 0 && expiration_ledger < e.ledger().sequence() {
+            panic!("Permit expired");
+        }
+
+        let owner = Address::from_payload(
+            &e,
+            AddressPayload::AccountIdPublicKeyEd25519(owner_pubkey.clone()),
+        );
+        let nonce_key = DataKey::Nonce(owner.clone());
+        let expected: u64 = e.storage().persistent().get(&nonce_key).unwrap_or(0);
+        if nonce != expected {
+            panic!("Invalid nonce");
+        }
+
+        let payload = PermitPayload {
+            domain: symbol_short!("TYCPERMIT"),
+            network_id: e.ledger().network_id(),
+            contract: e.current_contract_address(),
+            owner: owner.clone(),
+            spender: spender.clone(),
+            amount,
+            expiration_ledger,
+            nonce,
+        };
+        let digest = e.crypto().sha256(&payload.to_xdr(&e));
+        e.crypto()
+            .ed25519_verify(&owner_pubkey, &digest.into(), &signature);
+
+        e.storage().persistent().set(&nonce_key, &(expected + 1));
+        e.storage().persistent().set(
+            &DataKey::Allowance(owner.clone(), spender.clone()),
+            &AllowanceValue {
+                amount,
+                expiration_ledger,
+            },
+        );
+        ApproveEvent {
+            from: owner,
+            spender,
+            amount,
+            expiration_ledger,
+        }
+        .publish(&e);
+    }
+
+    /// Next nonce `owner` must sign for `permit`. Starts at 0.
+    pub fn nonces(e: Env, owner: Address) -> u64 {
+        e.storage()
+            .persistent()
+            .get(&DataKey::Nonce(owner))
+            .unwrap_or(0)
+    }
+
     pub fn balance(e: Env, id: Address) -> i128 {
         e.storage()
             .persistent()
@@ -198,6 +741,11 @@ impl TycoonToken {
             .unwrap_or(0)
     }
 
+    /// Transfer `amount` from `from` to `to`.
+    ///
+    /// If a transfer tax is configured and neither side is exempt, `to`
+    /// receives `amount` minus the tax; the `TransferEvent` carries the net
+    /// amount and a `TransferTaxEvent` carries the burned and treasury shares.
     pub fn transfer(e: Env, from: Address, to: Address, amount: i128) {
         from.require_auth();
         if amount < 0 {
@@ -206,30 +754,103 @@ impl TycoonToken {
         if amount == 0 {
             return;
         }
+        move_balance(&e, &from, &to, amount);
+    }
 
-        let from_balance: i128 = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Balance(from.clone()))
-            .unwrap_or(0);
-        if from_balance < amount {
-            panic!("Insufficient balance");
+    /// Transfer `amount` to the contract `to_contract` and notify it in the
+    /// same transaction by calling `on_token_received(from, amount, payload)`.
+    ///
+    /// The hook runs after balances are updated, so the receiver already holds
+    /// the tokens when it executes. `amount` passed to the hook is the net
+    /// amount received after any transfer tax. It must not call back into this token
+    /// (Soroban rejects contract re-entry). If the hook returns `false` or
+    /// panics, the whole transfer reverts.
+    ///
+    /// # Errors
+    /// - Panics with `"Amount must be positive"` if `amount <= 0`.
+    /// - Panics with `"Insufficient balance"` if `from` cannot cover `amount`.
+    /// - Panics with `"Receiver rejected transfer"` if the hook returns `false`.
+    pub fn transfer_and_call(
+        e: Env,
+        from: Address,
+        to_contract: Address,
+        amount: i128,
+        payload: Bytes,
+    ) {
+        from.require_auth();
+        if amount <= 0 {
+            panic!("Amount must be positive");
         }
-        e.storage()
-            .persistent()
-            .set(&DataKey::Balance(from.clone()), &(from_balance - amount));
+        let received = move_balance(&e, &from, &to_contract, amount);
 
-        let to_balance: i128 = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Balance(to.clone()))
-            .unwrap_or(0);
-        e.storage().persistent().set(
-            &DataKey::Balance(to.clone()),
-            &to_balance.checked_add(amount).expect("Balance overflow"),
+        let accepted: bool = e.invoke_contract(
+            &to_contract,
+            &Symbol::new(&e, "on_token_received"),
+            vec![
+                &e,
+                from.into_val(&e),
+                received.into_val(&e),
+                payload.into_val(&e),
+            ],
         );
+        if !accepted {
+            panic!("Receiver rejected transfer");
+        }
+    }
+
+    /// Transfer from `from` to up to `MAX_BATCH_SIZE` recipients with a
+    /// single authorization.
+    ///
+    /// The combined amount is checked against `from`'s balance before anything
+    /// is written, and any invalid entry reverts the whole batch. Emits one
+    /// `TransferEvent` per recipient so indexers see the same stream as
+    /// individual `transfer` calls. Zero amounts are skipped. Transfer tax is
+    /// assessed per recipient and settled once, with a single
+    /// `TransferTaxEvent` for the batch.
+    ///
+    /// # Errors
+    /// - Panics with `"Batch is empty"` / `"Batch too large"` on a bad recipient count.
+    /// - Panics with `"Amount cannot be negative"` if any amount is `< 0`.
+    /// - Panics with `"Insufficient balance"` if `from` cannot cover the total.
+    pub fn batch_transfer(e: Env, from: Address, recipients: Vec<(Address, i128)>) {
+        from.require_auth();
+        check_batch_size(&recipients);
+
+        let mut total: i128 = 0;
+        for (_, amount) in recipients.iter() {
+            if amount < 0 {
+                panic!("Amount cannot be negative");
+            }
+            total = total.checked_add(amount).expect("Balance overflow");
+        }
 
-        TransferEvent { from, to, amount }.publish(&e);
+        let from_balance = read_balance(&e, &from);
+        if from_balance < total {
+            panic!("Insufficient balance");
+        }
+        write_balance(&e, &from, from_balance - total);
+
+        let config = tax::read_config(&e);
+        let mut burned: i128 = 0;
+        let mut to_treasury: i128 = 0;
+        for (to, amount) in recipients.iter() {
+            if amount == 0 {
+                continue;
+            }
+            let assessed = tax::assess(&e, &config, &from, &to, amount);
+            burned += assessed.burned;
+            to_treasury += assessed.to_treasury;
+            credit_balance(&e, &to, assessed.net);
+            TransferEvent {
+                from: from.clone(),
+                to,
+                amount: assessed.net,
+            }
+            .publish(&e);
+        }
+        if let Some(config) = config {
+            settle_tax(&e, &from, &config, burned, to_treasury);
+        }
     }
 
     pub fn transfer_from(e: Env, spender: Address, from: Address, to: Address, amount: i128) {
@@ -263,29 +884,7 @@ impl TycoonToken {
             },
         );
 
-        let from_balance: i128 = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Balance(from.clone()))
-            .unwrap_or(0);
-        if from_balance < amount {
-            panic!("Insufficient balance");
-        }
-        e.storage()
-            .persistent()
-            .set(&DataKey::Balance(from.clone()), &(from_balance - amount));
-
-        let to_balance: i128 = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Balance(to.clone()))
-            .unwrap_or(0);
-        e.storage().persistent().set(
-            &DataKey::Balance(to.clone()),
-            &to_balance.checked_add(amount).expect("Balance overflow"),
-        );
-
-        TransferEvent { from, to, amount }.publish(&e);
+        move_balance(&e, &from, &to, amount);
     }
 
     pub fn burn(e: Env, from: Address, amount: i128) {
@@ -302,15 +901,10 @@ impl TycoonToken {
         if balance < amount {
             panic!("Insufficient balance");
         }
-        e.storage()
-            .persistent()
-            .set(&DataKey::Balance(from.clone()), &(balance - amount));
+        write_balance(&e, &from, balance - amount);
 
         let supply: i128 = e.storage().instance().get(&DataKey::TotalSupply).unwrap();
-        e.storage().instance().set(
-            &DataKey::TotalSupply,
-            &supply.checked_sub(amount).expect("Supply underflow"),
-        );
+        write_supply(&e, supply.checked_sub(amount).expect("Supply underflow"));
 
         BurnEvent { from, amount }.publish(&e);
     }
@@ -351,29 +945,24 @@ impl TycoonToken {
         if balance < amount {
             panic!("Insufficient balance");
         }
-        e.storage()
-            .persistent()
-            .set(&DataKey::Balance(from.clone()), &(balance - amount));
+        write_balance(&e, &from, balance - amount);
 
         let supply: i128 = e.storage().instance().get(&DataKey::TotalSupply).unwrap();
-        e.storage().instance().set(
-            &DataKey::TotalSupply,
-            &supply.checked_sub(amount).expect("Supply underflow"),
-        );
+        write_supply(&e, supply.checked_sub(amount).expect("Supply underflow"));
 
         BurnEvent { from, amount }.publish(&e);
     }
 
-    pub fn decimals(_e: Env) -> u32 {
-        18
+    pub fn decimals(e: Env) -> u32 {
+        read_metadata(&e).decimals
     }
 
     pub fn name(e: Env) -> String {
-        String::from_str(&e, "Tycoon")
+        read_metadata(&e).name
     }
 
     pub fn symbol(e: Env) -> String {
-        String::from_str(&e, "TYC")
+        read_metadata(&e).symbol
     }
 }
 
@@ -399,7 +988,8 @@ mod error_branch_tests;
 impl TycoonToken {
     /// Deprecated alias for `mint`.
     ///
-    /// Canonical replacement: `mint(e, to, amount)`
+    /// Canonical replacement: `mint(to, amount)`, or `mint_as(minter, to, amount)`
+    /// for registered minters
     pub fn legacy_mint(_e: Env, _to: Address, _amount: i128) {
         panic!("legacy_mint is deprecated; use mint instead");
     }
@@ -422,10 +1012,24 @@ impl TycoonToken {
 #[cfg(test)]
 mod access_control_tests;
 #[cfg(test)]
+mod batch_tests;
+#[cfg(test)]
+mod checkpoint_tests;
+#[cfg(test)]
 mod deprecation_tests;
 #[cfg(test)]
 mod integration_coverage;
 #[cfg(test)]
+mod metadata_tests;
+#[cfg(test)]
+mod minter_tests;
+#[cfg(test)]
+mod permit_tests;
+#[cfg(test)]
 mod security_review_tests;
 #[cfg(test)]
 mod simulation_scenarios;
+#[cfg(test)]
+mod tax_tests;
+#[cfg(test)]
+mod transfer_and_call_tests;
@@ -0,0 +1,233 @@
+/// # Tycoon Token (TYC) — Configurable Metadata & Migration Tests
+///
+/// | ID    | Behaviour |
+/// |-------|-----------|
+/// | MD-01 | `initialize` stores the given name, symbol and decimals |
+/// | MD-02 | Name and symbol lengths are validated |
+/// | MD-03 | Decimals above 18 are rejected |
+/// | MD-04 | A v1 deployment reports the legacy values before and after `migrate` |
+/// | MD-05 | `migrate` is idempotent and admin-only |
+use super::*;
+use soroban_sdk::testutils::Address as _;
+
+// ── helpers ──────────────────────────────────────────────────────────────────
+
+fn register(e: &Env) -> (TycoonTokenClient<'static>, Address) {
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    (client, admin)
+}
+
+/// Rewrite a freshly initialized instance into the v1 layout: no
+/// `Metadata`, no `StateVersion`.
+fn downgrade_to_v1(e: &Env, client: &TycoonTokenClient) {
+    e.as_contract(&client.address, || {
+        e.storage().instance().remove(&DataKey::Metadata);
+        e.storage().instance().remove(&DataKey::StateVersion);
+    });
+}
+
+// ── MD-01 ─────────────────────────────────────────────────────────────────────
+
+/// MD-01: the same WASM can back a USDC-style mock with different metadata.
+#[test]
+fn test_md_01_initialize_stores_metadata() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = register(&e);
+
+    client.initialize(
+        &admin,
+        &10_000_000_000_000,
+        &String::from_str(&e, "USD Coin (mock)"),
+        &String::from_str(&e, "USDC"),
+        &7,
+    );
+
+    assert_eq!(client.name(), String::from_str(&e, "USD Coin (mock)"));
+    assert_eq!(client.symbol(), String::from_str(&e, "USDC"));
+    assert_eq!(client.decimals(), 7);
+    assert_eq!(client.state_version(), STATE_VERSION);
+}
+
+/// MD-01b: zero decimals and maximum-length fields are accepted.
+#[test]
+fn test_md_01_boundary_metadata_accepted() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = register(&e);
+
+    let name = String::from_str(&e, "Tycoon Season Twelve Event Token"); // 32 chars
+    let symbol = String::from_str(&e, "TYCEVENT2026"); // 12 chars
+    client.initialize(&admin, &0, &name, &symbol, &0);
+
+    assert_eq!(client.name(), name);
+    assert_eq!(client.symbol(), symbol);
+    assert_eq!(client.decimals(), 0);
+}
+
+// ── MD-02 ─────────────────────────────────────────────────────────────────────
+
+/// MD-02a: an empty name is rejected.
+#[test]
+#[should_panic(expected = "Name must be 1-32 characters")]
+fn test_md_02_empty_name_rejected() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = register(&e);
+    client.initialize(
+        &admin,
+        &0,
+        &String::from_str(&e, ""),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
+}
+
+/// MD-02b: a 33-character name is rejected.
+#[test]
+#[should_panic(expected = "Name must be 1-32 characters")]
+fn test_md_02_long_name_rejected() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = register(&e);
+    client.initialize(
+        &admin,
+        &0,
+        &String::from_str(&e, "Tycoon Season Twelve Event Tokens"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
+}
+
+/// MD-02c: an empty symbol is rejected.
+#[test]
+#[should_panic(expected = "Symbol must be 1-12 characters")]
+fn test_md_02_empty_symbol_rejected() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = register(&e);
+    client.initialize(
+        &admin,
+        &0,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, ""),
+        &18,
+    );
+}
+
+/// MD-02d: a 13-character symbol is rejected.
+#[test]
+#[should_panic(expected = "Symbol must be 1-12 characters")]
+fn test_md_02_long_symbol_rejected() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = register(&e);
+    client.initialize(
+        &admin,
+        &0,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYCEVENT20261"),
+        &18,
+    );
+}
+
+// ── MD-03 ─────────────────────────────────────────────────────────────────────
+
+/// MD-03: decimals beyond 18 are rejected.
+#[test]
+#[should_panic(expected = "Decimals must be at most 18")]
+fn test_md_03_excess_decimals_rejected() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = register(&e);
+    client.initialize(
+        &admin,
+        &0,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &19,
+    );
+}
+
+// ── MD-04 ─────────────────────────────────────────────────────────────────────
+
+/// MD-04: a v1 instance keeps answering with the legacy values, and `migrate`
+/// persists them as `TokenMetadata`.
+#[test]
+fn test_md_04_migrate_v1_stores_legacy_metadata() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = register(&e);
+    client.initialize(
+        &admin,
+        &0,
+        &String::from_str(&e, "Other"),
+        &String::from_str(&e, "OTH"),
+        &6,
+    );
+    downgrade_to_v1(&e, &client);
+
+    assert_eq!(client.state_version(), 1);
+    assert_eq!(client.name(), String::from_str(&e, "Tycoon"));
+    assert_eq!(client.symbol(), String::from_str(&e, "TYC"));
+    assert_eq!(client.decimals(), 18);
+
+    client.migrate();
+
+    assert_eq!(client.state_version(), STATE_VERSION);
+    let stored: Option<TokenMetadata> = e.as_contract(&client.address, || {
+        e.storage().instance().get(&DataKey::Metadata)
+    });
+    assert_eq!(
+        stored,
+        Some(TokenMetadata {
+            decimals: 18,
+            name: String::from_str(&e, "Tycoon"),
+            symbol: String::from_str(&e, "TYC"),
+        })
+    );
+}
+
+// ── MD-05 ─────────────────────────────────────────────────────────────────────
+
+/// MD-05a: migrating a current instance changes nothing.
+#[test]
+fn test_md_05_migrate_is_idempotent() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin) = register(&e);
+    client.initialize(
+        &admin,
+        &0,
+        &String::from_str(&e, "Event Token"),
+        &String::from_str(&e, "EVT"),
+        &2,
+    );
+
+    client.migrate();
+    client.migrate();
+
+    assert_eq!(client.name(), String::from_str(&e, "Event Token"));
+    assert_eq!(client.decimals(), 2);
+    assert_eq!(client.state_version(), STATE_VERSION);
+}
+
+/// MD-05b: `migrate` requires the admin's signature.
+#[test]
+#[should_panic]
+fn test_md_05_migrate_requires_admin() {
+    let e = Env::default();
+    let (client, admin) = register(&e);
+    e.mock_all_auths();
+    client.initialize(
+        &admin,
+        &0,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
+    e.set_auths(&[]);
+    client.migrate();
+}
@@ -0,0 +1,273 @@
+/// # Tycoon Token (TYC) — Minter Registry Tests
+///
+/// | ID    | Behaviour |
+/// |-------|-----------|
+/// | MN-01 | A registered minter can mint and its remaining quota decreases |
+/// | MN-02 | Minting beyond the window quota is rejected |
+/// | MN-03 | The quota is restored once the window has elapsed |
+/// | MN-04 | Unregistered and removed minters are rejected |
+/// | MN-05 | Updating a minter keeps its usage in the open window |
+/// | MN-06 | Zero quotas and periods are rejected |
+/// | MN-07 | Registry changes and `mint` are admin-only; `mint_as` requires the minter's auth |
+/// | MN-08 | Minter mints emit `MintQuotaUsedEvent`; admin mints do not |
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
+    IntoVal, Map,
+};
+
+// ── helpers ──────────────────────────────────────────────────────────────────
+
+const ONE_TYC: i128 = 1_000_000_000_000_000_000;
+const QUOTA: i128 = 1_000 * ONE_TYC;
+const PERIOD: u32 = 100;
+
+/// Initialize the token at ledger 1000 and register a minter with `QUOTA`
+/// per `PERIOD` ledgers. Returns `(env, client, admin, minter)`.
+fn setup() -> (Env, TycoonTokenClient<'static>, Address, Address) {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_sequence_number(1000);
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    client.initialize(
+        &admin,
+        &0,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
+    let minter = Address::generate(&e);
+    client.set_minter(&minter, &QUOTA, &PERIOD);
+    (e, client, admin, minter)
+}
+
+// ── MN-01 ─────────────────────────────────────────────────────────────────────
+
+/// MN-01: a minter mints without the admin key and is charged for it.
+#[test]
+fn test_mn_01_minter_mints_within_quota() {
+    let (e, client, _admin, minter) = setup();
+    let player = Address::generate(&e);
+
+    client.mint_as(&minter, &player, &(300 * ONE_TYC));
+
+    assert_eq!(client.balance(&player), 300 * ONE_TYC);
+    assert_eq!(client.total_supply(), 300 * ONE_TYC);
+    assert_eq!(client.remaining_quota(&minter), 700 * ONE_TYC);
+    assert_eq!(
+        client.minter_quota(&minter),
+        Some(MinterQuota {
+            quota: QUOTA,
+            period_ledgers: PERIOD,
+            window_start: 1000,
+            minted: 300 * ONE_TYC,
+        })
+    );
+}
+
+// ── MN-02 ─────────────────────────────────────────────────────────────────────
+
+/// MN-02a: the quota can be used up exactly, but not exceeded.
+#[test]
+#[should_panic(expected = "Mint quota exceeded")]
+fn test_mn_02_mint_beyond_quota_rejected() {
+    let (e, client, _admin, minter) = setup();
+    let player = Address::generate(&e);
+
+    client.mint_as(&minter, &player, &QUOTA);
+    assert_eq!(client.remaining_quota(&minter), 0);
+    client.mint_as(&minter, &player, &1);
+}
+
+/// MN-02b: the admin is not subject to any quota.
+#[test]
+fn test_mn_02_admin_mints_without_quota() {
+    let (e, client, admin, _minter) = setup();
+    let player = Address::generate(&e);
+
+    client.mint(&player, &(10 * QUOTA));
+
+    assert_eq!(client.balance(&player), 10 * QUOTA);
+    assert_eq!(client.minter_quota(&admin), None);
+}
+
+// ── MN-03 ─────────────────────────────────────────────────────────────────────
+
+/// MN-03: the last ledger of a window is still inside it; the next one opens
+/// a fresh window.
+#[test]
+fn test_mn_03_quota_resets_after_period() {
+    let (e, client, _admin, minter) = setup();
+    let player = Address::generate(&e);
+    client.mint_as(&minter, &player, &QUOTA);
+
+    e.ledger().set_sequence_number(1000 + PERIOD - 1);
+    assert_eq!(client.remaining_quota(&minter), 0);
+    assert!(client.try_mint_as(&minter, &player, &1).is_err());
+
+    e.ledger().set_sequence_number(1000 + PERIOD);
+    assert_eq!(client.remaining_quota(&minter), QUOTA);
+    client.mint_as(&minter, &player, &(400 * ONE_TYC));
+
+    let entry = client.minter_quota(&minter).unwrap();
+    assert_eq!(entry.window_start, 1000 + PERIOD);
+    assert_eq!(entry.minted, 400 * ONE_TYC);
+    assert_eq!(client.balance(&player), QUOTA + 400 * ONE_TYC);
+}
+
+// ── MN-04 ─────────────────────────────────────────────────────────────────────
+
+/// MN-04a: an address that was never registered cannot mint.
+#[test]
+#[should_panic(expected = "Not an authorized minter")]
+fn test_mn_04_unregistered_minter_rejected() {
+    let (e, client, _admin, _minter) = setup();
+    let stranger = Address::generate(&e);
+    assert_eq!(client.remaining_quota(&stranger), 0);
+    client.mint_as(&stranger, &stranger, &1);
+}
+
+/// MN-04b: a removed minter loses its rights immediately.
+#[test]
+#[should_panic(expected = "Not an authorized minter")]
+fn test_mn_04_removed_minter_rejected() {
+    let (e, client, _admin, minter) = setup();
+    client.remove_minter(&minter);
+    assert_eq!(client.minter_quota(&minter), None);
+    client.mint_as(&minter, &Address::generate(&e), &1);
+}
+
+/// MN-04c: removing an unknown minter is an error.
+#[test]
+#[should_panic(expected = "Not a minter")]
+fn test_mn_04_remove_unknown_minter_rejected() {
+    let (e, client, _admin, _minter) = setup();
+    client.remove_minter(&Address::generate(&e));
+}
+
+// ── MN-05 ─────────────────────────────────────────────────────────────────────
+
+/// MN-05: lowering a quota mid-window cannot be undone by re-registering.
+#[test]
+fn test_mn_05_update_keeps_window_usage() {
+    let (e, client, _admin, minter) = setup();
+    let player = Address::generate(&e);
+    client.mint_as(&minter, &player, &(600 * ONE_TYC));
+
+    client.set_minter(&minter, &(800 * ONE_TYC), &PERIOD);
+    assert_eq!(client.remaining_quota(&minter), 200 * ONE_TYC);
+
+    // A quota below current usage leaves nothing to mint until the window ends.
+    client.set_minter(&minter, &(500 * ONE_TYC), &PERIOD);
+    assert_eq!(client.remaining_quota(&minter), 0);
+    assert!(client.try_mint_as(&minter, &player, &1).is_err());
+
+    e.ledger().set_sequence_number(1000 + PERIOD);
+    assert_eq!(client.remaining_quota(&minter), 500 * ONE_TYC);
+}
+
+// ── MN-06 ─────────────────────────────────────────────────────────────────────
+
+/// MN-06a: a zero quota is rejected; use `remove_minter` instead.
+#[test]
+#[should_panic(expected = "Quota must be positive")]
+fn test_mn_06_zero_quota_rejected() {
+    let (e, client, _admin, _minter) = setup();
+    client.set_minter(&Address::generate(&e), &0, &PERIOD);
+}
+
+/// MN-06b: a zero-length window is rejected.
+#[test]
+#[should_panic(expected = "Period must be positive")]
+fn test_mn_06_zero_period_rejected() {
+    let (e, client, _admin, _minter) = setup();
+    client.set_minter(&Address::generate(&e), &QUOTA, &0);
+}
+
+// ── MN-07 ─────────────────────────────────────────────────────────────────────
+
+/// MN-07a: only the admin can register minters.
+#[test]
+#[should_panic]
+fn test_mn_07_set_minter_requires_admin() {
+    let (e, client, _admin, _minter) = setup();
+    e.set_auths(&[]);
+    client.set_minter(&Address::generate(&e), &QUOTA, &PERIOD);
+}
+
+/// MN-07b: only the admin can remove minters.
+#[test]
+#[should_panic]
+fn test_mn_07_remove_minter_requires_admin() {
+    let (e, client, _admin, minter) = setup();
+    e.set_auths(&[]);
+    client.remove_minter(&minter);
+}
+
+/// MN-07c: `mint` stays admin-only; a registered minter's auth does not
+/// satisfy it.
+#[test]
+#[should_panic]
+fn test_mn_07_minter_cannot_use_admin_mint() {
+    let (e, client, _admin, minter) = setup();
+    e.mock_auths(&[MockAuth {
+        address: &minter,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "mint",
+            args: (minter.clone(), 1_i128).into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+    client.mint(&minter, &1);
+}
+
+/// MN-07d: naming a registered minter is not enough; it must sign.
+#[test]
+#[should_panic]
+fn test_mn_07_mint_requires_minter_auth() {
+    let (e, client, _admin, minter) = setup();
+    let attacker = Address::generate(&e);
+    e.mock_auths(&[MockAuth {
+        address: &attacker,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "mint_as",
+            args: (minter.clone(), attacker.clone(), 1_i128).into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+    client.mint_as(&minter, &attacker, &1);
+}
+
+// ── MN-08 ─────────────────────────────────────────────────────────────────────
+
+/// MN-08a: a minter mint reports the amount and the quota left.
+#[test]
+fn test_mn_08_minter_mint_emits_quota_event() {
+    let (e, client, _admin, minter) = setup();
+
+    client.mint_as(&minter, &Address::generate(&e), &(250 * ONE_TYC));
+
+    // MintQuotaUsedEvent, then MintEvent.
+    let events = e.events().all();
+    assert_eq!(events.len(), 2);
+    let (_, topics, data) = events.get(0).unwrap();
+    let topic_minter: Address = topics.get(1).unwrap().into_val(&e);
+    let data: Map<Symbol, i128> = data.into_val(&e);
+    assert_eq!(topic_minter, minter);
+    assert_eq!(data.get(symbol_short!("amount")), Some(250 * ONE_TYC));
+    assert_eq!(data.get(symbol_short!("remaining")), Some(750 * ONE_TYC));
+}
+
+/// MN-08b: admin mints only emit `MintEvent`.
+#[test]
+fn test_mn_08_admin_mint_emits_no_quota_event() {
+    let (e, client, _admin, _minter) = setup();
+
+    client.mint(&Address::generate(&e), &ONE_TYC);
+
+    assert_eq!(e.events().all().len(), 1);
+}
@@ -0,0 +1,102 @@
+//! Minter registry with per-period quotas.
+//!
+//! The admin can authorize other addresses (the reward system, a season-prize
+//! contract) to call `mint` without holding the admin key. Each minter gets a
+//! `quota` that may be minted per window of `period_ledgers` ledgers. A window
+//! opens on the first mint after the previous one has elapsed, so unused quota
+//! never carries over and a minter can never mint more than `quota` within any
+//! `period_ledgers` span that starts at a window boundary.
+//!
+//! The admin itself is not a registered minter and mints without a quota.
+use crate::DataKey;
+use soroban_sdk::{contracttype, Address, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MinterQuota {
+    /// Maximum amount mintable per window.
+    pub quota: i128,
+    /// Window length in ledgers.
+    pub period_ledgers: u32,
+    /// Ledger the current window opened at.
+    pub window_start: u32,
+    /// Amount minted in the current window.
+    pub minted: i128,
+}
+
+impl MinterQuota {
+    /// True once `ledger` is past the current window.
+    fn window_elapsed(&self, ledger: u32) -> bool {
+        ledger >= self.window_start.saturating_add(self.period_ledgers)
+    }
+
+    /// Quota left at `ledger`, accounting for a window that has elapsed.
+    /// Never negative, even after the quota is lowered below `minted`.
+    pub(crate) fn remaining_at(&self, ledger: u32) -> i128 {
+        if self.window_elapsed(ledger) {
+            self.quota
+        } else {
+            (self.quota - self.minted).max(0)
+        }
+    }
+}
+
+pub(crate) fn read(e: &Env, minter: &Address) -> Option<MinterQuota> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Minter(minter.clone()))
+}
+
+/// Register `minter` or update its quota. Usage in the open window is kept
+/// so an update cannot be used to reset a spent quota.
+pub(crate) fn set(e: &Env, minter: &Address, quota: i128, period_ledgers: u32) -> MinterQuota {
+    if quota <= 0 {
+        panic!("Quota must be positive");
+    }
+    if period_ledgers == 0 {
+        panic!("Period must be positive");
+    }
+    let ledger = e.ledger().sequence();
+    let (window_start, minted) = match read(e, minter) {
+        Some(prev) if !prev.window_elapsed(ledger) => (prev.window_start, prev.minted),
+        _ => (ledger, 0),
+    };
+    let entry = MinterQuota {
+        quota,
+        period_ledgers,
+        window_start,
+        minted,
+    };
+    e.storage()
+        .persistent()
+        .set(&DataKey::Minter(minter.clone()), &entry);
+    entry
+}
+
+pub(crate) fn remove(e: &Env, minter: &Address) {
+    let key = DataKey::Minter(minter.clone());
+    if !e.storage().persistent().has(&key) {
+        panic!("Not a minter");
+    }
+    e.storage().persistent().remove(&key);
+}
+
+/// Charge `amount` against `minter`'s quota, opening a new window if the
+/// previous one has elapsed. Returns the quota left in the window.
+pub(crate) fn consume(e: &Env, minter: &Address, amount: i128) -> i128 {
+    let mut entry = read(e, minter).unwrap_or_else(|| panic!("Not an authorized minter"));
+    let ledger = e.ledger().sequence();
+    if entry.window_elapsed(ledger) {
+        entry.window_start = ledger;
+        entry.minted = 0;
+    }
+    let minted = entry.minted.checked_add(amount).expect("Quota overflow");
+    if minted > entry.quota {
+        panic!("Mint quota exceeded");
+    }
+    entry.minted = minted;
+    e.storage()
+        .persistent()
+        .set(&DataKey::Minter(minter.clone()), &entry);
+    entry.quota - minted
+}
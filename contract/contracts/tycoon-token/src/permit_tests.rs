@@ -0,0 +1,384 @@
+/// # Tycoon Token (TYC) — Signed Permit Tests
+///
+/// | ID    | Behaviour |
+/// |-------|-----------|
+/// | PM-01 | A valid permit sets the allowance and consumes the nonce |
+/// | PM-02 | The spender can use a permit allowance through `transfer_from` |
+/// | PM-03 | Replaying a consumed permit is rejected |
+/// | PM-04 | A permit past its `expiration_ledger` is rejected; `0` never expires, as in `approve` |
+/// | PM-05 | A signature from another key is rejected |
+/// | PM-06 | Changing any signed field invalidates the signature |
+/// | PM-07 | A permit signed for another token contract is rejected |
+/// | PM-08 | Negative amounts are rejected before signature checks |
+use super::*;
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::testutils::{Address as _, Ledger};
+
+// ── helpers ──────────────────────────────────────────────────────────────────
+
+const INITIAL_SUPPLY: i128 = 1_000_000_000_000_000_000_000_000_000; // 1e9 * 10^18
+const ONE_TYC: i128 = 1_000_000_000_000_000_000;
+
+/// Spin up a fresh environment at ledger 100 and initialize the contract.
+/// Returns `(env, client, admin)`.
+fn setup() -> (Env, TycoonTokenClient<'static>, Address) {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_sequence_number(100);
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
+    (e, client, admin)
+}
+
+/// A player keypair plus the account address its public key maps to.
+struct Player {
+    key: SigningKey,
+    pubkey: BytesN<32>,
+    address: Address,
+}
+
+fn player(e: &Env, seed: u8) -> Player {
+    let key = SigningKey::from_bytes(&[seed; 32]);
+    let pubkey = BytesN::from_array(e, &key.verifying_key().to_bytes());
+    let address =
+        Address::from_payload(e, AddressPayload::AccountIdPublicKeyEd25519(pubkey.clone()));
+    Player {
+        key,
+        pubkey,
+        address,
+    }
+}
+
+/// Sign a permit the same way an off-chain wallet would.
+#[allow(clippy::too_many_arguments)]
+fn sign(
+    e: &Env,
+    contract: &Address,
+    signer: &SigningKey,
+    owner: &Address,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+    nonce: u64,
+) -> BytesN<64> {
+    let payload = PermitPayload {
+        domain: symbol_short!("TYCPERMIT"),
+        network_id: e.ledger().network_id(),
+        contract: contract.clone(),
+        owner: owner.clone(),
+        spender: spender.clone(),
+        amount,
+        expiration_ledger,
+        nonce,
+    };
+    let digest = e.crypto().sha256(&payload.to_xdr(e));
+    BytesN::from_array(e, &signer.sign(&digest.to_array()).to_bytes())
+}
+
+// ── PM-01 ─────────────────────────────────────────────────────────────────────
+
+/// PM-01: a valid permit sets the allowance and advances the owner's nonce.
+#[test]
+fn test_pm_01_permit_sets_allowance_and_consumes_nonce() {
+    let (e, client, _admin) = setup();
+    let owner = player(&e, 1);
+    let spender = Address::generate(&e);
+
+    assert_eq!(client.nonces(&owner.address), 0);
+    let sig = sign(
+        &e,
+        &client.address,
+        &owner.key,
+        &owner.address,
+        &spender,
+        50 * ONE_TYC,
+        200,
+        0,
+    );
+    client.permit(&owner.pubkey, &spender, &(50 * ONE_TYC), &200, &0, &sig);
+
+    assert_eq!(client.allowance(&owner.address, &spender), 50 * ONE_TYC);
+    assert_eq!(client.nonces(&owner.address), 1);
+
+    // The next permit must use nonce 1.
+    let sig = sign(
+        &e,
+        &client.address,
+        &owner.key,
+        &owner.address,
+        &spender,
+        10 * ONE_TYC,
+        200,
+        1,
+    );
+    client.permit(&owner.pubkey, &spender, &(10 * ONE_TYC), &200, &1, &sig);
+    assert_eq!(client.allowance(&owner.address, &spender), 10 * ONE_TYC);
+    assert_eq!(client.nonces(&owner.address), 2);
+}
+
+// ── PM-02 ─────────────────────────────────────────────────────────────────────
+
+/// PM-02: the relayer-submitted allowance is spendable like any other.
+#[test]
+fn test_pm_02_permit_allowance_is_spendable() {
+    let (e, client, admin) = setup();
+    let owner = player(&e, 2);
+    let game = Address::generate(&e);
+    let treasury = Address::generate(&e);
+
+    client.transfer(&admin, &owner.address, &(100 * ONE_TYC));
+    let sig = sign(
+        &e,
+        &client.address,
+        &owner.key,
+        &owner.address,
+        &game,
+        30 * ONE_TYC,
+        150,
+        0,
+    );
+    client.permit(&owner.pubkey, &game, &(30 * ONE_TYC), &150, &0, &sig);
+    client.transfer_from(&game, &owner.address, &treasury, &(30 * ONE_TYC));
+
+    assert_eq!(client.balance(&owner.address), 70 * ONE_TYC);
+    assert_eq!(client.balance(&treasury), 30 * ONE_TYC);
+    assert_eq!(client.allowance(&owner.address, &game), 0);
+}
+
+// ── PM-03 ─────────────────────────────────────────────────────────────────────
+
+/// PM-03: submitting the same signed permit twice fails on the nonce check.
+#[test]
+#[should_panic(expected = "Invalid nonce")]
+fn test_pm_03_replay_rejected() {
+    let (e, client, _admin) = setup();
+    let owner = player(&e, 3);
+    let spender = Address::generate(&e);
+
+    let sig = sign(
+        &e,
+        &client.address,
+        &owner.key,
+        &owner.address,
+        &spender,
+        ONE_TYC,
+        200,
+        0,
+    );
+    client.permit(&owner.pubkey, &spender, &ONE_TYC, &200, &0, &sig);
+    client.permit(&owner.pubkey, &spender, &ONE_TYC, &200, &0, &sig);
+}
+
+/// PM-03b: a permit signed for a future nonce cannot skip ahead.
+#[test]
+#[should_panic(expected = "Invalid nonce")]
+fn test_pm_03_future_nonce_rejected() {
+    let (e, client, _admin) = setup();
+    let owner = player(&e, 3);
+    let spender = Address::generate(&e);
+
+    let sig = sign(
+        &e,
+        &client.address,
+        &owner.key,
+        &owner.address,
+        &spender,
+        ONE_TYC,
+        200,
+        5,
+    );
+    client.permit(&owner.pubkey, &spender, &ONE_TYC, &200, &5, &sig);
+}
+
+// ── PM-04 ─────────────────────────────────────────────────────────────────────
+
+/// PM-04: a permit whose expiration ledger has passed is rejected.
+#[test]
+#[should_panic(expected = "Permit expired")]
+fn test_pm_04_expired_permit_rejected() {
+    let (e, client, _admin) = setup();
+    let owner = player(&e, 4);
+    let spender = Address::generate(&e);
+
+    let sig = sign(
+        &e,
+        &client.address,
+        &owner.key,
+        &owner.address,
+        &spender,
+        ONE_TYC,
+        120,
+        0,
+    );
+    e.ledger().set_sequence_number(121);
+    client.permit(&owner.pubkey, &spender, &ONE_TYC, &120, &0, &sig);
+}
+
+/// PM-04b: the expiration ledger itself is still inside the validity window.
+#[test]
+fn test_pm_04_permit_valid_on_expiration_ledger() {
+    let (e, client, _admin) = setup();
+    let owner = player(&e, 4);
+    let spender = Address::generate(&e);
+
+    let sig = sign(
+        &e,
+        &client.address,
+        &owner.key,
+        &owner.address,
+        &spender,
+        ONE_TYC,
+        120,
+        0,
+    );
+    e.ledger().set_sequence_number(120);
+    client.permit(&owner.pubkey, &spender, &ONE_TYC, &120, &0, &sig);
+    assert_eq!(client.allowance(&owner.address, &spender), ONE_TYC);
+}
+
+/// PM-04c: `expiration_ledger == 0` is a non-expiring allowance, as in `approve`.
+#[test]
+fn test_pm_04_zero_expiration_never_expires() {
+    let (e, client, _admin) = setup();
+    let owner = player(&e, 4);
+    let spender = Address::generate(&e);
+
+    let sig = sign(
+        &e,
+        &client.address,
+        &owner.key,
+        &owner.address,
+        &spender,
+        ONE_TYC,
+        0,
+        0,
+    );
+    client.permit(&owner.pubkey, &spender, &ONE_TYC, &0, &0, &sig);
+
+    e.ledger().set_sequence_number(1_000_000);
+    assert_eq!(client.allowance(&owner.address, &spender), ONE_TYC);
+}
+
+// ── PM-05 ─────────────────────────────────────────────────────────────────────
+
+/// PM-05: a signature made with another key does not verify for `owner_pubkey`.
+#[test]
+#[should_panic]
+fn test_pm_05_wrong_signer_rejected() {
+    let (e, client, _admin) = setup();
+    let owner = player(&e, 5);
+    let attacker = player(&e, 6);
+    let spender = Address::generate(&e);
+
+    let sig = sign(
+        &e,
+        &client.address,
+        &attacker.key,
+        &owner.address,
+        &spender,
+        ONE_TYC,
+        200,
+        0,
+    );
+    client.permit(&owner.pubkey, &spender, &ONE_TYC, &200, &0, &sig);
+}
+
+// ── PM-06 ─────────────────────────────────────────────────────────────────────
+
+/// PM-06: raising the amount after signing invalidates the signature.
+#[test]
+#[should_panic]
+fn test_pm_06_tampered_amount_rejected() {
+    let (e, client, _admin) = setup();
+    let owner = player(&e, 7);
+    let spender = Address::generate(&e);
+
+    let sig = sign(
+        &e,
+        &client.address,
+        &owner.key,
+        &owner.address,
+        &spender,
+        ONE_TYC,
+        200,
+        0,
+    );
+    client.permit(&owner.pubkey, &spender, &(1_000 * ONE_TYC), &200, &0, &sig);
+}
+
+/// PM-06b: redirecting the allowance to another spender invalidates the signature.
+#[test]
+#[should_panic]
+fn test_pm_06_tampered_spender_rejected() {
+    let (e, client, _admin) = setup();
+    let owner = player(&e, 7);
+    let spender = Address::generate(&e);
+    let thief = Address::generate(&e);
+
+    let sig = sign(
+        &e,
+        &client.address,
+        &owner.key,
+        &owner.address,
+        &spender,
+        ONE_TYC,
+        200,
+        0,
+    );
+    client.permit(&owner.pubkey, &thief, &ONE_TYC, &200, &0, &sig);
+}
+
+// ── PM-07 ─────────────────────────────────────────────────────────────────────
+
+/// PM-07: the contract address is part of the signed domain.
+#[test]
+#[should_panic]
+fn test_pm_07_signature_for_other_contract_rejected() {
+    let (e, client, _admin) = setup();
+    let owner = player(&e, 8);
+    let spender = Address::generate(&e);
+    let other_token = e.register(TycoonToken, ());
+
+    let sig = sign(
+        &e,
+        &other_token,
+        &owner.key,
+        &owner.address,
+        &spender,
+        ONE_TYC,
+        200,
+        0,
+    );
+    client.permit(&owner.pubkey, &spender, &ONE_TYC, &200, &0, &sig);
+}
+
+// ── PM-08 ─────────────────────────────────────────────────────────────────────
+
+/// PM-08: negative allowances are rejected like in `approve`.
+#[test]
+#[should_panic(expected = "Amount cannot be negative")]
+fn test_pm_08_negative_amount_rejected() {
+    let (e, client, _admin) = setup();
+    let owner = player(&e, 9);
+    let spender = Address::generate(&e);
+
+    let sig = sign(
+        &e,
+        &client.address,
+        &owner.key,
+        &owner.address,
+        &spender,
+        -1,
+        200,
+        0,
+    );
+    client.permit(&owner.pubkey, &spender, &-1, &200, &0, &sig);
+}
@@ -7,7 +7,7 @@ mod tests {
     use crate::{TycoonToken, TycoonTokenClient};
     use soroban_sdk::{
         testutils::{Address as _, Events, Ledger, LedgerInfo},
-        Address, Env,
+        Address, Env, String,
     };
 
     const INITIAL_SUPPLY: i128 = 1_000_000_000_000_000_000_000_000_000;
@@ -18,7 +18,13 @@ mod tests {
         let id = e.register(TycoonToken, ());
         let client = TycoonTokenClient::new(&e, &id);
         let admin = Address::generate(&e);
-        client.initialize(&admin, &INITIAL_SUPPLY);
+        client.initialize(
+            &admin,
+            &INITIAL_SUPPLY,
+            &String::from_str(&e, "Tycoon"),
+            &String::from_str(&e, "TYC"),
+            &18,
+        );
         (e, client, admin)
     }
 
@@ -45,7 +51,13 @@ mod tests {
         let id = e.register(TycoonToken, ());
         let client = TycoonTokenClient::new(&e, &id);
         let admin = Address::generate(&e);
-        client.initialize(&admin, &-1);
+        client.initialize(
+            &admin,
+            &-1,
+            &String::from_str(&e, "Tycoon"),
+            &String::from_str(&e, "TYC"),
+            &18,
+        );
     }
 
     // ── SEC-02: allowance expiry enforced in transfer_from ────────────────────
@@ -61,7 +73,13 @@ mod tests {
         let spender = Address::generate(&e);
         let recipient = Address::generate(&e);
 
-        client.initialize(&admin, &INITIAL_SUPPLY);
+        client.initialize(
+            &admin,
+            &INITIAL_SUPPLY,
+            &String::from_str(&e, "Tycoon"),
+            &String::from_str(&e, "TYC"),
+            &18,
+        );
         client.approve(&admin, &spender, &1_000_000_000_000_000_000, &10);
 
         set_ledger_seq(&e, 11); // past expiry
@@ -80,7 +98,13 @@ mod tests {
         let admin = Address::generate(&e);
         let spender = Address::generate(&e);
 
-        client.initialize(&admin, &INITIAL_SUPPLY);
+        client.initialize(
+            &admin,
+            &INITIAL_SUPPLY,
+            &String::from_str(&e, "Tycoon"),
+            &String::from_str(&e, "TYC"),
+            &18,
+        );
         client.approve(&admin, &spender, &1_000_000_000_000_000_000, &10);
 
         set_ledger_seq(&e, 11);
@@ -99,7 +123,13 @@ mod tests {
         let spender = Address::generate(&e);
         let recipient = Address::generate(&e);
 
-        client.initialize(&admin, &INITIAL_SUPPLY);
+        client.initialize(
+            &admin,
+            &INITIAL_SUPPLY,
+            &String::from_str(&e, "Tycoon"),
+            &String::from_str(&e, "TYC"),
+            &18,
+        );
 
         let amount: i128 = 1_000_000_000_000_000_000;
         client.approve(&admin, &spender, &amount, &100);
@@ -138,7 +168,13 @@ mod tests {
         let admin = Address::generate(&e);
         let spender = Address::generate(&e);
 
-        client.initialize(&admin, &INITIAL_SUPPLY);
+        client.initialize(
+            &admin,
+            &INITIAL_SUPPLY,
+            &String::from_str(&e, "Tycoon"),
+            &String::from_str(&e, "TYC"),
+            &18,
+        );
         client.approve(&admin, &spender, &1_000_000_000_000_000_000, &5);
 
         set_ledger_seq(&e, 6);
@@ -181,7 +217,13 @@ mod tests {
         let admin = Address::generate(&e);
         let spender = Address::generate(&e);
         let recipient = Address::generate(&e);
-        client.initialize(&admin, &INITIAL_SUPPLY);
+        client.initialize(
+            &admin,
+            &INITIAL_SUPPLY,
+            &String::from_str(&e, "Tycoon"),
+            &String::from_str(&e, "TYC"),
+            &18,
+        );
 
         let amount: i128 = 1_000_000_000_000_000_000;
         client.approve(&admin, &spender, &amount, &10);
@@ -201,7 +243,13 @@ mod tests {
         let admin = Address::generate(&e);
         let spender = Address::generate(&e);
         let recipient = Address::generate(&e);
-        client.initialize(&admin, &INITIAL_SUPPLY);
+        client.initialize(
+            &admin,
+            &INITIAL_SUPPLY,
+            &String::from_str(&e, "Tycoon"),
+            &String::from_str(&e, "TYC"),
+            &18,
+        );
 
         client.approve(&admin, &spender, &1_000_000_000_000_000_000, &10);
         set_ledger_seq(&e, 11); // one past expiry
@@ -213,8 +261,14 @@ mod tests {
     #[test]
     #[should_panic(expected = "Already initialized")]
     fn test_sec_10_double_initialize_rejected() {
-        let (_e, client, admin) = setup();
-        client.initialize(&admin, &INITIAL_SUPPLY);
+        let (e, client, admin) = setup();
+        client.initialize(
+            &admin,
+            &INITIAL_SUPPLY,
+            &String::from_str(&e, "Tycoon"),
+            &String::from_str(&e, "TYC"),
+            &18,
+        );
     }
 
     // ── SEC-11: legacy entrypoints panic with deprecation message ─────────────
@@ -4,7 +4,7 @@
 #[cfg(test)]
 mod tests {
     use crate::{TycoonToken, TycoonTokenClient};
-    use soroban_sdk::{testutils::Address as _, Address, Env};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
 
     const SUPPLY: i128 = 1_000_000_000_000_000_000_000_000_000;
 
@@ -14,7 +14,13 @@ mod tests {
         let id = e.register(TycoonToken, ());
         let client = TycoonTokenClient::new(&e, &id);
         let admin = Address::generate(&e);
-        client.initialize(&admin, &SUPPLY);
+        client.initialize(
+            &admin,
+            &SUPPLY,
+            &String::from_str(&e, "Tycoon"),
+            &String::from_str(&e, "TYC"),
+            &18,
+        );
         (e, client, admin)
     }
 
@@ -0,0 +1,103 @@
+//! Optional transfer tax.
+//!
+//! When configured, every peer-to-peer transfer pays `burn_bps + treasury_bps`
+//! of the amount: the burn share is destroyed (reducing `TotalSupply`) and the
+//! treasury share is credited to the treasury address. The recipient receives
+//! the rest. The split is computed with `tycoon_lib::fees::calculate_fee_split`
+//! so rounding matches the shop's fee splits: each share is floored and the
+//! residue goes to the recipient.
+//!
+//! Transfers where either side is on the admin-managed exemption list (game,
+//! reward and shop contracts) are not taxed. Mints and burns are never taxed.
+use crate::DataKey;
+use soroban_sdk::{contracttype, Address, Env};
+use tycoon_lib::fees::{calculate_fee_split, FeeConfig};
+
+/// Maximum combined transfer tax (5%).
+pub const MAX_TRANSFER_TAX_BPS: u32 = 500;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferTax {
+    /// Share of each transfer that is burned, in basis points.
+    pub burn_bps: u32,
+    /// Share of each transfer credited to `treasury`, in basis points.
+    pub treasury_bps: u32,
+    pub treasury: Address,
+}
+
+/// Result of assessing the tax on one transfer.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct Assessment {
+    pub net: i128,
+    pub burned: i128,
+    pub to_treasury: i128,
+}
+
+pub(crate) fn read_config(e: &Env) -> Option<TransferTax> {
+    e.storage().instance().get(&DataKey::TransferTax)
+}
+
+pub(crate) fn write_config(e: &Env, config: &TransferTax) {
+    if config.burn_bps + config.treasury_bps > MAX_TRANSFER_TAX_BPS {
+        panic!("Transfer tax exceeds cap");
+    }
+    if config.burn_bps == 0 && config.treasury_bps == 0 {
+        e.storage().instance().remove(&DataKey::TransferTax);
+    } else {
+        e.storage().instance().set(&DataKey::TransferTax, config);
+    }
+}
+
+pub(crate) fn is_exempt(e: &Env, account: &Address) -> bool {
+    e.storage()
+        .persistent()
+        .get(&DataKey::TaxExempt(account.clone()))
+        .unwrap_or(false)
+}
+
+pub(crate) fn set_exempt(e: &Env, account: &Address, exempt: bool) {
+    let key = DataKey::TaxExempt(account.clone());
+    if exempt {
+        e.storage().persistent().set(&key, &true);
+    } else {
+        e.storage().persistent().remove(&key);
+    }
+}
+
+/// Split a positive `amount` moving from `from` to `to`.
+///
+/// Takes the already-loaded `config` so batch transfers read it once.
+pub(crate) fn assess(
+    e: &Env,
+    config: &Option<TransferTax>,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> Assessment {
+    let untaxed = Assessment {
+        net: amount,
+        ..Default::default()
+    };
+    let Some(config) = config else {
+        return untaxed;
+    };
+    if is_exempt(e, from) || is_exempt(e, to) {
+        return untaxed;
+    }
+
+    // Map onto the shared fee splitter: platform = treasury, pool = burn.
+    let fees = FeeConfig {
+        platform_fee_bps: config.treasury_bps,
+        creator_fee_bps: 0,
+        pool_fee_bps: config.burn_bps,
+        platform_address: config.treasury.clone(),
+        pool_address: config.treasury.clone(),
+    };
+    let split = calculate_fee_split(amount as u128, &fees);
+    Assessment {
+        net: split.residue as i128,
+        burned: split.pool_amount as i128,
+        to_treasury: split.platform_amount as i128,
+    }
+}
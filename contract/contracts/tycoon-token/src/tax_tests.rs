@@ -0,0 +1,344 @@
+/// # Tycoon Token (TYC) — Transfer Tax Tests
+///
+/// | ID    | Behaviour |
+/// |-------|-----------|
+/// | TX-01 | A taxed transfer burns and credits the treasury; the recipient gets the rest |
+/// | TX-02 | Shares are floored; the rounding residue goes to the recipient |
+/// | TX-03 | `total_supply` and its checkpoints reflect the burned share |
+/// | TX-04 | Transfers to or from an exempt account are untaxed |
+/// | TX-05 | `transfer_from`, `batch_transfer` and `transfer_and_call` are taxed |
+/// | TX-06 | A combined rate above `MAX_TRANSFER_TAX_BPS` is rejected |
+/// | TX-07 | Setting both rates to 0 clears the tax |
+/// | TX-08 | The tax setters are admin-only |
+/// | TX-09 | `TransferEvent` carries the net amount and `TransferTaxEvent` the shares |
+/// | TX-10 | Mints and burns are never taxed |
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Events, Ledger},
+    vec, Bytes, IntoVal, Map,
+};
+
+// ── helpers ──────────────────────────────────────────────────────────────────
+
+const INITIAL_SUPPLY: i128 = 1_000_000_000_000_000_000_000_000_000; // 1e9 * 10^18
+const ONE_TYC: i128 = 1_000_000_000_000_000_000;
+
+/// Accepts every `transfer_and_call` and records the amount it was told about.
+#[contract]
+struct RecordingReceiver;
+
+#[contractimpl]
+impl RecordingReceiver {
+    pub fn on_token_received(e: Env, _from: Address, amount: i128, _payload: Bytes) -> bool {
+        e.storage()
+            .instance()
+            .set(&symbol_short!("amount"), &amount);
+        true
+    }
+
+    pub fn amount(e: Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&symbol_short!("amount"))
+            .unwrap_or(0)
+    }
+}
+
+/// Initialize the token, fund a player with 1 000 TYC and configure a
+/// 1% burn + 2% treasury tax. Returns `(env, client, admin, player, treasury)`.
+fn setup() -> (Env, TycoonTokenClient<'static>, Address, Address, Address) {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
+    let player = Address::generate(&e);
+    client.transfer(&admin, &player, &(1_000 * ONE_TYC));
+
+    let treasury = Address::generate(&e);
+    client.set_transfer_tax(&100, &200, &treasury);
+    (e, client, admin, player, treasury)
+}
+
+// ── TX-01 ─────────────────────────────────────────────────────────────────────
+
+/// TX-01: 3% of a 100 TYC transfer is withheld — 1 TYC burned, 2 TYC to treasury.
+#[test]
+fn test_tx_01_transfer_is_split() {
+    let (e, client, _admin, player, treasury) = setup();
+    let bob = Address::generate(&e);
+
+    client.transfer(&player, &bob, &(100 * ONE_TYC));
+
+    assert_eq!(client.balance(&player), 900 * ONE_TYC);
+    assert_eq!(client.balance(&bob), 97 * ONE_TYC);
+    assert_eq!(client.balance(&treasury), 2 * ONE_TYC);
+    assert_eq!(
+        client.transfer_tax(),
+        Some(TransferTax {
+            burn_bps: 100,
+            treasury_bps: 200,
+            treasury,
+        })
+    );
+}
+
+// ── TX-02 ─────────────────────────────────────────────────────────────────────
+
+/// TX-02: on tiny amounts each share rounds down and the recipient keeps the dust.
+#[test]
+fn test_tx_02_rounding_favours_recipient() {
+    let (e, client, _admin, player, treasury) = setup();
+    let bob = Address::generate(&e);
+
+    // 1% of 99 and 2% of 99 both floor to 0 / 1.
+    client.transfer(&player, &bob, &99);
+    assert_eq!(client.balance(&bob), 98);
+    assert_eq!(client.balance(&treasury), 1);
+    assert_eq!(client.total_supply(), INITIAL_SUPPLY);
+
+    // Below 34 units nothing is withheld at all.
+    client.transfer(&player, &bob, &33);
+    assert_eq!(client.balance(&bob), 98 + 33);
+    assert_eq!(client.balance(&treasury), 1);
+}
+
+// ── TX-03 ─────────────────────────────────────────────────────────────────────
+
+/// TX-03: the burned share leaves the supply and is checkpointed like a burn.
+#[test]
+fn test_tx_03_burn_share_reduces_supply() {
+    let (e, client, _admin, player, _treasury) = setup();
+    let bob = Address::generate(&e);
+
+    e.ledger().set_sequence_number(10);
+    client.transfer(&player, &bob, &(100 * ONE_TYC));
+    e.ledger().set_sequence_number(11);
+
+    assert_eq!(client.total_supply(), INITIAL_SUPPLY - ONE_TYC);
+    assert_eq!(client.total_supply_at(&9), INITIAL_SUPPLY);
+    assert_eq!(client.total_supply_at(&10), INITIAL_SUPPLY - ONE_TYC);
+}
+
+// ── TX-04 ─────────────────────────────────────────────────────────────────────
+
+/// TX-04a: paying into an exempt contract (e.g. the game) is untaxed.
+#[test]
+fn test_tx_04_transfer_to_exempt_untaxed() {
+    let (e, client, _admin, player, treasury) = setup();
+    let game = Address::generate(&e);
+    client.set_tax_exempt(&game, &true);
+
+    client.transfer(&player, &game, &(100 * ONE_TYC));
+
+    assert!(client.is_tax_exempt(&game));
+    assert_eq!(client.balance(&game), 100 * ONE_TYC);
+    assert_eq!(client.balance(&treasury), 0);
+    assert_eq!(client.total_supply(), INITIAL_SUPPLY);
+}
+
+/// TX-04b: payouts from an exempt contract are untaxed too.
+#[test]
+fn test_tx_04_transfer_from_exempt_untaxed() {
+    let (e, client, _admin, player, treasury) = setup();
+    let reward = Address::generate(&e);
+    client.set_tax_exempt(&reward, &true);
+    client.transfer(&player, &reward, &(100 * ONE_TYC));
+
+    let winner = Address::generate(&e);
+    client.transfer(&reward, &winner, &(100 * ONE_TYC));
+
+    assert_eq!(client.balance(&winner), 100 * ONE_TYC);
+    assert_eq!(client.balance(&treasury), 0);
+}
+
+/// TX-04c: removing an exemption restores the tax.
+#[test]
+fn test_tx_04_exemption_can_be_revoked() {
+    let (e, client, _admin, player, treasury) = setup();
+    client.set_tax_exempt(&player, &true);
+    client.set_tax_exempt(&player, &false);
+
+    client.transfer(&player, &Address::generate(&e), &(100 * ONE_TYC));
+
+    assert!(!client.is_tax_exempt(&player));
+    assert_eq!(client.balance(&treasury), 2 * ONE_TYC);
+}
+
+// ── TX-05 ─────────────────────────────────────────────────────────────────────
+
+/// TX-05a: `transfer_from` spends the gross amount from the allowance.
+#[test]
+fn test_tx_05_transfer_from_taxed() {
+    let (e, client, _admin, player, treasury) = setup();
+    let spender = Address::generate(&e);
+    let bob = Address::generate(&e);
+    client.approve(&player, &spender, &(100 * ONE_TYC), &1000);
+
+    client.transfer_from(&spender, &player, &bob, &(100 * ONE_TYC));
+
+    assert_eq!(client.allowance(&player, &spender), 0);
+    assert_eq!(client.balance(&bob), 97 * ONE_TYC);
+    assert_eq!(client.balance(&treasury), 2 * ONE_TYC);
+}
+
+/// TX-05b: a batch is taxed per recipient and settled once.
+#[test]
+fn test_tx_05_batch_transfer_taxed() {
+    let (e, client, _admin, player, treasury) = setup();
+    let bob = Address::generate(&e);
+    let carol = Address::generate(&e);
+
+    client.batch_transfer(
+        &player,
+        &vec![
+            &e,
+            (bob.clone(), 100 * ONE_TYC),
+            (carol.clone(), 200 * ONE_TYC),
+        ],
+    );
+
+    assert_eq!(client.balance(&player), 700 * ONE_TYC);
+    assert_eq!(client.balance(&bob), 97 * ONE_TYC);
+    assert_eq!(client.balance(&carol), 194 * ONE_TYC);
+    assert_eq!(client.balance(&treasury), 6 * ONE_TYC);
+    assert_eq!(client.total_supply(), INITIAL_SUPPLY - 3 * ONE_TYC);
+}
+
+/// TX-05c: the receiver hook is told the net amount it actually received.
+#[test]
+fn test_tx_05_transfer_and_call_passes_net_amount() {
+    let (e, client, _admin, player, _treasury) = setup();
+    let receiver = RecordingReceiverClient::new(&e, &e.register(RecordingReceiver, ()));
+
+    client.transfer_and_call(
+        &player,
+        &receiver.address,
+        &(100 * ONE_TYC),
+        &Bytes::new(&e),
+    );
+
+    assert_eq!(client.balance(&receiver.address), 97 * ONE_TYC);
+    assert_eq!(receiver.amount(), 97 * ONE_TYC);
+}
+
+// ── TX-06 ─────────────────────────────────────────────────────────────────────
+
+/// TX-06a: the combined rate is capped.
+#[test]
+#[should_panic(expected = "Transfer tax exceeds cap")]
+fn test_tx_06_rate_above_cap_rejected() {
+    let (e, client, _admin, _player, _treasury) = setup();
+    client.set_transfer_tax(&300, &201, &Address::generate(&e));
+}
+
+/// TX-06b: exactly the cap is accepted.
+#[test]
+fn test_tx_06_rate_at_cap_accepted() {
+    let (e, client, _admin, player, treasury) = setup();
+    client.set_transfer_tax(&0, &MAX_TRANSFER_TAX_BPS, &treasury);
+
+    client.transfer(&player, &Address::generate(&e), &(100 * ONE_TYC));
+
+    assert_eq!(client.balance(&treasury), 5 * ONE_TYC);
+    assert_eq!(client.total_supply(), INITIAL_SUPPLY);
+}
+
+// ── TX-07 ─────────────────────────────────────────────────────────────────────
+
+/// TX-07: 0/0 disables the tax entirely.
+#[test]
+fn test_tx_07_zero_rates_clear_tax() {
+    let (e, client, _admin, player, treasury) = setup();
+    client.set_transfer_tax(&0, &0, &treasury);
+
+    let bob = Address::generate(&e);
+    client.transfer(&player, &bob, &(100 * ONE_TYC));
+
+    assert_eq!(client.transfer_tax(), None);
+    assert_eq!(client.balance(&bob), 100 * ONE_TYC);
+}
+
+// ── TX-08 ─────────────────────────────────────────────────────────────────────
+
+/// TX-08a: only the admin can set the tax.
+#[test]
+#[should_panic]
+fn test_tx_08_set_transfer_tax_requires_admin() {
+    let (e, client, _admin, _player, treasury) = setup();
+    e.set_auths(&[]);
+    client.set_transfer_tax(&100, &100, &treasury);
+}
+
+/// TX-08b: only the admin can grant exemptions.
+#[test]
+#[should_panic]
+fn test_tx_08_set_tax_exempt_requires_admin() {
+    let (e, client, _admin, player, _treasury) = setup();
+    e.set_auths(&[]);
+    client.set_tax_exempt(&player, &true);
+}
+
+// ── TX-09 ─────────────────────────────────────────────────────────────────────
+
+/// TX-09: one `TransferEvent` with the net amount, then one `TransferTaxEvent`.
+#[test]
+fn test_tx_09_events_carry_net_and_shares() {
+    let (e, client, _admin, player, treasury) = setup();
+    let bob = Address::generate(&e);
+
+    client.transfer(&player, &bob, &(100 * ONE_TYC));
+
+    let events = e.events().all();
+    assert_eq!(events.len(), 2);
+
+    let (_, _, data) = events.get(0).unwrap();
+    let data: Map<Symbol, i128> = data.into_val(&e);
+    assert_eq!(data.get(symbol_short!("amount")), Some(97 * ONE_TYC));
+
+    // TransferTaxEvent topics: [name, from, treasury]; data: { burned, to_treasury }
+    let (_, topics, data) = events.get(1).unwrap();
+    let topic_from: Address = topics.get(1).unwrap().into_val(&e);
+    let topic_treasury: Address = topics.get(2).unwrap().into_val(&e);
+    let data: Map<Symbol, i128> = data.into_val(&e);
+    assert_eq!(topic_from, player);
+    assert_eq!(topic_treasury, treasury);
+    assert_eq!(data.get(Symbol::new(&e, "burned")), Some(ONE_TYC));
+    assert_eq!(data.get(Symbol::new(&e, "to_treasury")), Some(2 * ONE_TYC));
+}
+
+/// TX-09b: untaxed transfers emit no `TransferTaxEvent`.
+#[test]
+fn test_tx_09_exempt_transfer_emits_no_tax_event() {
+    let (e, client, _admin, player, _treasury) = setup();
+    client.set_tax_exempt(&player, &true);
+
+    client.transfer(&player, &Address::generate(&e), &(100 * ONE_TYC));
+
+    assert_eq!(e.events().all().len(), 1);
+}
+
+// ── TX-10 ─────────────────────────────────────────────────────────────────────
+
+/// TX-10: minting and burning move exactly the requested amount.
+#[test]
+fn test_tx_10_mint_and_burn_untaxed() {
+    let (e, client, _admin, player, treasury) = setup();
+    let bob = Address::generate(&e);
+
+    client.mint(&bob, &(50 * ONE_TYC));
+    client.burn(&player, &(50 * ONE_TYC));
+
+    assert_eq!(client.balance(&bob), 50 * ONE_TYC);
+    assert_eq!(client.balance(&player), 950 * ONE_TYC);
+    assert_eq!(client.balance(&treasury), 0);
+    assert_eq!(client.total_supply(), INITIAL_SUPPLY);
+}
@@ -12,7 +12,13 @@ fn test_initialization() {
     let client = TycoonTokenClient::new(&e, &contract_id);
     let admin = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     assert_eq!(client.name(), String::from_str(&e, "Tycoon"));
     assert_eq!(client.symbol(), String::from_str(&e, "TYC"));
@@ -32,8 +38,20 @@ fn test_cannot_reinitialize() {
     let client = TycoonTokenClient::new(&e, &contract_id);
     let admin = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 }
 
 #[test]
@@ -46,7 +64,13 @@ fn test_admin_can_mint() {
     let admin = Address::generate(&e);
     let user = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     let mint_amount: i128 = 1_000_000_000_000_000_000_000;
     client.mint(&user, &mint_amount);
@@ -66,7 +90,13 @@ fn test_cannot_mint_zero() {
     let admin = Address::generate(&e);
     let user = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
     client.mint(&user, &0);
 }
 
@@ -80,7 +110,13 @@ fn test_transfer() {
     let admin = Address::generate(&e);
     let user = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     let amount: i128 = 500_000_000_000_000_000_000_000_000;
     client.transfer(&admin, &user, &amount);
@@ -100,7 +136,13 @@ fn test_transfer_insufficient_balance() {
     let admin = Address::generate(&e);
     let user = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
     client.transfer(&admin, &user, &(INITIAL_SUPPLY + 1));
 }
 
@@ -115,7 +157,13 @@ fn test_approve_and_transfer_from() {
     let spender = Address::generate(&e);
     let recipient = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     let allowance: i128 = 100_000_000_000_000_000_000_000_000;
     let transfer: i128 = 50_000_000_000_000_000_000_000_000;
@@ -142,7 +190,13 @@ fn test_transfer_from_insufficient_allowance() {
     let spender = Address::generate(&e);
     let recipient = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     let allowance: i128 = 100_000_000_000_000_000_000_000_000;
     client.approve(&admin, &spender, &allowance, &0);
@@ -158,7 +212,13 @@ fn test_burn() {
     let client = TycoonTokenClient::new(&e, &contract_id);
     let admin = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     let burn_amount: i128 = 100_000_000_000_000_000_000_000_000;
     client.burn(&admin, &burn_amount);
@@ -177,7 +237,13 @@ fn test_burn_insufficient_balance() {
     let client = TycoonTokenClient::new(&e, &contract_id);
     let admin = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
     client.burn(&admin, &(INITIAL_SUPPLY + 1));
 }
 
@@ -191,7 +257,13 @@ fn test_burn_from() {
     let admin = Address::generate(&e);
     let spender = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     let allowance: i128 = 100_000_000_000_000_000_000_000_000;
     let burn_amount: i128 = 50_000_000_000_000_000_000_000_000;
@@ -215,7 +287,13 @@ fn test_burn_from_insufficient_allowance() {
     let admin = Address::generate(&e);
     let spender = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     let allowance: i128 = 100_000_000_000_000_000_000_000_000;
     client.approve(&admin, &spender, &allowance, &0);
@@ -232,7 +310,13 @@ fn test_set_admin() {
     let admin = Address::generate(&e);
     let new_admin = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
     client.set_admin(&new_admin);
 
     assert_eq!(client.admin(), new_admin);
@@ -256,7 +340,13 @@ fn sim_game_reward_cycle() {
     let reward_pool = Address::generate(&e);
     let winner = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     // Admin mints reward tokens into the pool
     let reward: i128 = 5_000_000_000_000_000_000_000;
@@ -291,7 +381,13 @@ fn sim_delegated_entry_stake() {
     let game_contract = Address::generate(&e);
     let treasury = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     // Fund player
     let player_funds: i128 = 10_000_000_000_000_000_000_000;
@@ -326,7 +422,13 @@ fn sim_multi_player_distribution() {
         Address::generate(&e),
     ];
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     // Distribute equal starting cash to each player
     let starting_cash: i128 = 1_500_000_000_000_000_000_000;
@@ -361,7 +463,13 @@ fn sim_admin_rotation() {
     let new_admin = Address::generate(&e);
     let user = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
     client.set_admin(&new_admin);
     assert_eq!(client.admin(), new_admin);
 
@@ -384,7 +492,13 @@ fn sim_burn_from_fee_collection() {
     let holder = Address::generate(&e);
     let protocol = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
 
     let grant: i128 = 2_000_000_000_000_000_000_000;
     client.transfer(&admin, &holder, &grant);
@@ -414,7 +528,13 @@ fn test_new_admin_can_mint() {
     let new_admin = Address::generate(&e);
     let user = Address::generate(&e);
 
-    client.initialize(&admin, &INITIAL_SUPPLY);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
     client.set_admin(&new_admin);
 
     let mint_amount: i128 = 1_000_000_000_000_000_000_000;
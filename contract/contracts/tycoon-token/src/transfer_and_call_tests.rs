@@ -0,0 +1,247 @@
+/// # Tycoon Token (TYC) — `transfer_and_call` Tests
+///
+/// | ID    | Behaviour |
+/// |-------|-----------|
+/// | TC-01 | Tokens move and the receiver hook sees `(from, amount, payload)` |
+/// | TC-02 | A hook that calls back into the token reverts (no re-entry) |
+/// | TC-03 | A hook returning `false` reverts the transfer |
+/// | TC-04 | A panicking hook reverts the transfer |
+/// | TC-05 | A target without the hook reverts the transfer |
+/// | TC-06 | Non-positive amounts and overdrafts are rejected |
+/// | TC-07 | `from` must authorize the call |
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    Bytes,
+};
+
+// ── helpers ──────────────────────────────────────────────────────────────────
+
+const INITIAL_SUPPLY: i128 = 1_000_000_000_000_000_000_000_000_000; // 1e9 * 10^18
+const ONE_TYC: i128 = 1_000_000_000_000_000_000;
+
+#[contracttype]
+enum ReceiverKey {
+    Token,
+    Last,
+}
+
+/// Minimal receiver: records the last call and rejects any payload equal to
+/// `"reject"`. Panics on `"panic"`. Once `set_token` is called the hook also
+/// tries to read its own balance from the token.
+#[contract]
+struct MockReceiver;
+
+#[contractimpl]
+impl MockReceiver {
+    pub fn set_token(e: Env, token: Address) {
+        e.storage().instance().set(&ReceiverKey::Token, &token);
+    }
+
+    pub fn on_token_received(e: Env, from: Address, amount: i128, payload: Bytes) -> bool {
+        if payload == Bytes::from_slice(&e, b"panic") {
+            panic!("receiver exploded");
+        }
+        if let Some(token) = e
+            .storage()
+            .instance()
+            .get::<_, Address>(&ReceiverKey::Token)
+        {
+            TycoonTokenClient::new(&e, &token).balance(&e.current_contract_address());
+        }
+        e.storage()
+            .instance()
+            .set(&ReceiverKey::Last, &(from, amount, payload.clone()));
+        payload != Bytes::from_slice(&e, b"reject")
+    }
+
+    pub fn last(e: Env) -> Option<(Address, i128, Bytes)> {
+        e.storage().instance().get(&ReceiverKey::Last)
+    }
+}
+
+/// A contract with no `on_token_received` entrypoint.
+#[contract]
+struct NotAReceiver;
+
+#[contractimpl]
+impl NotAReceiver {
+    pub fn ping(_e: Env) -> u32 {
+        1
+    }
+}
+
+/// Spin up a fresh environment, register token and receiver, and fund a player.
+/// Returns `(env, client, receiver, player)`.
+fn setup() -> (
+    Env,
+    TycoonTokenClient<'static>,
+    MockReceiverClient<'static>,
+    Address,
+) {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_id = e.register(TycoonToken, ());
+    let client = TycoonTokenClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    client.initialize(
+        &admin,
+        &INITIAL_SUPPLY,
+        &String::from_str(&e, "Tycoon"),
+        &String::from_str(&e, "TYC"),
+        &18,
+    );
+    let receiver = MockReceiverClient::new(&e, &e.register(MockReceiver, ()));
+    let player = Address::generate(&e);
+    client.transfer(&admin, &player, &(100 * ONE_TYC));
+    (e, client, receiver, player)
+}
+
+// ── TC-01 ─────────────────────────────────────────────────────────────────────
+
+/// TC-01: balances move and the hook receives the original arguments.
+#[test]
+fn test_tc_01_transfer_and_call_notifies_receiver() {
+    let (e, client, receiver, player) = setup();
+    let payload = Bytes::from_slice(&e, b"join:42");
+
+    client.transfer_and_call(&player, &receiver.address, &(30 * ONE_TYC), &payload);
+
+    assert_eq!(client.balance(&player), 70 * ONE_TYC);
+    assert_eq!(client.balance(&receiver.address), 30 * ONE_TYC);
+    assert_eq!(receiver.last(), Some((player, 30 * ONE_TYC, payload)));
+}
+
+// ── TC-02 ─────────────────────────────────────────────────────────────────────
+
+/// TC-02: Soroban forbids re-entry, so even a read-only call from the hook
+/// back into the token aborts the whole transfer. Receivers must trust the
+/// `amount` argument instead of re-checking their balance.
+#[test]
+fn test_tc_02_reentrant_receiver_reverts() {
+    let (e, client, receiver, player) = setup();
+    receiver.set_token(&client.address);
+
+    let result =
+        client.try_transfer_and_call(&player, &receiver.address, &(5 * ONE_TYC), &Bytes::new(&e));
+
+    assert!(result.is_err());
+    assert_eq!(client.balance(&player), 100 * ONE_TYC);
+    assert_eq!(client.balance(&receiver.address), 0);
+}
+
+// ── TC-03 ─────────────────────────────────────────────────────────────────────
+
+/// TC-03: a `false` return reverts the payment.
+#[test]
+#[should_panic(expected = "Receiver rejected transfer")]
+fn test_tc_03_rejected_transfer_panics() {
+    let (e, client, receiver, player) = setup();
+    client.transfer_and_call(
+        &player,
+        &receiver.address,
+        &ONE_TYC,
+        &Bytes::from_slice(&e, b"reject"),
+    );
+}
+
+/// TC-03b: after a rejection no balance has changed.
+#[test]
+fn test_tc_03_rejected_transfer_leaves_balances() {
+    let (e, client, receiver, player) = setup();
+    let result = client.try_transfer_and_call(
+        &player,
+        &receiver.address,
+        &ONE_TYC,
+        &Bytes::from_slice(&e, b"reject"),
+    );
+
+    assert!(result.is_err());
+    assert_eq!(client.balance(&player), 100 * ONE_TYC);
+    assert_eq!(client.balance(&receiver.address), 0);
+    assert_eq!(receiver.last(), None);
+}
+
+// ── TC-04 ─────────────────────────────────────────────────────────────────────
+
+/// TC-04: a panic inside the hook unwinds the transfer too.
+#[test]
+fn test_tc_04_panicking_receiver_reverts() {
+    let (e, client, receiver, player) = setup();
+    let result = client.try_transfer_and_call(
+        &player,
+        &receiver.address,
+        &ONE_TYC,
+        &Bytes::from_slice(&e, b"panic"),
+    );
+
+    assert!(result.is_err());
+    assert_eq!(client.balance(&player), 100 * ONE_TYC);
+    assert_eq!(client.balance(&receiver.address), 0);
+}
+
+// ── TC-05 ─────────────────────────────────────────────────────────────────────
+
+/// TC-05: a contract that does not implement the hook cannot receive this way.
+#[test]
+fn test_tc_05_missing_hook_reverts() {
+    let (e, client, _receiver, player) = setup();
+    let target = e.register(NotAReceiver, ());
+
+    let result = client.try_transfer_and_call(&player, &target, &ONE_TYC, &Bytes::new(&e));
+
+    assert!(result.is_err());
+    assert_eq!(client.balance(&target), 0);
+}
+
+// ── TC-06 ─────────────────────────────────────────────────────────────────────
+
+/// TC-06a: zero is not a payment.
+#[test]
+#[should_panic(expected = "Amount must be positive")]
+fn test_tc_06_zero_amount_rejected() {
+    let (e, client, receiver, player) = setup();
+    client.transfer_and_call(&player, &receiver.address, &0, &Bytes::new(&e));
+}
+
+/// TC-06b: the sender must hold the full amount.
+#[test]
+#[should_panic(expected = "Insufficient balance")]
+fn test_tc_06_overdraft_rejected() {
+    let (e, client, receiver, player) = setup();
+    client.transfer_and_call(
+        &player,
+        &receiver.address,
+        &(101 * ONE_TYC),
+        &Bytes::new(&e),
+    );
+}
+
+// ── TC-07 ─────────────────────────────────────────────────────────────────────
+
+/// TC-07: a third party cannot push someone else's tokens into a receiver.
+#[test]
+#[should_panic]
+fn test_tc_07_requires_from_auth() {
+    let (e, client, receiver, player) = setup();
+    let attacker = Address::generate(&e);
+    let payload = Bytes::new(&e);
+
+    e.mock_auths(&[MockAuth {
+        address: &attacker,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "transfer_and_call",
+            args: (
+                player.clone(),
+                receiver.address.clone(),
+                ONE_TYC,
+                payload.clone(),
+            )
+                .into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+    client.transfer_and_call(&player, &receiver.address, &ONE_TYC, &payload);
+}
@@ -28,7 +28,7 @@ mod tests {
         testutils::{Address as _, Ledger, LedgerInfo},
         Address, Env,
     };
-    use tycoon_boost_system::{Boost, BoostType};
+    use tycoon_boost_system::{Boost, BoostCategory, BoostType};
 
     fn set_ledger(env: &Env, seq: u32) {
         env.ledger().set(LedgerInfo {
@@ -47,9 +47,12 @@ mod tests {
         Boost {
             id,
             boost_type,
+            category: BoostCategory::General,
             value,
             priority,
             expires_at_ledger: 0,
+            expires_at_timestamp: 0,
+            charges: None,
         }
     }
 
@@ -57,9 +60,12 @@ mod tests {
         Boost {
             id,
             boost_type,
+            category: BoostCategory::General,
             value,
             priority,
             expires_at_ledger: expires,
+            expires_at_timestamp: 0,
+            charges: None,
         }
     }
 
@@ -14,7 +14,7 @@ use soroban_sdk::{
 };
 
 // Re-export boost types for convenience
-use tycoon_boost_system::{Boost, BoostType, TycoonBoostSystemClient};
+use tycoon_boost_system::{Boost, BoostCategory, BoostType, TycoonBoostSystemClient};
 
 // ── Test Helpers ──────────────────────────────────────────────────────────────
 
@@ -35,9 +35,12 @@ fn boost(id: u128, boost_type: BoostType, value: u32, priority: u32, expires: u3
     Boost {
         id,
         boost_type,
+        category: BoostCategory::General,
         value,
         priority,
         expires_at_ledger: expires,
+        expires_at_timestamp: 0,
+        charges: None,
     }
 }
 
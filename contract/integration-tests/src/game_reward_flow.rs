@@ -17,10 +17,11 @@
 /// | `multiple_players_register_independently` | three players, isolated data |
 /// | `export_state_reflects_fixture_wiring`    | export_state cross-contract wiring |
 /// | `game_migrate_is_idempotent`              | migrate no-op at v1 |
-/// | `reward_migrate_is_idempotent`            | reward migrate no-op at v1 |
+/// | `reward_migrate_is_idempotent`            | reward migrate no-op when current |
 /// | `register_then_reward_then_remove`        | full lifecycle: register → reward → remove |
 /// | `admin_set_game_controller_updates_state` | admin_set_game_controller reflected in export_state |
 /// | `remove_player_no_controller_owner_ok`    | owner removes when no controller set |
+/// | `daily_claim_needs_game_registration`     | reward.claim_daily → game.is_registered |
 #[cfg(test)]
 mod tests {
     extern crate std;
@@ -31,7 +32,7 @@ mod tests {
     fn register_player_succeeds() {
         let f = Fixture::new();
         f.game
-            .register_player(&String::from_str(&f.env, "alice"), &f.player_a);
+            .register_player(&String::from_str(&f.env, "alice"), &f.player_a, &None);
         assert!(f.game.get_user(&f.player_a).is_some());
     }
 
@@ -39,7 +40,7 @@ mod tests {
     fn registered_player_data_correct() {
         let f = Fixture::new();
         let username = String::from_str(&f.env, "bob123");
-        f.game.register_player(&username, &f.player_b);
+        f.game.register_player(&username, &f.player_b, &None);
         let user = f.game.get_user(&f.player_b).unwrap();
         assert_eq!(user.username, username);
         assert_eq!(user.address, f.player_b);
@@ -51,9 +52,9 @@ mod tests {
     fn duplicate_registration_rejected() {
         let f = Fixture::new();
         let u = String::from_str(&f.env, "carol");
-        f.game.register_player(&u, &f.player_a);
+        f.game.register_player(&u, &f.player_a, &None);
         let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-            f.game.register_player(&u, &f.player_a);
+            f.game.register_player(&u, &f.player_a, &None);
         }));
         assert!(res.is_err());
     }
@@ -63,7 +64,7 @@ mod tests {
         let f = Fixture::new();
         let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
             f.game
-                .register_player(&String::from_str(&f.env, "ab"), &f.player_a);
+                .register_player(&String::from_str(&f.env, "ab"), &f.player_a, &None);
         }));
         assert!(res.is_err());
     }
@@ -75,6 +76,7 @@ mod tests {
             f.game.register_player(
                 &String::from_str(&f.env, "thisusernameiswaytoolong"),
                 &f.player_a,
+                &None,
             );
         }));
         assert!(res.is_err());
@@ -84,7 +86,7 @@ mod tests {
     fn owner_can_withdraw_after_registration() {
         let f = Fixture::new();
         f.game
-            .register_player(&String::from_str(&f.env, "dave"), &f.player_a);
+            .register_player(&String::from_str(&f.env, "dave"), &f.player_a, &None);
         assert!(f.tyc_balance(&f.game_id) > 0);
     }
 
@@ -92,7 +94,7 @@ mod tests {
     fn backend_controller_removes_player() {
         let f = Fixture::new();
         f.game
-            .register_player(&String::from_str(&f.env, "eve"), &f.player_a);
+            .register_player(&String::from_str(&f.env, "eve"), &f.player_a, &None);
         f.game
             .remove_player_from_game(&f.backend, &1, &f.player_a, &5);
     }
@@ -101,7 +103,7 @@ mod tests {
     fn owner_removes_player() {
         let f = Fixture::new();
         f.game
-            .register_player(&String::from_str(&f.env, "frank"), &f.player_b);
+            .register_player(&String::from_str(&f.env, "frank"), &f.player_b, &None);
         f.game
             .remove_player_from_game(&f.admin, &2, &f.player_b, &10);
     }
@@ -121,11 +123,11 @@ mod tests {
     fn multiple_players_register_independently() {
         let f = Fixture::new();
         f.game
-            .register_player(&String::from_str(&f.env, "alice"), &f.player_a);
+            .register_player(&String::from_str(&f.env, "alice"), &f.player_a, &None);
         f.game
-            .register_player(&String::from_str(&f.env, "bob"), &f.player_b);
+            .register_player(&String::from_str(&f.env, "bob"), &f.player_b, &None);
         f.game
-            .register_player(&String::from_str(&f.env, "carol"), &f.player_c);
+            .register_player(&String::from_str(&f.env, "carol"), &f.player_c, &None);
 
         let ua = f.game.get_user(&f.player_a).unwrap();
         let ub = f.game.get_user(&f.player_b).unwrap();
@@ -179,7 +181,7 @@ mod tests {
         assert!(dump.is_initialized);
     }
 
-    /// `migrate` on the reward contract is idempotent at v1 and does not corrupt state.
+    /// `migrate` on the reward contract is idempotent at the current version and does not corrupt state.
     #[test]
     fn reward_migrate_is_idempotent() {
         let f = Fixture::new();
@@ -196,7 +198,7 @@ mod tests {
     fn register_then_reward_then_remove() {
         let f = Fixture::new();
         f.game
-            .register_player(&String::from_str(&f.env, "lifecycle"), &f.player_a);
+            .register_player(&String::from_str(&f.env, "lifecycle"), &f.player_a, &None);
         assert!(f.game.get_user(&f.player_a).is_some());
 
         let value: u128 = 25_000_000_000_000_000_000;
@@ -230,9 +232,25 @@ mod tests {
         // Override fixture's backend controller by registering a fresh game
         // without a controller — use the fixture admin directly.
         f.game
-            .register_player(&String::from_str(&f.env, "solo"), &f.player_b);
+            .register_player(&String::from_str(&f.env, "solo"), &f.player_b, &None);
         f.game
             .remove_player_from_game(&f.admin, &5, &f.player_b, &3);
         // No panic — owner is always authorized
     }
+
+    /// Daily streak claims are open to players registered in the game only.
+    #[test]
+    fn daily_claim_needs_game_registration() {
+        let f = Fixture::new();
+        f.reward.set_player_registry(&f.game_id);
+        f.reward
+            .set_streak_table(&soroban_sdk::vec![&f.env, 1_000_000_000_000_000_000]);
+
+        assert!(f.reward.try_claim_daily(&f.player_a).is_err());
+
+        f.game
+            .register_player(&String::from_str(&f.env, "alice"), &f.player_a, &None);
+        let voucher = f.reward.claim_daily(&f.player_a);
+        assert_eq!(f.reward.get_balance(&f.player_a, &voucher), 1);
+    }
 }
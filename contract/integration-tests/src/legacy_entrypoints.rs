@@ -200,7 +200,7 @@ mod tests {
 
         // Register the player first (required by the game contract).
         f.game
-            .register_player(&String::from_str(&f.env, "alice"), &f.player_a);
+            .register_player(&String::from_str(&f.env, "alice"), &f.player_a, &None);
 
         // Owner calls the legacy entrypoint.
         f.game.mint_registration_voucher(&f.player_a);
@@ -222,7 +222,7 @@ mod tests {
         let f = Fixture::new();
 
         f.game
-            .register_player(&String::from_str(&f.env, "bob"), &f.player_b);
+            .register_player(&String::from_str(&f.env, "bob"), &f.player_b, &None);
         f.game.mint_registration_voucher(&f.player_b);
 
         // Derive the voucher token_id: first mint → VOUCHER_ID_START.
@@ -251,7 +251,7 @@ mod tests {
         let attacker = Address::generate(&f.env);
 
         f.game
-            .register_player(&String::from_str(&f.env, "carol"), &f.player_c);
+            .register_player(&String::from_str(&f.env, "carol"), &f.player_c, &None);
 
         // We need a fixture where mock_all_auths is NOT active to test real
         // auth rejection.  Since Fixture always calls mock_all_auths(), we
@@ -279,7 +279,7 @@ mod tests {
 
         // Register player and mint a voucher.
         f.game
-            .register_player(&String::from_str(&f.env, "dave"), &f.player_a);
+            .register_player(&String::from_str(&f.env, "dave"), &f.player_a, &None);
         let value: u128 = 200_000_000_000_000_000_000;
         let tid = f.reward.mint_voucher(&f.admin, &f.player_a, &value);
 
@@ -406,7 +406,7 @@ mod tests {
         let f = Fixture::new();
 
         f.game
-            .register_player(&String::from_str(&f.env, "eve"), &f.player_a);
+            .register_player(&String::from_str(&f.env, "eve"), &f.player_a, &None);
 
         f.game.mint_registration_voucher(&f.player_a);
         f.game.mint_registration_voucher(&f.player_a);
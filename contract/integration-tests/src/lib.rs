@@ -10,6 +10,8 @@ mod game_token_flow;
 #[cfg(test)]
 mod multi_player_flow;
 #[cfg(test)]
+mod referral_flow;
+#[cfg(test)]
 mod reward_transfer_flow;
 // Stellar Wave (SW-FE-001): simulation scenarios
 #[cfg(test)]
@@ -25,3 +27,5 @@ mod security_review_checklist;
 mod simulation_scenarios;
 #[cfg(test)]
 mod token_reward_flow;
+#[cfg(test)]
+mod transfer_and_call_flow;
@@ -25,7 +25,7 @@ mod tests {
 
         // 1. Register player
         f.game
-            .register_player(&String::from_str(&f.env, "alice"), &f.player_a);
+            .register_player(&String::from_str(&f.env, "alice"), &f.player_a, &None);
         assert!(f.game.get_user(&f.player_a).is_some());
 
         // 2. Mint reward voucher
@@ -160,11 +160,11 @@ mod tests {
         let f = Fixture::new();
 
         f.game
-            .register_player(&String::from_str(&f.env, "alice"), &f.player_a);
+            .register_player(&String::from_str(&f.env, "alice"), &f.player_a, &None);
         f.game
-            .register_player(&String::from_str(&f.env, "bob"), &f.player_b);
+            .register_player(&String::from_str(&f.env, "bob"), &f.player_b, &None);
         f.game
-            .register_player(&String::from_str(&f.env, "carol"), &f.player_c);
+            .register_player(&String::from_str(&f.env, "carol"), &f.player_c, &None);
 
         let ua = f.game.get_user(&f.player_a).unwrap();
         let ub = f.game.get_user(&f.player_b).unwrap();
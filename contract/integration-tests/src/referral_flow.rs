@@ -0,0 +1,126 @@
+/// # Cross-contract flow: Game referrals → RewardSystem vouchers
+///
+/// The game contract records referrals at registration and, once the referred
+/// player has played enough games, mints referral vouchers through the reward
+/// system. The game is set as the reward system's referral minter, next to the
+/// fixture's backend minter.
+///
+/// | Test | Cross-contract path |
+/// |------|---------------------|
+/// | `referral_rewards_are_redeemable_vouchers` | game.claim_referral_reward → reward.mint_voucher → redeem |
+/// | `referral_claim_needs_minter_role`         | reward system rejects a game that is not a minter |
+/// | `backend_and_game_mint_side_by_side`       | backend minter and game both mint through reward |
+/// | `tripped_breaker_keeps_referral_claimable` | reward breaker refuses the game's mint and pauses; the claim stays open |
+/// | `referral_vouchers_are_backed_by_reserves` | claims count against reward-system liabilities |
+#[cfg(test)]
+mod tests {
+    use crate::fixture::Fixture;
+    use soroban_sdk::{testutils::Address as _, Address, String};
+
+    const REFERRER_REWARD: u128 = 100_000_000_000_000_000_000; // 100 TYC
+    const REFEREE_REWARD: u128 = 50_000_000_000_000_000_000; // 50 TYC
+
+    /// Fixture with the game as referral minter and a 2-game referral program.
+    /// `player_a` refers `player_b`.
+    fn setup<'a>() -> Fixture<'a> {
+        let f = Fixture::new();
+        f.reward.set_referral_minter(&f.game_id);
+        f.game.admin_set_referral_config(
+            &2,
+            &REFERRER_REWARD,
+            &REFEREE_REWARD,
+            &(10 * REFERRER_REWARD),
+        );
+        f.game
+            .register_player(&String::from_str(&f.env, "alice"), &f.player_a, &None);
+        f.game.register_player(
+            &String::from_str(&f.env, "bob"),
+            &f.player_b,
+            &Some(f.player_a.clone()),
+        );
+        f.game.record_game(&f.backend, &f.player_b, &true);
+        f.game.record_game(&f.backend, &f.player_b, &false);
+        f
+    }
+
+    #[test]
+    fn referral_rewards_are_redeemable_vouchers() {
+        let f = setup();
+        let referrer_voucher = f.game.claim_referral_reward(&f.player_a, &f.player_b);
+        let referee_voucher = f.game.claim_referral_reward(&f.player_b, &f.player_b);
+
+        assert_eq!(f.reward.get_balance(&f.player_a, &referrer_voucher), 1);
+        assert_eq!(f.reward.get_balance(&f.player_b, &referee_voucher), 1);
+
+        let before = f.tyc_balance(&f.player_a);
+        f.reward.redeem_voucher_from(&f.player_a, &referrer_voucher);
+        assert_eq!(f.tyc_balance(&f.player_a) - before, REFERRER_REWARD as i128);
+    }
+
+    #[test]
+    fn referral_claim_needs_minter_role() {
+        let f = setup();
+        f.reward.clear_referral_minter();
+        assert!(f
+            .game
+            .try_claim_referral_reward(&f.player_b, &f.player_b)
+            .is_err());
+        // The failed mint rolled back the claim marker.
+        f.reward.set_referral_minter(&f.game_id);
+        f.game.claim_referral_reward(&f.player_b, &f.player_b);
+    }
+
+    #[test]
+    fn backend_and_game_mint_side_by_side() {
+        let f = setup();
+
+        let from_game = f.game.claim_referral_reward(&f.player_b, &f.player_b);
+        let from_backend = f
+            .reward
+            .mint_voucher(&f.backend, &f.player_c, &REFEREE_REWARD);
+
+        assert_eq!(f.reward.get_balance(&f.player_b, &from_game), 1);
+        assert_eq!(f.reward.get_balance(&f.player_c, &from_backend), 1);
+        assert_eq!(f.reward.get_backend_minter(), Some(f.backend.clone()));
+    }
+
+    #[test]
+    fn referral_vouchers_are_backed_by_reserves() {
+        let f = setup();
+        f.game.claim_referral_reward(&f.player_a, &f.player_b);
+        f.game.claim_referral_reward(&f.player_b, &f.player_b);
+        assert_eq!(
+            f.reward.outstanding_liabilities(&f.tyc_id),
+            REFERRER_REWARD + REFEREE_REWARD
+        );
+
+        // An unrelated address cannot claim for the pair.
+        assert!(f
+            .game
+            .try_claim_referral_reward(&Address::generate(&f.env), &f.player_b)
+            .is_err());
+    }
+
+    #[test]
+    fn tripped_breaker_keeps_referral_claimable() {
+        let f = setup();
+        // Room for the referee's voucher but not the referrer's.
+        f.reward.set_mint_limit(&5, &REFEREE_REWARD, &1_000);
+
+        assert_eq!(f.game.claim_referral_reward(&f.player_a, &f.player_b), 0);
+        // The pause held: minters cannot mint until the admin unpauses.
+        assert!(f
+            .reward
+            .try_mint_voucher(&f.game_id, &f.player_a, &1)
+            .is_err());
+        assert_eq!(f.reward.owned_token_count(&f.player_a), 0);
+        assert_eq!(f.game.get_referral_paid(&f.player_a), 0);
+
+        // The claim stays open once the admin clears the breaker.
+        f.reward.clear_mint_limit();
+        f.reward.unpause();
+        let voucher = f.game.claim_referral_reward(&f.player_a, &f.player_b);
+        assert_eq!(f.reward.get_balance(&f.player_a, &voucher), 1);
+        assert_eq!(f.game.get_referral_paid(&f.player_a), REFERRER_REWARD);
+    }
+}
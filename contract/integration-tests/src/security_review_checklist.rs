@@ -272,7 +272,7 @@ mod tests {
     fn only_owner_or_backend_can_remove_player() {
         let f = Fixture::new();
         f.game
-            .register_player(&String::from_str(&f.env, "alice"), &f.player_a);
+            .register_player(&String::from_str(&f.env, "alice"), &f.player_a, &None);
         let attacker = Address::generate(&f.env);
         let game_id: u128 = 1;
         let turns: u32 = 5;
@@ -341,7 +341,7 @@ mod tests {
     fn player_cannot_mint_own_voucher() {
         let f = Fixture::new();
         f.game
-            .register_player(&String::from_str(&f.env, "alice"), &f.player_a);
+            .register_player(&String::from_str(&f.env, "alice"), &f.player_a, &None);
         let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
             f.reward
                 .mint_voucher(&f.player_a, &f.player_a, &1_000_000_000_000_000_000u128);
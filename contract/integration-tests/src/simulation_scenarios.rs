@@ -399,7 +399,7 @@ mod tests {
         let name = String::from_str(&f.env, "alice");
 
         // Register
-        f.game.register_player(&name, &f.player_a);
+        f.game.register_player(&name, &f.player_a, &None);
         assert!(f.game.get_user(&f.player_a).is_some());
 
         // Backend removes the player from the game session
@@ -413,7 +413,7 @@ mod tests {
         // Re-registration must be rejected — address is still registered
         let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
             f.game
-                .register_player(&String::from_str(&f.env, "alice2"), &f.player_a);
+                .register_player(&String::from_str(&f.env, "alice2"), &f.player_a, &None);
         }));
         assert!(
             res.is_err(),
@@ -0,0 +1,215 @@
+/// # Cross-contract flow: TycoonToken `transfer_and_call` → Game
+///
+/// A player joins a staked game in one transaction: the TYC token moves the
+/// stake into the game contract and then calls its `on_token_received` hook.
+/// Uses the native `TycoonToken` (not the Stellar asset contract used by
+/// `Fixture`) because the hook lives on that contract.
+///
+/// | Test | Cross-contract path |
+/// |------|---------------------|
+/// | `player_joins_staked_game_in_one_call`    | token.transfer_and_call → game.on_token_received |
+/// | `game_fills_from_token_payments`          | stakes accumulate in the game contract |
+/// | `wrong_stake_reverts_payment`             | hook panic unwinds the token transfer |
+/// | `unregistered_player_payment_reverts`     | hook panic unwinds the token transfer |
+/// | `staked_tyc_is_withdrawable_by_owner`     | escrowed stakes reach admin_withdraw_funds |
+/// | `settled_pot_reaches_winner`              | game.settle_staked_game → token.transfer |
+/// | `cancelled_game_refunds_stakes`           | game.cancel_staked_game → token.transfer per player |
+/// | `taxed_join_fails_unless_game_exempt`     | transfer tax shrinks the hook amount below the stake |
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use soroban_sdk::{testutils::Address as _, xdr::ToXdr, Address, Bytes, Env, String};
+    use tycoon_game::{TycoonContract, TycoonContractClient};
+    use tycoon_token::{TycoonToken, TycoonTokenClient};
+
+    const STAKE: i128 = 25_000_000_000_000_000_000; // 25 TYC
+
+    struct Setup<'a> {
+        env: Env,
+        owner: Address,
+        tyc: TycoonTokenClient<'a>,
+        game: TycoonContractClient<'a>,
+    }
+
+    fn setup<'a>() -> Setup<'a> {
+        let env = Env::default();
+        env.mock_all_auths();
+        let owner = Address::generate(&env);
+
+        let tyc = TycoonTokenClient::new(&env, &env.register(TycoonToken, ()));
+        tyc.initialize(
+            &owner,
+            &(1_000 * STAKE),
+            &String::from_str(&env, "Tycoon"),
+            &String::from_str(&env, "TYC"),
+            &18,
+        );
+
+        let game = TycoonContractClient::new(&env, &env.register(TycoonContract, ()));
+        let usdc = Address::generate(&env);
+        let reward = Address::generate(&env);
+        game.initialize(&tyc.address, &usdc, &owner, &reward);
+        game.create_staked_game(&owner, &1, &(STAKE as u128), &3);
+
+        Setup {
+            env,
+            owner,
+            tyc,
+            game,
+        }
+    }
+
+    fn funded_player(s: &Setup, name: &str) -> Address {
+        let player = Address::generate(&s.env);
+        s.game
+            .register_player(&String::from_str(&s.env, name), &player, &None);
+        s.tyc.transfer(&s.owner, &player, &(2 * STAKE));
+        player
+    }
+
+    fn join_payload(env: &Env, game_id: u128) -> Bytes {
+        game_id.to_xdr(env)
+    }
+
+    #[test]
+    fn player_joins_staked_game_in_one_call() {
+        let s = setup();
+        let alice = funded_player(&s, "alice");
+
+        s.tyc
+            .transfer_and_call(&alice, &s.game.address, &STAKE, &join_payload(&s.env, 1));
+
+        assert_eq!(s.tyc.balance(&alice), STAKE);
+        assert_eq!(s.tyc.balance(&s.game.address), STAKE);
+        let game = s.game.get_staked_game(&1).unwrap();
+        assert_eq!(game.players.len(), 1);
+        assert_eq!(game.players.get(0).unwrap(), alice);
+    }
+
+    #[test]
+    fn game_fills_from_token_payments() {
+        let s = setup();
+        for name in ["alice", "bob", "carol"] {
+            let player = funded_player(&s, name);
+            s.tyc
+                .transfer_and_call(&player, &s.game.address, &STAKE, &join_payload(&s.env, 1));
+        }
+
+        assert_eq!(s.game.get_staked_game(&1).unwrap().players.len(), 3);
+        assert_eq!(s.tyc.balance(&s.game.address), 3 * STAKE);
+
+        // A fourth stake bounces and stays with the player.
+        let dave = funded_player(&s, "dave");
+        let result =
+            s.tyc
+                .try_transfer_and_call(&dave, &s.game.address, &STAKE, &join_payload(&s.env, 1));
+        assert!(result.is_err());
+        assert_eq!(s.tyc.balance(&dave), 2 * STAKE);
+    }
+
+    #[test]
+    fn wrong_stake_reverts_payment() {
+        let s = setup();
+        let alice = funded_player(&s, "alice");
+
+        let result = s.tyc.try_transfer_and_call(
+            &alice,
+            &s.game.address,
+            &(STAKE / 2),
+            &join_payload(&s.env, 1),
+        );
+
+        assert!(result.is_err());
+        assert_eq!(s.tyc.balance(&alice), 2 * STAKE);
+        assert_eq!(s.tyc.balance(&s.game.address), 0);
+        assert_eq!(s.game.get_staked_game(&1).unwrap().players.len(), 0);
+    }
+
+    #[test]
+    fn unregistered_player_payment_reverts() {
+        let s = setup();
+        let stranger = Address::generate(&s.env);
+        s.tyc.transfer(&s.owner, &stranger, &STAKE);
+
+        let result = s.tyc.try_transfer_and_call(
+            &stranger,
+            &s.game.address,
+            &STAKE,
+            &join_payload(&s.env, 1),
+        );
+
+        assert!(result.is_err());
+        assert_eq!(s.tyc.balance(&stranger), STAKE);
+    }
+
+    #[test]
+    fn staked_tyc_is_withdrawable_by_owner() {
+        let s = setup();
+        let alice = funded_player(&s, "alice");
+        s.tyc
+            .transfer_and_call(&alice, &s.game.address, &STAKE, &join_payload(&s.env, 1));
+
+        let winner = Address::generate(&s.env);
+        s.game
+            .admin_withdraw_funds(&s.tyc.address, &winner, &(STAKE as u128));
+
+        assert_eq!(s.tyc.balance(&winner), STAKE);
+        assert_eq!(s.tyc.balance(&s.game.address), 0);
+    }
+
+    #[test]
+    fn settled_pot_reaches_winner() {
+        let s = setup();
+        let alice = funded_player(&s, "alice");
+        let bob = funded_player(&s, "bob");
+        for player in [&alice, &bob] {
+            s.tyc
+                .transfer_and_call(player, &s.game.address, &STAKE, &join_payload(&s.env, 1));
+        }
+
+        s.game.settle_staked_game(&s.owner, &1, &bob);
+
+        assert_eq!(s.tyc.balance(&bob), 3 * STAKE);
+        assert_eq!(s.tyc.balance(&alice), STAKE);
+        assert_eq!(s.tyc.balance(&s.game.address), 0);
+    }
+
+    #[test]
+    fn cancelled_game_refunds_stakes() {
+        let s = setup();
+        let alice = funded_player(&s, "alice");
+        s.tyc
+            .transfer_and_call(&alice, &s.game.address, &STAKE, &join_payload(&s.env, 1));
+
+        s.game.cancel_staked_game(&s.owner, &1);
+
+        assert_eq!(s.tyc.balance(&alice), 2 * STAKE);
+        assert!(s.game.get_staked_game(&1).is_none());
+    }
+
+    #[test]
+    fn taxed_join_fails_unless_game_exempt() {
+        let s = setup();
+        let alice = funded_player(&s, "alice");
+        let bob = funded_player(&s, "bob");
+        s.tyc
+            .set_transfer_tax(&100, &100, &Address::generate(&s.env));
+
+        // The hook sees the stake minus 2 % tax, so the join reverts.
+        let result =
+            s.tyc
+                .try_transfer_and_call(&alice, &s.game.address, &STAKE, &join_payload(&s.env, 1));
+        assert!(result.is_err());
+        assert_eq!(s.tyc.balance(&alice), 2 * STAKE);
+
+        // Exempting the game contract lets the full stake through, and the
+        // payout leaves untaxed too.
+        s.tyc.set_tax_exempt(&s.game.address, &true);
+        for player in [&alice, &bob] {
+            s.tyc
+                .transfer_and_call(player, &s.game.address, &STAKE, &join_payload(&s.env, 1));
+        }
+        s.game.settle_staked_game(&s.owner, &1, &alice);
+        assert_eq!(s.tyc.balance(&alice), 3 * STAKE);
+    }
+}
@@ -12,7 +12,7 @@ extern crate std;
 use soroban_sdk::{
     testutils::{Address as _, Events, Ledger, LedgerInfo},
     token::{StellarAssetClient, TokenClient},
-    Address, Env,
+    Address, Env, String,
 };
 use tycoon_token::TycoonToken;
 
@@ -386,7 +386,13 @@ fn test_multi_token_transfers() {
 
 fn register_tycoon_token(env: &Env, admin: &Address, initial_supply: i128) -> Address {
     let id = env.register(TycoonToken, ());
-    tycoon_token::TycoonTokenClient::new(env, &id).initialize(admin, &initial_supply);
+    tycoon_token::TycoonTokenClient::new(env, &id).initialize(
+        admin,
+        &initial_supply,
+        &String::from_str(env, "Tycoon"),
+        &String::from_str(env, "TYC"),
+        &18,
+    );
     id
 }
 
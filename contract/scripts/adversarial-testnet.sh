@@ -133,7 +133,10 @@ assert_fails "double initialize" \
   --id "${TOKEN_CONTRACT_ID}" \
   -- initialize \
   --admin "${ADVERSARY_ADDRESS}" \
-  --initial_supply 1000000000000000000000000000
+  --initial_supply 1000000000000000000000000000 \
+  --name Tycoon \
+  --symbol TYC \
+  --decimals 18
 
 # Case 7: Repeated rapid mints (10×) by non-admin — all must fail (no DoS)
 echo "  Testing: repeated rapid mints (10x non-admin, all should fail) ..."